@@ -1,4 +1,5 @@
-use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::hint::black_box;
 use std::time::Duration;
 use vizij_animation_core::TrackValue;
 use vizij_animation_core::{
@@ -32,6 +33,7 @@ fn make_track(id: usize, keyframes: usize, _duration_ms: u32) -> Track {
             stamp: t,
             value: TrackValue::Float(idx as f32),
             transitions,
+            interpolation: None,
         });
     }
     Track {
@@ -44,6 +46,7 @@ fn make_track(id: usize, keyframes: usize, _duration_ms: u32) -> Track {
         },
         points,
         settings: None,
+        units: None,
     }
 }
 
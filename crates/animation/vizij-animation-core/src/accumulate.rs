@@ -6,6 +6,7 @@
 //! weighted component sums in plain arrays and encodes each blended result
 //! through the vocabulary constructors once, in [`AccumulatorWithDerivatives::finalize`].
 
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 
 use crate::interp::functions::nlerp_quat;
@@ -27,6 +28,13 @@ impl CollectionKind {
         }
     }
 
+    fn name(&self) -> &'static str {
+        match self {
+            CollectionKind::Vector(_) => "vector",
+            CollectionKind::Array(_) => "array",
+        }
+    }
+
     fn matches(&self, other: &CollectionKind) -> bool {
         std::mem::discriminant(self) == std::mem::discriminant(other) && self.len() == other.len()
     }
@@ -42,6 +50,33 @@ impl CollectionKind {
     }
 }
 
+fn track_kind_name(value: &TrackValue) -> &'static str {
+    match value {
+        TrackValue::Float(_) => "float",
+        TrackValue::Vec2(_) => "vec2",
+        TrackValue::Vec3(_) => "vec3",
+        TrackValue::Vec4(_) => "vec4",
+        TrackValue::Quat(_) => "quat",
+        TrackValue::ColorRgba(_) => "color",
+        TrackValue::Transform(_) => "transform",
+        TrackValue::Vector(_) => "vector",
+        TrackValue::NumericArray(_) => "array",
+        TrackValue::Bool(_) => "bool",
+        TrackValue::Text(_) => "text",
+        TrackValue::Step(_) => "step",
+    }
+}
+
+/// Kind name used in conflict warnings; collections carry their length (`vector[3]`).
+fn describe_track(value: &TrackValue) -> String {
+    match value {
+        TrackValue::Vector(values) | TrackValue::NumericArray(values) => {
+            format!("{}[{}]", track_kind_name(value), values.len())
+        }
+        _ => track_kind_name(value).to_string(),
+    }
+}
+
 fn numeric_collection_from_value(value: &TrackValue) -> Option<(CollectionKind, Vec<f32>)> {
     match value {
         TrackValue::Vector(values) => Some((CollectionKind::Vector(values.len()), values.clone())),
@@ -94,11 +129,16 @@ enum AccumEntry {
         w: f32,
     },
     /// Step-only kinds (Bool/Text/other): prefer last assignment, no blending
-    Step(TrackValue),
+    Step {
+        value: TrackValue,
+        w: f32,
+    },
 }
 
 impl AccumEntry {
-    fn add_value(&mut self, v: &TrackValue, w: f32) {
+    /// Fold `v` into this entry. Returns `false` when `v` is of an incompatible kind and was
+    /// not applied.
+    fn add_value(&mut self, v: &TrackValue, w: f32) -> bool {
         match (self, v) {
             (AccumEntry::Scalar { sum, w: ww }, TrackValue::Float(x)) => {
                 *sum += x * w;
@@ -160,38 +200,62 @@ impl AccumEntry {
                 *ww += w;
             }
             (
-                AccumEntry::Step(last),
-                TrackValue::Bool(_)
-                | TrackValue::Text(_)
-                | TrackValue::NumericArray(_)
-                | TrackValue::Step(_),
+                AccumEntry::Step { value: last, w: ww },
+                TrackValue::Bool(_) | TrackValue::Text(_) | TrackValue::Step(_),
             ) => {
                 *last = v.clone(); // prefer last/most-recent assignment
+                *ww += w;
             }
-            (entry @ AccumEntry::Collection { .. }, other) => {
-                if let Some((incoming_kind, data)) = numeric_collection_from_value(other) {
-                    let matches_kind = match entry {
-                        AccumEntry::Collection { kind, .. } => kind.matches(&incoming_kind),
-                        _ => false,
-                    };
-                    if matches_kind {
-                        if let AccumEntry::Collection { sum, w: ww, .. } = entry {
-                            debug_assert_eq!(sum.len(), data.len());
-                            for (acc, component) in sum.iter_mut().zip(data.iter()) {
-                                *acc += component * w;
-                            }
-                            *ww += w;
+            (AccumEntry::Collection { kind, sum, w: ww }, other) => {
+                match numeric_collection_from_value(other) {
+                    Some((incoming_kind, data)) if kind.matches(&incoming_kind) => {
+                        for (acc, component) in sum.iter_mut().zip(data.iter()) {
+                            *acc += component * w;
                         }
-                    } else {
-                        *entry = AccumEntry::Step(other.clone());
+                        *ww += w;
                     }
-                } else {
-                    *entry = AccumEntry::Step(other.clone());
+                    _ => return false,
                 }
             }
-            _ => {
-                // Mismatched kind; ignore additional values to keep fail-soft behavior.
-            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Total weight folded into this entry so far.
+    fn weight(&self) -> f32 {
+        match *self {
+            AccumEntry::Scalar { w, .. }
+            | AccumEntry::Vec2 { w, .. }
+            | AccumEntry::Vec3 { w, .. }
+            | AccumEntry::Vec4 { w, .. }
+            | AccumEntry::Quat { w, .. }
+            | AccumEntry::Color { w, .. }
+            | AccumEntry::Transform { w, .. }
+            | AccumEntry::Collection { w, .. }
+            | AccumEntry::Step { w, .. } => w,
+        }
+    }
+
+    fn kind_name(&self) -> &'static str {
+        match self {
+            AccumEntry::Scalar { .. } => "float",
+            AccumEntry::Vec2 { .. } => "vec2",
+            AccumEntry::Vec3 { .. } => "vec3",
+            AccumEntry::Vec4 { .. } => "vec4",
+            AccumEntry::Quat { .. } => "quat",
+            AccumEntry::Color { .. } => "color",
+            AccumEntry::Transform { .. } => "transform",
+            AccumEntry::Collection { kind, .. } => kind.name(),
+            AccumEntry::Step { value, .. } => track_kind_name(value),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            AccumEntry::Collection { kind, .. } => format!("{}[{}]", kind.name(), kind.len()),
+            AccumEntry::Step { value, .. } => describe_track(value),
+            _ => self.kind_name().to_string(),
         }
     }
 
@@ -241,9 +305,10 @@ impl AccumEntry {
                 }
                 AccumEntry::Collection { kind, sum: data, w }
             }
-            TrackValue::Bool(_) | TrackValue::Text(_) | TrackValue::Step(_) => {
-                AccumEntry::Step(v.clone())
-            }
+            TrackValue::Bool(_) | TrackValue::Text(_) | TrackValue::Step(_) => AccumEntry::Step {
+                value: v.clone(),
+                w,
+            },
         }
     }
 
@@ -332,17 +397,27 @@ impl AccumEntry {
                     None
                 }
             }
-            AccumEntry::Step(v) => Some(v.into()),
+            AccumEntry::Step { value, .. } => Some(value.into()),
         }
     }
 }
 
 /// Accumulates per-handle contributions across instances, tracking both values and optional
 /// derivatives so the engine can emit aligned `(Value, Option<Value>)` pairs.
+///
+/// Contributions of incompatible kinds to one handle (e.g. a scalar and a Vec3, or vectors of
+/// different lengths) are not blended:
+/// the side with the higher total weight wins (ties keep the earlier one) and a warning naming
+/// the handle and both kinds is recorded.
+///
+/// Handles fed through [`Self::add_with_priority`] are resolved by priority first: only the
+/// highest-priority contributions are kept and blended.
 #[derive(Default)]
 pub struct AccumulatorWithDerivatives {
     values: HashMap<String, AccumEntry>,
     derivatives: HashMap<String, AccumEntry>,
+    priorities: HashMap<String, i32>,
+    warnings: Vec<String>,
 }
 
 impl AccumulatorWithDerivatives {
@@ -362,24 +437,82 @@ impl AccumulatorWithDerivatives {
             return;
         }
 
-        self.values
-            .entry(handle.to_string())
-            .and_modify(|entry| entry.add_value(value, weight))
-            .or_insert_with(|| AccumEntry::from_value(value, weight));
+        match self.values.entry(handle.to_string()) {
+            Entry::Vacant(slot) => {
+                slot.insert(AccumEntry::from_value(value, weight));
+            }
+            Entry::Occupied(mut slot) => {
+                if !slot.get_mut().add_value(value, weight) {
+                    let existing = slot.get();
+                    let incoming_wins = weight > existing.weight();
+                    let existing_kind = existing.describe();
+                    let incoming_kind = describe_track(value);
+                    let (kept, kept_weight) = if incoming_wins {
+                        (&incoming_kind, weight)
+                    } else {
+                        (&existing_kind, existing.weight())
+                    };
+                    self.warnings.push(format!(
+                        "output '{handle}' mixes {existing_kind} and {incoming_kind} values; keeping {kept} (weight {kept_weight})",
+                    ));
+                    if !incoming_wins {
+                        return;
+                    }
+                    slot.insert(AccumEntry::from_value(value, weight));
+                    self.derivatives.remove(handle);
+                }
+            }
+        }
 
         if let Some(deriv) = derivative {
             self.derivatives
                 .entry(handle.to_string())
-                .and_modify(|entry| entry.add_value(deriv, weight))
+                .and_modify(|entry| {
+                    entry.add_value(deriv, weight);
+                })
                 .or_insert_with(|| AccumEntry::from_value(deriv, weight));
         }
     }
 
+    /// Add a contribution that only counts when no higher-priority one was added for `handle`.
+    ///
+    /// A higher `priority` discards everything accumulated for the handle so far; an equal one
+    /// blends by weight as [`Self::add`] does; a lower one is ignored. Feed each handle
+    /// through either this method or [`Self::add`], not both.
+    pub fn add_with_priority(
+        &mut self,
+        handle: &str,
+        value: &TrackValue,
+        derivative: Option<&TrackValue>,
+        weight: f32,
+        priority: i32,
+    ) {
+        if weight <= 0.0 {
+            return;
+        }
+        match self.priorities.get(handle) {
+            Some(&current) if priority < current => return,
+            Some(&current) if priority > current => {
+                self.values.remove(handle);
+                self.derivatives.remove(handle);
+            }
+            _ => {}
+        }
+        self.priorities.insert(handle.to_string(), priority);
+        self.add(handle, value, derivative, weight);
+    }
+
+    /// Drain the kind-conflict warnings recorded since the last call.
+    pub fn take_warnings(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.warnings)
+    }
+
     /// Finalize accumulated values into canonical `(value, derivative)` pairs keyed by handle.
     pub fn finalize(self) -> HashMap<String, (Value, Option<Value>)> {
         let Self {
             values,
             mut derivatives,
+            ..
         } = self;
 
         let mut out = HashMap::with_capacity(values.len());
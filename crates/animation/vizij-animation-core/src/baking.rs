@@ -1,11 +1,15 @@
 #![allow(dead_code)]
 //! Baking API: produce baked samples for an AnimationData clip over a time window.
 
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
 use serde::{Deserialize, Serialize};
 
 use crate::data::AnimationData;
 use crate::ids::AnimId;
 use crate::sampling::{sample_track_with_derivative_epsilon, DEFAULT_DERIVATIVE_EPSILON};
+use crate::value::{TrackValue, Transform};
 use vizij_api_core::Value;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -26,6 +30,14 @@ pub struct BakingConfig {
     ///
     /// Non-finite or non-positive values fall back to the default epsilon.
     pub derivative_epsilon: Option<f32>,
+    /// Emit frames in reverse time order (last sample first) with derivatives negated, as if
+    /// the window were played backwards.
+    #[serde(default)]
+    pub reverse: bool,
+    /// Bit depth (clamped to `1..=16`) for an additional fixed-point encoding of numeric tracks
+    /// in [`BakedTrack::quantized`]; `None` keeps the float samples only.
+    #[serde(default)]
+    pub quantize_bits: Option<u8>,
 }
 
 impl Default for BakingConfig {
@@ -35,6 +47,8 @@ impl Default for BakingConfig {
             start_time: 0.0,
             end_time: None,
             derivative_epsilon: None,
+            reverse: false,
+            quantize_bits: None,
         }
     }
 }
@@ -45,6 +59,101 @@ pub struct BakedTrack {
     pub target_path: String,
     /// Sampled values at each frame.
     pub values: Vec<Value>,
+    /// Fixed-point copy of `values` when [`BakingConfig::quantize_bits`] is set and the track
+    /// is numeric.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quantized: Option<QuantizedTrack>,
+}
+
+/// Fixed-point encoding of one baked track: `value = offset[c] + scale[c] * code` for component
+/// `c`, so each component keeps the full code range over its own span.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct QuantizedTrack {
+    /// Bits used per code.
+    pub bits: u8,
+    /// Numeric components per frame (transforms flatten as translation, rotation, scale).
+    pub components: usize,
+    /// Decode step between consecutive codes, per component.
+    pub scale: Vec<f32>,
+    /// Decoded value of code `0` (the component's minimum), per component.
+    pub offset: Vec<f32>,
+    /// Frame-major codes, `components` per frame.
+    pub data: Vec<u16>,
+}
+
+impl QuantizedTrack {
+    /// Quantize frames of one numeric kind; `None` for step kinds, mixed shapes, or tracks with a
+    /// non-finite sample (which no code can represent).
+    fn encode(values: &[Value], bits: u8) -> Option<Self> {
+        let bits = bits.clamp(1, 16);
+        let mut flat = Vec::new();
+        let mut components = None;
+        for value in values {
+            let parts = TrackValue::from(value.clone()).numeric_components()?;
+            if *components.get_or_insert(parts.len()) != parts.len() {
+                return None;
+            }
+            if parts.iter().any(|v| !v.is_finite()) {
+                return None;
+            }
+            flat.extend(parts);
+        }
+        let components = components.unwrap_or(0);
+        let levels = ((1u32 << bits) - 1) as f32;
+        let mut offset = vec![f32::INFINITY; components];
+        let mut max = vec![f32::NEG_INFINITY; components];
+        for frame in flat.chunks_exact(components.max(1)) {
+            for (c, &v) in frame.iter().enumerate() {
+                offset[c] = offset[c].min(v);
+                max[c] = max[c].max(v);
+            }
+        }
+        // A constant component gets scale 0: every code decodes to its single value.
+        let scale: Vec<f32> = offset
+            .iter()
+            .zip(&max)
+            .map(|(&lo, &hi)| if hi > lo { (hi - lo) / levels } else { 0.0 })
+            .collect();
+        let data = flat
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| {
+                let c = i % components;
+                if scale[c] > 0.0 {
+                    ((v - offset[c]) / scale[c]).round().clamp(0.0, levels) as u16
+                } else {
+                    0
+                }
+            })
+            .collect();
+        Some(Self {
+            bits,
+            components,
+            scale,
+            offset,
+            data,
+        })
+    }
+
+    /// Decode back to frame-major float components.
+    pub fn decode(&self) -> Vec<f32> {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(i, &code)| {
+                let c = i % self.components.max(1);
+                self.offset[c] + self.scale[c] * code as f32
+            })
+            .collect()
+    }
+}
+
+fn quantize_tracks(tracks: &mut [BakedTrack], cfg: &BakingConfig) {
+    if let Some(bits) = cfg.quantize_bits {
+        for track in tracks {
+            track.quantized = QuantizedTrack::encode(&track.values, bits);
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -96,6 +205,223 @@ pub fn bake_animation_data(
     bake_animation_data_with_derivatives(anim_id, data, cfg).0
 }
 
+/// One baked sample time across every track of a clip, as yielded by [`sample_iter`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct BakedFrame {
+    /// Animation the frame was sampled from.
+    pub anim: AnimId,
+    /// Zero-based frame index within the bake window.
+    pub index: usize,
+    /// Clip-space sample time in seconds (descending when baking in reverse).
+    pub time: f32,
+    /// Sampled value per track, in `AnimationData::tracks` order.
+    pub values: Vec<Value>,
+    /// Sampled derivative per track; `None` marks an unavailable derivative sample.
+    pub derivatives: Vec<Option<Value>>,
+}
+
+/// Sample window resolved from a [`BakingConfig`] against one clip.
+struct BakeWindow {
+    frame_rate: f32,
+    start: f32,
+    end: f32,
+    frame_count: usize,
+    duration_s: f32,
+    derivative_epsilon: f32,
+    reverse: bool,
+}
+
+impl BakeWindow {
+    fn resolve(data: &AnimationData, cfg: &BakingConfig) -> Self {
+        let sr = if cfg.frame_rate.is_finite() && cfg.frame_rate > 0.0 {
+            cfg.frame_rate
+        } else {
+            60.0
+        };
+        let sr = sr.max(1.0);
+        let start = cfg.start_time.max(0.0);
+        // Convert canonical duration (ms) to seconds for baking time domain
+        let duration_s = data.duration_ms as f32 / 1000.0;
+        let mut end = cfg.end_time.unwrap_or(duration_s);
+        if !end.is_finite() {
+            end = duration_s;
+        }
+        let end = end.clamp(start, duration_s);
+        let span = end - start;
+        let frames_f = (span * sr).ceil();
+        let frame_count = frames_f as usize + 1; // inclusive of end
+
+        let derivative_epsilon = cfg
+            .derivative_epsilon
+            .filter(|eps| eps.is_finite() && *eps > 0.0)
+            .unwrap_or(DEFAULT_DERIVATIVE_EPSILON);
+
+        Self {
+            frame_rate: sr,
+            start,
+            end,
+            frame_count,
+            duration_s,
+            derivative_epsilon,
+            reverse: cfg.reverse,
+        }
+    }
+}
+
+/// Negate every numeric component of a derivative sample; step kinds pass through.
+fn negate(value: TrackValue) -> TrackValue {
+    fn neg<const N: usize>(mut a: [f32; N]) -> [f32; N] {
+        a.iter_mut().for_each(|c| *c = -*c);
+        a
+    }
+    match value {
+        TrackValue::Float(x) => TrackValue::Float(-x),
+        TrackValue::Vec2(a) => TrackValue::Vec2(neg(a)),
+        TrackValue::Vec3(a) => TrackValue::Vec3(neg(a)),
+        TrackValue::Vec4(a) => TrackValue::Vec4(neg(a)),
+        TrackValue::Quat(a) => TrackValue::Quat(neg(a)),
+        TrackValue::ColorRgba(a) => TrackValue::ColorRgba(neg(a)),
+        TrackValue::Transform(t) => TrackValue::Transform(Transform {
+            translation: neg(t.translation),
+            rotation: neg(t.rotation),
+            scale: neg(t.scale),
+        }),
+        TrackValue::Vector(v) => TrackValue::Vector(v.into_iter().map(|c| -c).collect()),
+        TrackValue::NumericArray(v) => {
+            TrackValue::NumericArray(v.into_iter().map(|c| -c).collect())
+        }
+        other => other,
+    }
+}
+
+/// Lazily sample `data` (the clip registered as `anim`) one frame at a time over the window
+/// described by `cfg`.
+///
+/// Each [`BakedFrame`] holds every track's value and derivative at one sample time, so callers
+/// can stream huge bakes to disk or report progress (the iterator knows its exact length)
+/// without materializing every sample. [`bake_animation_data`] collects this iterator.
+pub fn sample_iter<'a>(
+    anim: AnimId,
+    data: &'a AnimationData,
+    cfg: &BakingConfig,
+) -> impl ExactSizeIterator<Item = BakedFrame> + 'a {
+    let window = BakeWindow::resolve(data, cfg);
+    (0..window.frame_count).map(move |f| {
+        let step = if window.reverse {
+            window.frame_count - 1 - f
+        } else {
+            f
+        };
+        let t = window.start + (step as f32) / window.frame_rate; // seconds in clip space
+        let u = if window.duration_s > 0.0 {
+            (t / window.duration_s).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let mut values = Vec::with_capacity(data.tracks.len());
+        let mut derivatives = Vec::with_capacity(data.tracks.len());
+        for track in &data.tracks {
+            let (v, deriv) = sample_track_with_derivative_epsilon(
+                track,
+                u,
+                window.duration_s,
+                window.derivative_epsilon,
+            );
+            // Encode the POD samples into wire-form Values for the baked artifact.
+            values.push(v.into());
+            let deriv = if window.reverse {
+                deriv.map(negate)
+            } else {
+                deriv
+            };
+            derivatives.push(deriv.map(Value::from));
+        }
+        BakedFrame {
+            anim,
+            index: f,
+            time: t,
+            values,
+            derivatives,
+        }
+    })
+}
+
+/// Cooperative cancellation flag for long-running bakes.
+///
+/// Clones share one flag, so a host can keep a handle and cancel from a progress callback or
+/// another thread; the bake checks it before sampling each frame.
+#[derive(Clone, Debug, Default)]
+pub struct BakeCancelToken(Arc<AtomicBool>);
+
+impl BakeCancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request that any bake observing this token stop before its next frame.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Bake animation values while reporting progress and honouring cancellation.
+///
+/// `progress` receives the completion fraction in `(0, 1]` after each frame. When `cancel` is
+/// set the bake stops before the next frame and returns the frames sampled so far, with the
+/// window narrowed to them: `end_time` lowered to the last baked frame (or `start_time` when
+/// nothing was sampled), or for a [`BakingConfig::reverse`] bake, which samples from the end
+/// backwards, `start_time` raised to it (or to `end_time`).
+pub fn bake_animation_data_with_progress(
+    anim_id: AnimId,
+    data: &AnimationData,
+    cfg: &BakingConfig,
+    mut progress: impl FnMut(f32),
+    cancel: &BakeCancelToken,
+) -> BakedAnimationData {
+    let window = BakeWindow::resolve(data, cfg);
+    let total = window.frame_count as f32;
+
+    let mut tracks: Vec<BakedTrack> = data
+        .tracks
+        .iter()
+        .map(|track| BakedTrack {
+            target_path: track.animatable_id.clone(),
+            values: Vec::with_capacity(window.frame_count),
+            quantized: None,
+        })
+        .collect();
+    let (mut start_time, mut end_time) = (window.start, window.end);
+    let mut last_time = None;
+    for frame in sample_iter(anim_id, data, cfg) {
+        if cancel.is_cancelled() {
+            if window.reverse {
+                start_time = last_time.unwrap_or(window.end);
+            } else {
+                end_time = last_time.unwrap_or(window.start);
+            }
+            break;
+        }
+        for (track, value) in tracks.iter_mut().zip(frame.values) {
+            track.values.push(value);
+        }
+        last_time = Some(frame.time.min(window.end));
+        progress((frame.index + 1) as f32 / total);
+    }
+    quantize_tracks(&mut tracks, cfg);
+
+    BakedAnimationData {
+        anim: anim_id,
+        frame_rate: window.frame_rate,
+        start_time,
+        end_time,
+        tracks,
+    }
+}
+
 /// Bake animation values and derivatives simultaneously.
 ///
 /// The returned time window is expressed in clip seconds even though `AnimationData` stores
@@ -105,70 +431,48 @@ pub fn bake_animation_data_with_derivatives(
     data: &AnimationData,
     cfg: &BakingConfig,
 ) -> (BakedAnimationData, BakedDerivativeAnimationData) {
-    let sr = if cfg.frame_rate.is_finite() && cfg.frame_rate > 0.0 {
-        cfg.frame_rate
-    } else {
-        60.0
-    };
-    let sr = sr.max(1.0);
-    let start = cfg.start_time.max(0.0);
-    // Convert canonical duration (ms) to seconds for baking time domain
-    let duration_s = data.duration_ms as f32 / 1000.0;
-    let mut end = cfg.end_time.unwrap_or(duration_s);
-    if !end.is_finite() {
-        end = duration_s;
-    }
-    let end = end.clamp(start, duration_s);
-    let span = end - start;
-    let frames_f = (span * sr).ceil();
-    let frame_count = frames_f as usize + 1; // inclusive of end
-
-    let derivative_epsilon = cfg
-        .derivative_epsilon
-        .filter(|eps| eps.is_finite() && *eps > 0.0)
-        .unwrap_or(DEFAULT_DERIVATIVE_EPSILON);
-
-    let mut tracks = Vec::with_capacity(data.tracks.len());
-    let mut derivative_tracks = Vec::with_capacity(data.tracks.len());
-    for track in &data.tracks {
-        let mut values = Vec::with_capacity(frame_count);
-        let mut derivatives = Vec::with_capacity(frame_count);
-        for f in 0..frame_count {
-            let t = start + (f as f32) / sr; // seconds in clip space
-            let u = if duration_s > 0.0 {
-                (t / duration_s).clamp(0.0, 1.0)
-            } else {
-                0.0
-            };
-            let (v, deriv) =
-                sample_track_with_derivative_epsilon(track, u, duration_s, derivative_epsilon);
-            // Encode the POD samples into wire-form Values for the baked artifact.
-            values.push(v.into());
-            derivatives.push(deriv.map(Value::from));
-        }
-        tracks.push(BakedTrack {
+    let window = BakeWindow::resolve(data, cfg);
+
+    let mut tracks: Vec<BakedTrack> = data
+        .tracks
+        .iter()
+        .map(|track| BakedTrack {
             target_path: track.animatable_id.clone(),
-            values,
-        });
-        derivative_tracks.push(BakedDerivativeTrack {
+            values: Vec::with_capacity(window.frame_count),
+            quantized: None,
+        })
+        .collect();
+    let mut derivative_tracks: Vec<BakedDerivativeTrack> = data
+        .tracks
+        .iter()
+        .map(|track| BakedDerivativeTrack {
             target_path: track.animatable_id.clone(),
-            values: derivatives,
-        });
+            values: Vec::with_capacity(window.frame_count),
+        })
+        .collect();
+    for frame in sample_iter(anim_id, data, cfg) {
+        for (track, value) in tracks.iter_mut().zip(frame.values) {
+            track.values.push(value);
+        }
+        for (track, derivative) in derivative_tracks.iter_mut().zip(frame.derivatives) {
+            track.values.push(derivative);
+        }
     }
+    quantize_tracks(&mut tracks, cfg);
 
     (
         BakedAnimationData {
             anim: anim_id,
-            frame_rate: sr,
-            start_time: start,
-            end_time: end,
+            frame_rate: window.frame_rate,
+            start_time: window.start,
+            end_time: window.end,
             tracks,
         },
         BakedDerivativeAnimationData {
             anim: anim_id,
-            frame_rate: sr,
-            start_time: start,
-            end_time: end,
+            frame_rate: window.frame_rate,
+            start_time: window.start,
+            end_time: window.end,
             tracks: derivative_tracks,
         },
     )
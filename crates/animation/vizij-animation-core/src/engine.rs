@@ -6,12 +6,12 @@
 
 use crate::accumulate::AccumulatorWithDerivatives;
 use crate::baking::{
-    bake_animation_data, bake_animation_data_with_derivatives, BakedAnimationData,
-    BakedDerivativeAnimationData, BakingConfig,
+    bake_animation_data, bake_animation_data_with_derivatives, bake_animation_data_with_progress,
+    BakeCancelToken, BakedAnimationData, BakedDerivativeAnimationData, BakingConfig,
 };
 use crate::binding::{BindingSet, BindingTable, ChannelKey, TargetResolver};
 use crate::config::Config;
-use crate::data::AnimationData;
+use crate::data::{AnimationData, TimedKeypoint};
 use crate::ids::{AnimId, IdAllocator, InstId, PlayerId};
 use crate::inputs::{Inputs, LoopMode};
 use crate::interp::InterpRegistry;
@@ -80,6 +80,7 @@ pub struct Instance {
     pub time_scale: f32,
     pub start_offset: f32,
     pub enabled: bool,
+    pub priority: i32,
     pub binding_set: BindingSet,
 }
 
@@ -94,6 +95,9 @@ pub struct InstanceCfg {
     pub start_offset: f32,
     /// Whether the instance participates in evaluation.
     pub enabled: bool,
+    /// Layer priority for override keys (see [`Engine::set_key_override`]); ignored elsewhere.
+    #[serde(default)]
+    pub priority: i32,
 }
 
 impl Default for InstanceCfg {
@@ -103,6 +107,7 @@ impl Default for InstanceCfg {
             time_scale: 1.0,
             start_offset: 0.0,
             enabled: true,
+            priority: 0,
         }
     }
 }
@@ -122,6 +127,11 @@ impl AnimLib {
             .iter()
             .find_map(|(a, d)| if *a == id { Some(d) } else { None })
     }
+    fn get_mut(&mut self, id: AnimId) -> Option<&mut AnimationData> {
+        self.items
+            .iter_mut()
+            .find_map(|(a, d)| if *a == id { Some(d) } else { None })
+    }
     fn iter(&self) -> impl Iterator<Item = &(AnimId, AnimationData)> {
         self.items.iter()
     }
@@ -135,6 +145,28 @@ impl AnimLib {
     }
 }
 
+/// Global time-warp curve: maps the engine clock (seconds of unscaled host
+/// time) to a multiplier applied to every player's `dt`.
+pub struct TimeWarp(Box<dyn Fn(f32) -> f32 + Send + Sync>);
+
+impl TimeWarp {
+    /// Wrap a curve `clock -> multiplier`.
+    pub fn new(curve: impl Fn(f32) -> f32 + Send + Sync + 'static) -> Self {
+        Self(Box::new(curve))
+    }
+
+    /// Evaluate the curve at `clock` seconds.
+    pub fn eval(&self, clock: f32) -> f32 {
+        (self.0)(clock)
+    }
+}
+
+impl std::fmt::Debug for TimeWarp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TimeWarp(..)")
+    }
+}
+
 /// Engine (core) with engine-agnostic handle type fixed to String for v1.
 #[derive(Debug)]
 pub struct Engine {
@@ -153,6 +185,15 @@ pub struct Engine {
     // Per-tick outputs
     outputs: Outputs,
     outputs_with_derivatives: OutputsWithDerivatives,
+
+    // Global time control, applied to `dt` before it reaches any player
+    time_scale: f32,
+    time_warp: Option<TimeWarp>,
+    clock: f32,
+    paused: bool,
+
+    // Output keys resolved by instance priority instead of weighted blending
+    override_keys: HashSet<String>,
 }
 
 fn fmod(a: f32, b: f32) -> f32 {
@@ -319,19 +360,192 @@ impl Engine {
             interp: InterpRegistry::new(),
             outputs: Outputs::default(),
             outputs_with_derivatives: OutputsWithDerivatives::default(),
+            time_scale: 1.0,
+            time_warp: None,
+            clock: 0.0,
+            paused: false,
+            override_keys: HashSet::new(),
         }
     }
 
+    /// Set the global time scale multiplying every `dt` before players advance.
+    ///
+    /// Unlike per-player speed or per-instance `time_scale`, this affects all players at once
+    /// (e.g. `0.5` for engine-wide slow motion). Defaults to `1.0`.
+    pub fn set_time_scale(&mut self, scale: f32) {
+        self.time_scale = scale;
+    }
+
+    /// Current global time scale.
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale
+    }
+
+    /// Install a global time-warp curve, or clear it with `None`.
+    ///
+    /// Each update evaluates the curve at the engine clock (the sum of unscaled `dt`s seen so
+    /// far, before this update) and multiplies `dt` by the result on top of the time scale,
+    /// which allows slow-motion ramps without per-frame host bookkeeping.
+    pub fn set_time_warp(&mut self, warp: Option<TimeWarp>) {
+        self.time_warp = warp;
+    }
+
+    /// Freeze every player without touching their individual playback state.
+    ///
+    /// While paused, updates still apply inputs and produce outputs for the current time, but
+    /// treat `dt` as zero (the engine clock driving any time-warp curve is frozen too).
+    pub fn pause_all(&mut self) {
+        self.paused = true;
+    }
+
+    /// Undo [`Self::pause_all`]; players continue from the time they were frozen at.
+    pub fn resume_all(&mut self) {
+        self.paused = false;
+    }
+
+    /// Resolve output `key` by instance priority (`enabled`) or by weighted blending (the default).
+    ///
+    /// Within a player, the contributions to an override key come only from the instances with
+    /// the highest [`InstanceCfg::priority`] writing it; those still blend by weight among
+    /// themselves. `key` is the output key as it appears in changes (the resolved handle after
+    /// prebinding, otherwise the track's canonical path).
+    pub fn set_key_override(&mut self, key: &str, enabled: bool) {
+        if enabled {
+            self.override_keys.insert(key.to_string());
+        } else {
+            self.override_keys.remove(key);
+        }
+    }
+
+    /// Whether [`Self::pause_all`] is in effect.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// `dt` after the global pause, time scale, and time-warp curve; advances the engine clock.
+    fn scaled_dt(&mut self, dt: f32) -> f32 {
+        if self.paused {
+            return 0.0;
+        }
+        let warp = self
+            .time_warp
+            .as_ref()
+            .map_or(1.0, |warp| warp.eval(self.clock));
+        self.clock += dt;
+        dt * self.time_scale * warp
+    }
+
     /// Load animation data into the engine and return its assigned [`AnimId`].
     ///
     /// The engine stores the clip internally and stamps the allocated id into `data.id`.
+    /// Degree-annotated tracks are converted to radians first.
     pub fn load_animation(&mut self, mut data: AnimationData) -> AnimId {
         let id = self.ids.alloc_anim();
         data.id = Some(id);
+        data.normalize_units();
         self.anims.insert(id, data);
         id
     }
 
+    /// Merge two loaded animations track-wise and load the result as a new clip.
+    ///
+    /// The inputs stay loaded. See [`AnimationData::merge`] for duration and conflict rules.
+    pub fn merge_animations(
+        &mut self,
+        a: AnimId,
+        b: AnimId,
+        overwrite: bool,
+    ) -> Result<AnimId, String> {
+        let first = self
+            .anims
+            .get(a)
+            .cloned()
+            .ok_or_else(|| format!("merge_animations: unknown animation id {}", a.0))?;
+        let second = self
+            .anims
+            .get(b)
+            .cloned()
+            .ok_or_else(|| format!("merge_animations: unknown animation id {}", b.0))?;
+        let merged = first.merge(second, overwrite)?;
+        Ok(self.load_animation(merged))
+    }
+
+    /// Extract `[start_s, end_s]` of a loaded animation and load it as a new clip.
+    ///
+    /// See [`AnimationData::subclip`]; errors when `anim` is unknown or the clamped range is
+    /// empty.
+    pub fn subclip_animation(
+        &mut self,
+        anim: AnimId,
+        start_s: f32,
+        end_s: f32,
+    ) -> Result<AnimId, String> {
+        let data = self
+            .anims
+            .get(anim)
+            .ok_or_else(|| format!("subclip: unknown animation id {}", anim.0))?;
+        let clip = data.subclip(start_s, end_s);
+        clip.validate_basic().map_err(|e| format!("subclip: {e}"))?;
+        Ok(self.load_animation(clip))
+    }
+
+    /// Simplify a loaded animation within `tolerance` and load the result as a new clip.
+    ///
+    /// See [`AnimationData::simplify`]; errors when `anim` is unknown.
+    pub fn simplify_animation(&mut self, anim: AnimId, tolerance: f32) -> Result<AnimId, String> {
+        let data = self
+            .anims
+            .get(anim)
+            .ok_or_else(|| format!("simplify: unknown animation id {}", anim.0))?;
+        let simplified = data.simplify(tolerance);
+        Ok(self.load_animation(simplified))
+    }
+
+    /// Resample a loaded animation onto a uniform `fps` keypoint grid and load it as a new clip.
+    ///
+    /// See [`AnimationData::resample_uniform`]; errors when `anim` is unknown or `fps` is not a
+    /// positive, finite rate.
+    pub fn resample_animation(&mut self, anim: AnimId, fps: f32) -> Result<AnimId, String> {
+        if !fps.is_finite() || fps <= 0.0 {
+            return Err(format!(
+                "resample: fps must be positive and finite, got {fps}"
+            ));
+        }
+        let data = self
+            .anims
+            .get(anim)
+            .ok_or_else(|| format!("resample: unknown animation id {}", anim.0))?;
+        let resampled = data.resample_uniform(fps);
+        Ok(self.load_animation(resampled))
+    }
+
+    /// Stream keypoints into a track of a loaded animation in place.
+    ///
+    /// See [`AnimationData::append_keypoints`] for ordering and duration rules; errors when
+    /// `anim` is unknown. Player lengths are recomputed when the clip grows, so instances
+    /// already playing it reach the new region.
+    pub fn append_keypoints(
+        &mut self,
+        anim: AnimId,
+        track_path: &str,
+        points: Vec<TimedKeypoint>,
+        sort: bool,
+    ) -> Result<(), String> {
+        let data = self
+            .anims
+            .get_mut(anim)
+            .ok_or_else(|| format!("append_keypoints: unknown animation id {}", anim.0))?;
+        let before_ms = data.duration_ms;
+        data.append_keypoints(track_path, points, sort)?;
+        if data.duration_ms != before_ms {
+            let player_ids: Vec<PlayerId> = self.players.iter().map(|p| p.id).collect();
+            for pid in player_ids {
+                self.recalc_player_duration(pid);
+            }
+        }
+        Ok(())
+    }
+
     /// Bake a loaded animation into per-frame samples using the provided config.
     ///
     /// Returns `None` when `anim` is not currently loaded.
@@ -341,6 +555,22 @@ impl Engine {
             .map(|data| bake_animation_data(anim, data, cfg))
     }
 
+    /// Bake a loaded animation while reporting progress and honouring `cancel`.
+    ///
+    /// See [`bake_animation_data_with_progress`] for the partial-result contract. Returns `None`
+    /// when `anim` is not currently loaded.
+    pub fn bake_animation_with_progress(
+        &self,
+        anim: AnimId,
+        cfg: &BakingConfig,
+        progress: impl FnMut(f32),
+        cancel: &BakeCancelToken,
+    ) -> Option<BakedAnimationData> {
+        self.anims
+            .get(anim)
+            .map(|data| bake_animation_data_with_progress(anim, data, cfg, progress, cancel))
+    }
+
     /// Bake animation values and derivatives in one pass.
     ///
     /// Returns `None` when `anim` is not currently loaded.
@@ -386,6 +616,7 @@ impl Engine {
             time_scale: cfg.time_scale,
             start_offset: cfg.start_offset,
             enabled: cfg.enabled,
+            priority: cfg.priority,
             binding_set,
         };
         self.instances.push(instance);
@@ -610,6 +841,7 @@ impl Engine {
         }
 
         self.apply_inputs(inputs);
+        let dt = self.scaled_dt(dt);
         self.advance_player_times(dt);
 
         for p in &self.players {
@@ -652,12 +884,23 @@ impl Engine {
                             } else {
                                 track.animatable_id.as_str()
                             };
-                            accum.add(handle, &value, derivative.as_ref(), inst.weight);
+                            if self.override_keys.contains(handle) {
+                                accum.add_with_priority(
+                                    handle,
+                                    &value,
+                                    derivative.as_ref(),
+                                    inst.weight,
+                                    inst.priority,
+                                );
+                            } else {
+                                accum.add(handle, &value, derivative.as_ref(), inst.weight);
+                            }
                         }
                     }
                 }
             }
 
+            self.outputs.warnings.extend(accum.take_warnings());
             let blended = accum.finalize();
             if with_derivatives {
                 for (key, (value, derivative)) in blended.into_iter() {
@@ -687,6 +930,7 @@ impl Engine {
 
         if with_derivatives {
             self.outputs_with_derivatives.events = self.outputs.events.clone();
+            self.outputs_with_derivatives.warnings = self.outputs.warnings.clone();
         }
     }
 
@@ -728,6 +972,13 @@ impl Engine {
         self.outputs.to_writebatch()
     }
 
+    /// Clear the output buffers, dropping whatever a step had written before it was interrupted
+    /// (e.g. by a panic caught at the host boundary). The next update starts from empty buffers.
+    pub fn discard_outputs(&mut self) {
+        self.outputs.clear();
+        self.outputs_with_derivatives.clear();
+    }
+
     /// Remove an instance from a player.
     ///
     /// Returns `true` only when the instance was attached to `player` and was removed.
@@ -866,6 +1117,7 @@ impl Engine {
                         time_scale: ii.time_scale,
                         start_offset: ii.start_offset,
                         enabled: ii.enabled,
+                        priority: ii.priority,
                     },
                 })
                 .collect()
@@ -874,6 +1126,19 @@ impl Engine {
         }
     }
 
+    /// Effective duration of `player` in seconds, for drawing timeline bounds.
+    ///
+    /// `LoopMode::Once` players report the latest instance end (`start_offset + clip duration *
+    /// |time_scale|`), clamped to the playback window like [`PlayerInfo::length`].
+    /// `Loop`/`PingPong` players never end and report `f32::INFINITY`. Unknown players report 0.
+    pub fn player_duration(&self, player: PlayerId) -> f32 {
+        match self.players.iter().find(|p| p.id == player) {
+            Some(p) if p.mode == LoopMode::Once => p.total_duration,
+            Some(_) => f32::INFINITY,
+            None => 0.0,
+        }
+    }
+
     /// List the set of resolved output keys currently associated with the player's instances.
     /// Keys match those produced in Outputs (bound handle if available, else canonical track path).
     pub fn list_player_keys(&self, player: PlayerId) -> Vec<String> {
@@ -916,6 +1181,24 @@ impl Engine {
             .find(|i| i.id == inst)
             .map(|i| i.binding_set.channels.clone())
     }
+
+    /// List every channel of every loaded animation with its bound target handle.
+    ///
+    /// Channels come in load order, then track order. `None` marks a channel with no binding
+    /// row (never prebound, or the resolver returned `None` for its path); such channels write
+    /// to their canonical track path instead.
+    pub fn binding_report(&self) -> Vec<(ChannelKey, Option<String>)> {
+        self.anims
+            .iter()
+            .flat_map(|(anim, data)| {
+                (0..data.tracks.len()).map(move |idx| ChannelKey {
+                    anim: *anim,
+                    track_idx: idx as u32,
+                })
+            })
+            .map(|ch| (ch, self.binds.get(ch).map(|row| row.handle.clone())))
+            .collect()
+    }
 }
 
 #[cfg(test)]
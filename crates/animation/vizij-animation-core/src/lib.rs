@@ -37,18 +37,22 @@ pub mod value;
 // Re-exports for consumers (adapters)
 /// Baking helpers and exported baked-data contracts.
 pub use baking::{
-    bake_animation_data, bake_animation_data_with_derivatives, export_baked_json,
-    export_baked_with_derivatives_json, BakedAnimationData, BakedDerivativeAnimationData,
-    BakedDerivativeTrack, BakingConfig,
+    bake_animation_data, bake_animation_data_with_derivatives, bake_animation_data_with_progress,
+    export_baked_json, export_baked_with_derivatives_json, sample_iter, BakeCancelToken,
+    BakedAnimationData, BakedDerivativeAnimationData, BakedDerivativeTrack, BakedFrame,
+    BakingConfig, QuantizedTrack,
 };
 /// Binding traits and table types used by host adapters.
 pub use binding::{BindingSet, BindingTable, ChannelKey, TargetHandle, TargetResolver};
 /// Engine configuration.
 pub use config::Config;
 /// Canonical animation clip data types.
-pub use data::{AnimationData, Keypoint, Track, Transitions, Vec2};
+pub use data::{
+    AnimationData, AnimationDataBuilder, InterpolationType, Keypoint, TimedKeypoint, Track,
+    TrackUnits, Transitions, Vec2,
+};
 /// Playback engine and its inspection/configuration helpers.
-pub use engine::{Engine, InstanceCfg, Player, PrebindReport};
+pub use engine::{Engine, InstanceCfg, Player, PrebindReport, TimeWarp};
 /// Strongly typed ids for the animation runtime.
 pub use ids::{AnimId, InstId, PlayerId};
 /// Per-tick command/update inputs.
@@ -67,3 +71,5 @@ pub use stored_animation::parse_stored_animation_json;
 pub use value::TrackValue;
 /// The shared runtime value used at the output boundary.
 pub use vizij_api_core::Value;
+/// Vocabulary kinds, declared per track by [`AnimationDataBuilder::track`].
+pub use vizij_api_core::VizijKind;
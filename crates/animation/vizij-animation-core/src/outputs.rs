@@ -95,6 +95,9 @@ pub struct Outputs {
     /// Semantic events emitted during the tick.
     #[serde(default)]
     pub events: Vec<CoreEvent>,
+    /// Diagnostics for the tick, e.g. instances writing incompatible value kinds to one key.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 }
 
 /// Outputs returned when derivatives are requested.
@@ -106,6 +109,9 @@ pub struct OutputsWithDerivatives {
     /// Semantic events emitted during the tick.
     #[serde(default)]
     pub events: Vec<CoreEvent>,
+    /// Diagnostics for the tick, e.g. instances writing incompatible value kinds to one key.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 }
 
 impl Outputs {
@@ -113,6 +119,7 @@ impl Outputs {
     pub fn clear(&mut self) {
         self.changes.clear();
         self.events.clear();
+        self.warnings.clear();
     }
 
     #[inline]
@@ -127,7 +134,7 @@ impl Outputs {
 
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.changes.is_empty() && self.events.is_empty()
+        self.changes.is_empty() && self.events.is_empty() && self.warnings.is_empty()
     }
 
     /// Convert the current set of changes into a [`WriteBatch`], parsing each
@@ -151,6 +158,7 @@ impl OutputsWithDerivatives {
     pub fn clear(&mut self) {
         self.changes.clear();
         self.events.clear();
+        self.warnings.clear();
     }
 
     #[inline]
@@ -165,7 +173,7 @@ impl OutputsWithDerivatives {
 
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.changes.is_empty() && self.events.is_empty()
+        self.changes.is_empty() && self.events.is_empty() && self.warnings.is_empty()
     }
 }
 
@@ -7,6 +7,8 @@
 //! - Segment [Pi -> P(i+1)] timing is a cubic-bezier determined by:
 //!   cp0 = Pi.transitions.out or default {x:0.42, y:0.0}
 //!   cp1 = P(i+1).transitions.in or default {x:0.58, y:1.0}
+//!   unless Pi.interpolation is an explicit `Bezier { p1, p2 }`, whose control
+//!   points are used as-is.
 //! - Bool/Text kinds use true step behavior (hold left).
 //! - All other kinds use bezier easing on time, then linear/nlerp blend on
 //!   value (step-only kinds hold left through the blend fallback).
@@ -14,7 +16,7 @@
 //! API:
 //! - `sample_track(&Track, u)` where `u` is normalized time in `[0, 1]` over the whole clip.
 
-use crate::data::{Keypoint, Track};
+use crate::data::{InterpolationType, Keypoint, Track};
 use crate::interp::functions::{bezier_value, step_value};
 use crate::value::{TrackValue, Transform};
 
@@ -174,6 +176,10 @@ pub fn sample_track(track: &Track, u: f32) -> TrackValue {
                 return step_value(&left.value);
             }
 
+            if let Some(InterpolationType::Bezier { p1, p2 }) = left.interpolation {
+                return bezier_value(&left.value, &right.value, lt, [p1.x, p1.y, p2.x, p2.y]);
+            }
+
             // Derive per-segment cubic-bezier control points from keypoint transitions.
             let (x1, y1) = left
                 .transitions
@@ -2,7 +2,9 @@
 
 use serde::Deserialize;
 
-use crate::data::{AnimationData, Keypoint, Track, TrackSettings, Transitions};
+use crate::data::{
+    AnimationData, InterpolationType, Keypoint, Track, TrackSettings, TrackUnits, Transitions,
+};
 use crate::ids::AnimId;
 use crate::value::{TrackValue, Transform};
 
@@ -13,8 +15,11 @@ use crate::value::{TrackValue, Transform};
 /// - Duration is provided in milliseconds in the JSON and kept as milliseconds (duration_ms).
 /// - Keypoint stamps are normalized in `[0, 1]` and kept normalized.
 /// - Per-keypoint transitions { in?, out? } are preserved; defaults are applied at sampling time.
+/// - An optional per-keypoint `interpolation` (`{ "type": "bezier", p1, p2 }`) is preserved and
+///   overrides the transitions for the segment leaving that keypoint.
 /// - Values are decoded from untagged RawValue shapes into POD TrackValues,
 ///   the typed track storage the sampling kernel computes on.
+/// - Tracks annotated `"units": "degrees"` are converted to radians.
 pub fn parse_stored_animation_json(s: &str) -> Result<AnimationData, String> {
     let sa: StoredAnimation = serde_json::from_str(s).map_err(|e| format!("parse error: {e}"))?;
 
@@ -38,6 +43,7 @@ pub fn parse_stored_animation_json(s: &str) -> Result<AnimationData, String> {
                 stamp: p.stamp as f32,
                 value,
                 transitions,
+                interpolation: p.interpolation,
             });
         }
 
@@ -47,10 +53,11 @@ pub fn parse_stored_animation_json(s: &str) -> Result<AnimationData, String> {
             animatable_id: st.animatable_id,
             points,
             settings: st.settings.map(|s| TrackSettings { color: s.color }),
+            units: st.units,
         });
     }
 
-    let data = AnimationData {
+    let mut data = AnimationData {
         id: None::<AnimId>,
         name: sa.name,
         tracks,
@@ -59,6 +66,7 @@ pub fn parse_stored_animation_json(s: &str) -> Result<AnimationData, String> {
     };
     // Basic validation (stamps in [0,1], non-decreasing, duration_ms > 0)
     data.validate_basic()?;
+    data.normalize_units();
     Ok(data)
 }
 
@@ -161,6 +169,8 @@ struct SaTrack {
     pub animatable_id: String,
     pub points: Vec<SaPoint>,
     pub settings: Option<SaSettings>,
+    #[serde(default)]
+    pub units: Option<TrackUnits>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -174,6 +184,8 @@ struct SaPoint {
     pub stamp: f64, // 0..1
     pub value: RawValue,
     pub transitions: Option<PointTransitions>,
+    #[serde(default)]
+    pub interpolation: Option<InterpolationType>,
 }
 
 #[derive(Debug, Copy, Clone, Deserialize)]
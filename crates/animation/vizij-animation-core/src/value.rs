@@ -10,7 +10,7 @@
 use serde::{Deserialize, Serialize};
 use vizij_api_core::value as vocab;
 
-pub use vizij_api_core::{Transform, Value};
+pub use vizij_api_core::{Transform, Value, VizijKind};
 
 /// Keyframe value in plain-Rust form.
 ///
@@ -58,6 +58,47 @@ impl TrackValue {
     pub fn to_value(&self) -> Value {
         Value::from(self.clone())
     }
+
+    /// The vocabulary kind [`Self::to_value`] encodes as.
+    pub fn kind(&self) -> VizijKind {
+        match self {
+            TrackValue::Float(_) => VizijKind::Float,
+            TrackValue::Vec2(_) => VizijKind::Vec2,
+            TrackValue::Vec3(_) => VizijKind::Vec3,
+            TrackValue::Vec4(_) => VizijKind::Vec4,
+            TrackValue::Quat(_) => VizijKind::Quat,
+            TrackValue::ColorRgba(_) => VizijKind::ColorRgba,
+            TrackValue::Transform(_) => VizijKind::Transform,
+            TrackValue::Vector(_) => VizijKind::Vector,
+            TrackValue::NumericArray(_) => VizijKind::Array,
+            TrackValue::Bool(_) => VizijKind::Bool,
+            TrackValue::Text(_) => VizijKind::Text,
+            TrackValue::Step(value) => vocab::kind(value),
+        }
+    }
+
+    /// Flattened numeric components (transforms as translation, rotation, scale), or `None`
+    /// for step-only kinds.
+    pub(crate) fn numeric_components(&self) -> Option<Vec<f32>> {
+        match self {
+            TrackValue::Float(x) => Some(vec![*x]),
+            TrackValue::Vec2(a) => Some(a.to_vec()),
+            TrackValue::Vec3(a) => Some(a.to_vec()),
+            TrackValue::Vec4(a) | TrackValue::Quat(a) | TrackValue::ColorRgba(a) => {
+                Some(a.to_vec())
+            }
+            TrackValue::Transform(t) => Some(
+                t.translation
+                    .iter()
+                    .chain(&t.rotation)
+                    .chain(&t.scale)
+                    .copied()
+                    .collect(),
+            ),
+            TrackValue::Vector(v) | TrackValue::NumericArray(v) => Some(v.clone()),
+            TrackValue::Bool(_) | TrackValue::Text(_) | TrackValue::Step(_) => None,
+        }
+    }
 }
 
 impl From<Value> for TrackValue {
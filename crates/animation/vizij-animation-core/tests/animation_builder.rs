@@ -0,0 +1,96 @@
+use vizij_animation_core::{sample_track, AnimationData, TrackValue, Transitions, Vec2, VizijKind};
+
+fn components(value: &TrackValue) -> Vec<f32> {
+    match value {
+        TrackValue::Float(x) => vec![*x],
+        TrackValue::Vec3(v) => v.to_vec(),
+        other => panic!("unexpected sample {other:?}"),
+    }
+}
+
+/// it should build a two-track clip whose samples hit each keypoint value
+#[test]
+fn builder_two_track_clip_samples_keypoints() {
+    let anim = AnimationData::builder()
+        .name("pose")
+        .duration_ms(2000)
+        .track("node/Transform.translation", VizijKind::Vec3)
+        .keypoint(0.0, TrackValue::Vec3([0.0, 0.0, 0.0]))
+        .transition(Transitions {
+            r#in: None,
+            r#out: Some(Vec2 { x: 0.0, y: 0.0 }),
+        })
+        .keypoint(1.0, TrackValue::Vec3([2.0, 4.0, 6.0]))
+        .track("node/weight", VizijKind::Float)
+        .keypoint(0.0, TrackValue::Float(1.0))
+        .keypoint(0.5, TrackValue::Float(3.0))
+        .keypoint(1.0, TrackValue::Float(-1.0))
+        .build()
+        .expect("valid clip");
+
+    assert_eq!(anim.name, "pose");
+    assert_eq!(anim.duration_ms, 2000);
+    assert_eq!(anim.tracks.len(), 2);
+    assert_eq!(anim.tracks[0].animatable_id, "node/Transform.translation");
+    assert!(anim.tracks[0].points[0].transitions.is_some());
+
+    for track in &anim.tracks {
+        for point in &track.points {
+            let sampled = components(&sample_track(track, point.stamp));
+            for (got, want) in sampled.iter().zip(components(&point.value)) {
+                assert!((got - want).abs() <= 1e-4, "{got} vs {want}");
+            }
+        }
+    }
+}
+
+/// it should reject empty tracks, unsorted keypoints, orphan keypoints, and undeclared kinds
+#[test]
+fn builder_rejects_invalid_clips() {
+    let no_tracks = AnimationData::builder().duration_ms(1000).build();
+    assert!(no_tracks.is_err());
+
+    let empty_track = AnimationData::builder()
+        .duration_ms(1000)
+        .track("a", VizijKind::Float)
+        .build()
+        .unwrap_err();
+    assert!(empty_track.contains("'a'"), "{empty_track}");
+
+    let unsorted = AnimationData::builder()
+        .duration_ms(1000)
+        .track("a", VizijKind::Float)
+        .keypoint(0.8, TrackValue::Float(0.0))
+        .keypoint(0.2, TrackValue::Float(1.0))
+        .build();
+    assert!(unsorted.is_err());
+
+    let orphan = AnimationData::builder()
+        .duration_ms(1000)
+        .keypoint(0.0, TrackValue::Float(0.0))
+        .track("a", VizijKind::Float)
+        .keypoint(0.0, TrackValue::Float(0.0))
+        .build();
+    assert!(orphan.is_err());
+
+    let mixed = AnimationData::builder()
+        .duration_ms(1000)
+        .track("a", VizijKind::Float)
+        .keypoint(0.0, TrackValue::Float(0.0))
+        .keypoint(1.0, TrackValue::Bool(true))
+        .build()
+        .unwrap_err();
+    assert!(mixed.contains("keypoint 1 is Bool"), "{mixed}");
+
+    let undeclared = AnimationData::builder()
+        .duration_ms(1000)
+        .track("a", VizijKind::Vec3)
+        .keypoint(0.0, TrackValue::Float(0.0))
+        .keypoint(1.0, TrackValue::Float(1.0))
+        .build()
+        .unwrap_err();
+    assert!(
+        undeclared.contains("track 'a' declares Vec3 but keypoint 0 is Float"),
+        "{undeclared}"
+    );
+}
@@ -17,6 +17,7 @@ fn mk_bool_track(path: &str, keys: &[(f32, bool)]) -> Track {
             stamp: *stamp,
             value: TrackValue::Bool(*v),
             transitions: None,
+            interpolation: None,
         });
     }
     Track {
@@ -25,6 +26,7 @@ fn mk_bool_track(path: &str, keys: &[(f32, bool)]) -> Track {
         animatable_id: path.to_string(),
         points,
         settings: None,
+        units: None,
     }
 }
 
@@ -36,6 +38,7 @@ fn mk_text_track(path: &str, keys: &[(f32, &str)]) -> Track {
             stamp: *stamp,
             value: TrackValue::Text((*s).to_string()),
             transitions: None,
+            interpolation: None,
         });
     }
     Track {
@@ -44,6 +47,7 @@ fn mk_text_track(path: &str, keys: &[(f32, &str)]) -> Track {
         animatable_id: path.to_string(),
         points,
         settings: None,
+        units: None,
     }
 }
 
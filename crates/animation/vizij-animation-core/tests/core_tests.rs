@@ -2,9 +2,9 @@
 use vizij_animation_core::{
     accumulate::AccumulatorWithDerivatives,
     baking::{export_baked_json, BakingConfig},
-    binding::TargetResolver,
+    binding::{ChannelKey, TargetResolver},
     config::Config,
-    data::{AnimationData, Keypoint, Track, Transitions, Vec2},
+    data::{AnimationData, Keypoint, TimedKeypoint, Track, Transitions, Vec2},
     engine::{Engine, InstanceCfg},
     ids::{AnimId, IdAllocator, PlayerId},
     inputs::{Inputs, InstanceUpdate, LoopMode, PlayerCommand},
@@ -12,7 +12,7 @@ use vizij_animation_core::{
     sampling::{sample_track, sample_track_with_derivative},
     value::TrackValue,
 };
-use vizij_api_core::value::{as_quat, Value};
+use vizij_api_core::value::{as_quat, as_vec3, Value};
 
 fn approx(a: f32, b: f32, eps: f32) {
     assert!((a - b).abs() <= eps, "left={a} right={b} eps={eps}");
@@ -51,6 +51,7 @@ fn mk_scalar_track_linear(path: &str, keys: &[(f32, f32)]) -> Track {
             stamp: *stamp,
             value: TrackValue::Float(*v),
             transitions,
+            interpolation: None,
         });
     }
     Track {
@@ -59,6 +60,7 @@ fn mk_scalar_track_linear(path: &str, keys: &[(f32, f32)]) -> Track {
         animatable_id: path.to_string(),
         points,
         settings: None,
+        units: None,
     }
 }
 
@@ -88,6 +90,7 @@ fn mk_quat_track_linear(path: &str, keys: &[(f32, [f32; 4])]) -> Track {
             stamp: *stamp,
             value: TrackValue::Quat(*q),
             transitions,
+            interpolation: None,
         });
     }
     Track {
@@ -96,6 +99,7 @@ fn mk_quat_track_linear(path: &str, keys: &[(f32, [f32; 4])]) -> Track {
         animatable_id: path.to_string(),
         points,
         settings: None,
+        units: None,
     }
 }
 
@@ -164,15 +168,18 @@ fn sampling_linear_step_bezier() {
                 stamp: 0.0,
                 value: TrackValue::Bool(true),
                 transitions: None,
+                interpolation: None,
             },
             Keypoint {
                 id: "k1".into(),
                 stamp: 1.0,
                 value: TrackValue::Bool(false),
                 transitions: None,
+                interpolation: None,
             },
         ],
         settings: None,
+        units: None,
     };
     if let TrackValue::Bool(v) = sample_track(&bool_track, 0.5) {
         assert!(v);
@@ -191,15 +198,18 @@ fn sampling_linear_step_bezier() {
                 stamp: 0.0,
                 value: TrackValue::Float(0.0),
                 transitions: None,
+                interpolation: None,
             },
             Keypoint {
                 id: "k1".into(),
                 stamp: 1.0,
                 value: TrackValue::Float(1.0),
                 transitions: None,
+                interpolation: None,
             },
         ],
         settings: None,
+        units: None,
     };
     if let TrackValue::Float(v) = sample_track(&track_bezier_default, 0.5) {
         assert!(v > 0.4 && v < 0.6, "bezier mid expected near 0.5 got {v}");
@@ -245,6 +255,132 @@ fn accumulates_vector_values_componentwise() {
     }
 }
 
+/// it should keep the heavier side and warn when contributions of different kinds share a key
+#[test]
+fn accumulate_kind_conflict_keeps_heavier_side_and_warns() {
+    let mut acc = AccumulatorWithDerivatives::new();
+    acc.add("mixed", &TrackValue::Float(1.0), None, 0.25);
+    acc.add("mixed", &TrackValue::Vec3([1.0, 2.0, 3.0]), None, 0.75);
+    let warnings = acc.take_warnings();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("'mixed'"), "{}", warnings[0]);
+    assert!(warnings[0].contains("float") && warnings[0].contains("vec3"));
+    let finalized = acc.finalize();
+    let (value, _) = finalized.get("mixed").expect("mixed entry");
+    assert_eq!(value, &vizij_api_core::value::vec3([1.0, 2.0, 3.0]));
+
+    let mut acc = AccumulatorWithDerivatives::new();
+    acc.add("mixed", &TrackValue::Float(1.0), None, 1.0);
+    acc.add("mixed", &TrackValue::Vec3([1.0, 2.0, 3.0]), None, 0.5);
+    assert_eq!(acc.take_warnings().len(), 1);
+    let finalized = acc.finalize();
+    assert_eq!(finalized["mixed"].0, vizij_api_core::value::float(1.0));
+}
+
+/// it should treat vectors of different lengths as a conflict instead of truncating either
+#[test]
+fn accumulate_collection_length_mismatch_warns() {
+    let mut acc = AccumulatorWithDerivatives::new();
+    acc.add("pose", &TrackValue::Vector(vec![1.0, 2.0, 3.0]), None, 1.0);
+    acc.add("pose", &TrackValue::Vector(vec![4.0, 5.0]), None, 0.5);
+    let warnings = acc.take_warnings();
+    assert_eq!(warnings.len(), 1, "{warnings:?}");
+    assert!(
+        warnings[0].contains("vector[3]") && warnings[0].contains("vector[2]"),
+        "{}",
+        warnings[0]
+    );
+    let finalized = acc.finalize();
+    assert_eq!(
+        finalized["pose"].0,
+        vizij_api_core::value::vector(vec![1.0, 2.0, 3.0])
+    );
+}
+
+/// it should report a warning when two instances write different kinds to one key
+#[test]
+fn engine_reports_mixed_kind_warning() {
+    let scalar = mk_scalar_track_linear("node.mixed", &[(0.0, 0.0), (1.0, 1.0)]);
+    let quat = mk_quat_track_linear(
+        "node.mixed",
+        &[(0.0, [0.0, 0.0, 0.0, 1.0]), (1.0, [0.0, 0.0, 0.0, 1.0])],
+    );
+    let mut eng = Engine::new(Config::default());
+    let a_scalar = eng.load_animation(mk_anim("scalar", 1.0, vec![scalar]));
+    let a_quat = eng.load_animation(mk_anim("quat", 1.0, vec![quat]));
+    let p = eng.create_player("p");
+    eng.add_instance(p, a_scalar, InstanceCfg::default());
+    eng.add_instance(
+        p,
+        a_quat,
+        InstanceCfg {
+            weight: 2.0,
+            ..Default::default()
+        },
+    );
+
+    let out = eng.update_values(0.0, Inputs::default());
+    assert_eq!(out.warnings.len(), 1, "{:?}", out.warnings);
+    assert!(out.warnings[0].contains("node.mixed"));
+    let change = out
+        .changes
+        .iter()
+        .find(|c| c.key == "node.mixed")
+        .expect("mixed change");
+    assert!(as_quat(&change.value).is_some());
+
+    let with_derivs = eng.update_values_and_derivatives(0.0, Inputs::default());
+    assert_eq!(with_derivs.warnings.len(), 1);
+}
+
+/// it should let the highest-priority instance win override keys while other keys still blend
+#[test]
+fn override_keys_resolve_by_instance_priority() {
+    let base = mk_anim(
+        "base",
+        1.0,
+        vec![
+            mk_scalar_track_linear("node.a", &[(0.0, 1.0), (1.0, 1.0)]),
+            mk_scalar_track_linear("node.b", &[(0.0, 10.0), (1.0, 10.0)]),
+        ],
+    );
+    let pose = mk_anim(
+        "pose",
+        1.0,
+        vec![
+            mk_scalar_track_linear("node.a", &[(0.0, 3.0), (1.0, 3.0)]),
+            mk_scalar_track_linear("node.b", &[(0.0, 30.0), (1.0, 30.0)]),
+        ],
+    );
+    let mut eng = Engine::new(Config::default());
+    let a_base = eng.load_animation(base);
+    let a_pose = eng.load_animation(pose);
+    let p = eng.create_player("p");
+    // The override is added first so the result cannot depend on evaluation order.
+    eng.add_instance(
+        p,
+        a_pose,
+        InstanceCfg {
+            priority: 5,
+            ..Default::default()
+        },
+    );
+    eng.add_instance(p, a_base, InstanceCfg::default());
+    eng.set_key_override("node.a", true);
+
+    let value_of = |out: &Outputs, key: &str| {
+        let change = out.changes.iter().find(|c| c.key == key).expect("change");
+        vizij_api_core::value::as_float(&change.value).expect("float")
+    };
+    let out = eng.update_values(0.0, Inputs::default()).clone();
+    approx(value_of(&out, "node.a"), 3.0, 1e-6);
+    approx(value_of(&out, "node.b"), 20.0, 1e-6);
+
+    eng.set_key_override("node.a", false);
+    let out = eng.update_values(0.0, Inputs::default()).clone();
+    approx(value_of(&out, "node.a"), 2.0, 1e-6);
+}
+
 #[test]
 fn sampling_derivative_linear_and_step() {
     let track_lin = mk_scalar_track_linear("node.value", &[(0.0, 0.0), (1.0, 1.0)]);
@@ -270,15 +406,18 @@ fn sampling_derivative_linear_and_step() {
                 stamp: 0.0,
                 value: TrackValue::Bool(true),
                 transitions: None,
+                interpolation: None,
             },
             Keypoint {
                 id: "k1".into(),
                 stamp: 1.0,
                 value: TrackValue::Bool(false),
                 transitions: None,
+                interpolation: None,
             },
         ],
         settings: None,
+        units: None,
     };
     let step_sample = sample_track_with_derivative(&bool_track, 0.5, 1.0);
     if let TrackValue::Bool(v) = step_sample.0 {
@@ -341,6 +480,37 @@ fn binding_prebind_and_fallback() {
     assert!(keys2.contains(&"node.fallback"));
 }
 
+/// it should report every channel's bound target and flag the ones the resolver missed
+#[test]
+fn binding_report_flags_unresolved_paths() {
+    let anim = mk_anim(
+        "a",
+        1.0,
+        vec![
+            mk_scalar_track_linear("node.a", &[(0.0, 1.0), (1.0, 1.0)]),
+            mk_scalar_track_linear("node.missing", &[(0.0, 1.0), (1.0, 1.0)]),
+        ],
+    );
+    let mut eng = Engine::new(Config::default());
+    let anim_id = eng.load_animation(anim);
+
+    let mut map = std::collections::HashMap::new();
+    map.insert("node.a".to_string(), "HANDLE_A".to_string());
+    eng.prebind(&mut MapResolver(map));
+
+    let channel = |track_idx| ChannelKey {
+        anim: anim_id,
+        track_idx,
+    };
+    assert_eq!(
+        eng.binding_report(),
+        vec![
+            (channel(0), Some("HANDLE_A".to_string())),
+            (channel(1), None),
+        ]
+    );
+}
+
 /// it should handle Once/Loop/PingPong, window clamp, and seek behavior
 #[test]
 fn engine_loop_modes_and_window_and_seek() {
@@ -698,6 +868,520 @@ fn baking_matches_sampling_and_counts() {
     assert!(j.is_object());
 }
 
+/// it should yield the same frames lazily as the batch bake
+#[test]
+fn sample_iter_matches_batch_bake() {
+    let track = mk_scalar_track_linear("node.s", &[(0.0, 0.0), (1.0, 1.0)]);
+    let anim = mk_anim("clip", 1.0, vec![track]);
+    let cfg = BakingConfig {
+        frame_rate: 30.0,
+        start_time: 0.25,
+        end_time: Some(0.9),
+        ..Default::default()
+    };
+    let (baked, derivs) =
+        vizij_animation_core::baking::bake_animation_data_with_derivatives(AnimId(0), &anim, &cfg);
+
+    let iter = vizij_animation_core::baking::sample_iter(AnimId(0), &anim, &cfg);
+    assert_eq!(iter.len(), baked.tracks[0].values.len());
+    let frames: Vec<_> = iter.collect();
+    assert_eq!(frames.len(), baked.tracks[0].values.len());
+    for (i, frame) in frames.iter().enumerate() {
+        assert_eq!(frame.anim, baked.anim);
+        assert_eq!(frame.index, i);
+        approx(frame.time, cfg.start_time + i as f32 / cfg.frame_rate, 1e-6);
+        assert_eq!(frame.values.len(), 1);
+        assert_eq!(frame.values[0], baked.tracks[0].values[i]);
+        assert_eq!(frame.derivatives[0], derivs.tracks[0].values[i]);
+    }
+}
+
+/// it should merge two single-track clips into one spanning the longer duration
+#[test]
+fn merge_unions_tracks_and_takes_max_duration() {
+    let upper = mk_anim(
+        "upper",
+        1.0,
+        vec![mk_scalar_track_linear("arm", &[(0.0, 0.0), (1.0, 1.0)])],
+    );
+    let lower = mk_anim(
+        "lower",
+        2.0,
+        vec![mk_scalar_track_linear("leg", &[(0.0, 0.0), (1.0, 2.0)])],
+    );
+
+    let merged = upper.clone().merge(lower.clone(), false).expect("merge");
+    assert_eq!(merged.name, "upper");
+    assert_eq!(merged.duration_ms, 2000);
+    let paths: Vec<&str> = merged
+        .tracks
+        .iter()
+        .map(|t| t.animatable_id.as_str())
+        .collect();
+    assert_eq!(paths, vec!["arm", "leg"]);
+    // The shorter clip keeps its timing in seconds: its last key now sits halfway.
+    approx(merged.tracks[0].points[1].stamp, 0.5, 1e-6);
+    approx(merged.tracks[1].points[1].stamp, 1.0, 1e-6);
+
+    let dup = upper.clone().merge(upper.clone(), false).unwrap_err();
+    assert!(dup.contains("'arm'"), "{dup}");
+
+    let replacement = mk_anim(
+        "replacement",
+        1.0,
+        vec![mk_scalar_track_linear("arm", &[(0.0, 5.0), (1.0, 6.0)])],
+    );
+    let overwritten = upper.clone().merge(replacement, true).expect("overwrite");
+    assert_eq!(overwritten.tracks.len(), 1);
+    assert_eq!(
+        overwritten.tracks[0].points[0].value,
+        TrackValue::Float(5.0)
+    );
+
+    let mut eng = Engine::new(Config::default());
+    let a = eng.load_animation(upper);
+    let b = eng.load_animation(lower);
+    let m = eng.merge_animations(a, b, false).expect("engine merge");
+    let info = eng
+        .list_animations()
+        .into_iter()
+        .find(|info| info.id == m.0)
+        .expect("merged clip loaded");
+    assert_eq!(info.track_count, 2);
+    assert!(eng.merge_animations(a, AnimId(999), false).is_err());
+}
+
+/// it should extract a rebased mid-range subclip whose endpoints match the original
+#[test]
+fn subclip_rebases_mid_range_and_samples_endpoints() {
+    let track = mk_scalar_track_linear("node.s", &[(0.0, 0.0), (0.5, 4.0), (1.0, 2.0)]);
+    let anim = mk_anim("clip", 2.0, vec![track.clone()]);
+
+    let sub = anim.subclip(0.5, 1.5);
+    assert_eq!(sub.duration_ms, 1000);
+    sub.validate_basic().expect("valid subclip");
+    let sub_track = &sub.tracks[0];
+    // Boundary keys plus the original interior key at 1.0s, now halfway.
+    assert_eq!(sub_track.points.len(), 3);
+    approx(sub_track.points[1].stamp, 0.5, 1e-6);
+
+    let scalar = |v: TrackValue| match v {
+        TrackValue::Float(x) => x,
+        other => panic!("expected float, got {other:?}"),
+    };
+    approx(
+        scalar(sample_track(sub_track, 0.0)),
+        scalar(sample_track(&track, 0.25)),
+        1e-5,
+    );
+    approx(
+        scalar(sample_track(sub_track, 1.0)),
+        scalar(sample_track(&track, 0.75)),
+        1e-5,
+    );
+
+    let mut eng = Engine::new(Config::default());
+    let a = eng.load_animation(anim);
+    assert!(eng.subclip_animation(a, 0.5, 1.5).is_ok());
+    assert!(eng.subclip_animation(a, 1.0, 1.0).is_err());
+}
+
+/// it should stream keypoints past the end of a loaded clip and sample the new region
+#[test]
+fn append_keypoints_extends_clip_and_samples_new_region() {
+    let timed = |time: f32, v: f32| TimedKeypoint {
+        id: String::new(),
+        time,
+        value: TrackValue::Float(v),
+        transitions: None,
+    };
+    let track = mk_scalar_track_linear("node.s", &[(0.0, 0.0), (1.0, 1.0)]);
+    let mut anim = mk_anim("live", 1.0, vec![track]);
+
+    anim.append_keypoints("node.s", vec![timed(2.0, 3.0)], false)
+        .expect("append in order");
+    assert_eq!(anim.duration_ms, 2000);
+    anim.validate_basic().expect("still valid");
+    let points = &anim.tracks[0].points;
+    // Existing keys keep their timing in seconds; the new key ends the clip.
+    approx(points[1].stamp, 0.5, 1e-6);
+    assert_eq!(points[2].stamp, 1.0);
+    assert_eq!(points[2].id, format!("{}-2", anim.tracks[0].id));
+    match sample_track(&anim.tracks[0], 0.75) {
+        TrackValue::Float(x) => assert!(x > 1.0 && x < 3.0, "sampled {x}"),
+        other => panic!("expected float, got {other:?}"),
+    }
+
+    let err = anim
+        .append_keypoints("node.s", vec![timed(1.5, 9.0)], false)
+        .unwrap_err();
+    assert!(err.contains("precedes"), "{err}");
+    assert_eq!(
+        anim.tracks[0].points.len(),
+        3,
+        "rejected appends change nothing"
+    );
+    anim.append_keypoints("node.s", vec![timed(1.5, 9.0)], true)
+        .expect("sorted insert");
+    anim.validate_basic()
+        .expect("sorted insert keeps stamps monotonic");
+    assert_eq!(anim.tracks[0].points[2].value, TrackValue::Float(9.0));
+
+    assert!(anim
+        .append_keypoints("missing", vec![timed(0.5, 0.0)], false)
+        .is_err());
+    assert!(anim
+        .append_keypoints(
+            "node.s",
+            vec![TimedKeypoint {
+                value: TrackValue::Bool(true),
+                ..timed(3.0, 0.0)
+            }],
+            false
+        )
+        .is_err());
+
+    let mut eng = Engine::new(Config::default());
+    let a = eng.load_animation(mk_anim(
+        "live",
+        1.0,
+        vec![mk_scalar_track_linear("node.s", &[(0.0, 0.0), (1.0, 1.0)])],
+    ));
+    let p = eng.create_player("p");
+    eng.add_instance(p, a, InstanceCfg::default());
+    eng.append_keypoints(a, "node.s", vec![timed(3.0, 5.0)], false)
+        .expect("engine append");
+    let player = eng
+        .list_players()
+        .into_iter()
+        .find(|info| info.id == p.0)
+        .expect("player listed");
+    approx(player.length, 3.0, 1e-6);
+    assert!(eng
+        .append_keypoints(AnimId(999), "node.s", Vec::new(), false)
+        .is_err());
+}
+
+/// it should report the longest instance end as a once player's duration, infinity when looping
+#[test]
+fn player_duration_is_longest_instance_or_infinite_when_looping() {
+    let mut eng = Engine::new(Config::default());
+    let short = eng.load_animation(mk_anim(
+        "short",
+        1.0,
+        vec![mk_scalar_track_linear("node.a", &[(0.0, 0.0), (1.0, 1.0)])],
+    ));
+    let long = eng.load_animation(mk_anim(
+        "long",
+        2.0,
+        vec![mk_scalar_track_linear("node.b", &[(0.0, 0.0), (1.0, 1.0)])],
+    ));
+    let pid = eng.create_player("timeline");
+    eng.add_instance(pid, short, InstanceCfg::default());
+    eng.add_instance(
+        pid,
+        long,
+        InstanceCfg {
+            start_offset: 0.5,
+            ..InstanceCfg::default()
+        },
+    );
+
+    // New players loop, so they never end.
+    assert_eq!(eng.player_duration(pid), f32::INFINITY);
+
+    let mut inputs = Inputs::default();
+    inputs.player_cmds.push(PlayerCommand::SetLoopMode {
+        player: pid,
+        mode: LoopMode::Once,
+    });
+    eng.update(0.0, inputs);
+    approx(eng.player_duration(pid), 2.5, 1e-6);
+    assert_eq!(eng.player_duration(PlayerId(999)), 0.0);
+}
+
+/// it should resample onto a uniform grid that reproduces the original curve at each frame
+#[test]
+fn resample_uniform_matches_original_at_grid_points() {
+    let track = mk_scalar_track_linear("node.x", &[(0.0, 0.0), (0.4, 2.0), (1.0, -1.0)]);
+    let anim = mk_anim("sparse", 1.0, vec![track]);
+
+    let resampled = anim.resample_uniform(30.0);
+    assert_eq!(resampled.duration_ms, anim.duration_ms);
+    let points = &resampled.tracks[0].points;
+    assert_eq!(points.len(), 31);
+
+    for k in 0..=30 {
+        let u = k as f32 / 30.0;
+        let (TrackValue::Float(orig), TrackValue::Float(grid)) = (
+            sample_track(&anim.tracks[0], u),
+            sample_track(&resampled.tracks[0], u),
+        ) else {
+            panic!("expected Float samples");
+        };
+        assert!((orig - grid).abs() < 1e-4, "frame {k}: {orig} vs {grid}");
+    }
+
+    let mut eng = Engine::new(Config::default());
+    let id = eng.load_animation(anim);
+    assert!(eng.resample_animation(id, 0.0).is_err());
+    let new_id = eng.resample_animation(id, 30.0).unwrap();
+    assert_ne!(new_id, id);
+}
+
+/// it should drop most keypoints of a noisy-but-linear track while staying within tolerance
+#[test]
+fn simplify_reduces_dense_track_within_tolerance() {
+    let keys: Vec<(f32, f32)> = (0..=100)
+        .map(|i| {
+            let u = i as f32 / 100.0;
+            // Deterministic jitter of at most 0.01 around the line y = 2u.
+            (u, 2.0 * u + 0.01 * (i as f32 * 12.9898).sin())
+        })
+        .collect();
+    let track = mk_scalar_track_linear("node.mocap", &keys);
+    let anim = mk_anim("mocap", 1.0, vec![track.clone()]);
+
+    let tolerance = 0.05;
+    let simplified = anim.simplify(tolerance);
+    simplified.validate_basic().expect("valid simplified clip");
+    let reduced = &simplified.tracks[0];
+    assert!(
+        reduced.points.len() <= 10,
+        "expected heavy reduction, kept {}",
+        reduced.points.len()
+    );
+    assert_eq!(reduced.points.first(), track.points.first());
+    assert_eq!(reduced.points.last(), track.points.last());
+
+    for i in 0..=500 {
+        let u = i as f32 / 500.0;
+        match (sample_track(&track, u), sample_track(reduced, u)) {
+            (TrackValue::Float(a), TrackValue::Float(b)) => {
+                assert!((a - b).abs() <= tolerance, "u={u}: {a} vs {b}")
+            }
+            other => panic!("expected floats, got {other:?}"),
+        }
+    }
+
+    // A zero tolerance keeps every keypoint that is off the line.
+    assert!(anim.simplify(0.0).tracks[0].points.len() > 50);
+}
+
+/// it should bake reversed samples as the forward samples backwards with negated derivatives
+#[test]
+fn reverse_bake_mirrors_forward_bake() {
+    let track = mk_scalar_track_linear("node.s", &[(0.0, 0.0), (0.4, 3.0), (1.0, 1.0)]);
+    let anim = mk_anim("clip", 1.0, vec![track]);
+    let forward_cfg = BakingConfig {
+        frame_rate: 24.0,
+        ..Default::default()
+    };
+    let reverse_cfg = BakingConfig {
+        reverse: true,
+        ..forward_cfg.clone()
+    };
+    let (fwd, fwd_d) = vizij_animation_core::baking::bake_animation_data_with_derivatives(
+        AnimId(0),
+        &anim,
+        &forward_cfg,
+    );
+    let (rev, rev_d) = vizij_animation_core::baking::bake_animation_data_with_derivatives(
+        AnimId(0),
+        &anim,
+        &reverse_cfg,
+    );
+
+    let mut expected = fwd.tracks[0].values.clone();
+    expected.reverse();
+    assert_eq!(rev.tracks[0].values, expected);
+
+    let n = fwd_d.tracks[0].values.len();
+    assert_eq!(rev_d.tracks[0].values.len(), n);
+    for (i, reversed) in rev_d.tracks[0].values.iter().enumerate() {
+        match (reversed, &fwd_d.tracks[0].values[n - 1 - i]) {
+            (Some(Value::F32(r)), Some(Value::F32(f))) => approx(*r, -*f, 1e-6),
+            other => panic!("expected scalar derivatives, got {other:?}"),
+        }
+    }
+}
+
+/// it should emit quantized codes that decode to within one step of the float bake
+#[test]
+fn quantized_bake_decodes_within_step() {
+    let track = mk_scalar_track_linear("node.s", &[(0.0, -2.0), (0.5, 5.0), (1.0, 1.0)]);
+    let anim = mk_anim("clip", 1.0, vec![track]);
+    let cfg = BakingConfig {
+        frame_rate: 30.0,
+        quantize_bits: Some(8),
+        ..Default::default()
+    };
+    let baked = vizij_animation_core::baking::bake_animation_data(AnimId(0), &anim, &cfg);
+    let track = &baked.tracks[0];
+    let quantized = track
+        .quantized
+        .as_ref()
+        .expect("numeric track is quantized");
+    assert_eq!(quantized.bits, 8);
+    assert_eq!(quantized.components, 1);
+    approx(quantized.scale[0], 7.0 / 255.0, 1e-4);
+
+    let decoded = quantized.decode();
+    assert_eq!(decoded.len(), track.values.len());
+    for (value, decoded) in track.values.iter().zip(decoded) {
+        match value {
+            Value::F32(v) => assert!(
+                (v - decoded).abs() <= quantized.scale[0] * 0.5 + 1e-5,
+                "{v} vs {decoded}"
+            ),
+            other => panic!("expected float, got {other:?}"),
+        }
+    }
+
+    let plain = vizij_animation_core::baking::bake_animation_data(
+        AnimId(0),
+        &anim,
+        &BakingConfig::default(),
+    );
+    assert!(plain.tracks[0].quantized.is_none());
+}
+
+/// it should give each component its own quantization range and skip tracks with non-finite samples
+#[test]
+fn quantized_bake_uses_per_component_ranges() {
+    let mut wide = mk_scalar_track_linear("node.v", &[(0.0, 0.0), (1.0, 1.0)]);
+    for point in &mut wide.points {
+        let TrackValue::Float(x) = point.value else {
+            unreachable!()
+        };
+        // Component ranges: [0, 1000], [0, 0.01], constant 3.
+        point.value = TrackValue::Vec3([1000.0 * x, 0.01 * x, 3.0]);
+    }
+    let mut bad = mk_scalar_track_linear("node.nan", &[(0.0, 0.0), (1.0, 1.0)]);
+    bad.points[1].value = TrackValue::Float(f32::NAN);
+    let anim = mk_anim("clip", 1.0, vec![wide, bad]);
+    let cfg = BakingConfig {
+        frame_rate: 10.0,
+        quantize_bits: Some(8),
+        ..Default::default()
+    };
+    let baked = vizij_animation_core::baking::bake_animation_data(AnimId(0), &anim, &cfg);
+
+    let quantized = baked.tracks[0]
+        .quantized
+        .as_ref()
+        .expect("vec3 track is quantized");
+    assert_eq!(quantized.components, 3);
+    assert_eq!(quantized.offset, vec![0.0, 0.0, 3.0]);
+    approx(quantized.scale[0], 1000.0 / 255.0, 1e-3);
+    approx(quantized.scale[1], 0.01 / 255.0, 1e-8);
+    assert_eq!(quantized.scale[2], 0.0);
+    let decoded = quantized.decode();
+    for (frame, value) in decoded.chunks_exact(3).zip(&baked.tracks[0].values) {
+        let v = as_vec3(value).unwrap_or_else(|| panic!("expected vec3, got {value:?}"));
+        for c in 0..3 {
+            assert!(
+                (v[c] - frame[c]).abs() <= quantized.scale[c] * 0.501 + 1e-6,
+                "component {c}: {} vs {}",
+                v[c],
+                frame[c]
+            );
+        }
+    }
+
+    assert!(baked.tracks[1].quantized.is_none());
+}
+
+/// it should report bake progress monotonically and stop early once cancelled
+#[test]
+fn bake_progress_is_monotonic_and_cancellable() {
+    use vizij_animation_core::baking::{bake_animation_data_with_progress, BakeCancelToken};
+
+    let track = mk_scalar_track_linear("node.s", &[(0.0, 0.0), (1.0, 1.0)]);
+    let anim = mk_anim("clip", 1.0, vec![track]);
+    let cfg = BakingConfig {
+        frame_rate: 60.0,
+        ..Default::default()
+    };
+    let full = vizij_animation_core::baking::bake_animation_data(AnimId(0), &anim, &cfg);
+    let frame_count = full.tracks[0].values.len();
+
+    let mut reported = Vec::new();
+    let baked = bake_animation_data_with_progress(
+        AnimId(0),
+        &anim,
+        &cfg,
+        |p| reported.push(p),
+        &BakeCancelToken::new(),
+    );
+    assert_eq!(baked.tracks[0].values, full.tracks[0].values);
+    assert_eq!(reported.len(), frame_count);
+    assert!(reported.windows(2).all(|w| w[0] < w[1]));
+    approx(*reported.last().unwrap(), 1.0, 1e-6);
+
+    let cancel = BakeCancelToken::new();
+    let handle = cancel.clone();
+    let mut last = 0.0;
+    let partial = bake_animation_data_with_progress(
+        AnimId(0),
+        &anim,
+        &cfg,
+        |p| {
+            last = p;
+            if p >= 0.5 {
+                handle.cancel();
+            }
+        },
+        &cancel,
+    );
+    assert!(cancel.is_cancelled());
+    assert!(last < 1.0);
+    let baked_frames = partial.tracks[0].values.len();
+    assert!(baked_frames > 0 && baked_frames < frame_count);
+    assert_eq!(
+        partial.tracks[0].values[..],
+        full.tracks[0].values[..baked_frames]
+    );
+    assert!(partial.end_time < full.end_time);
+}
+
+/// it should narrow a cancelled reverse bake's window from the start, keeping its end
+#[test]
+fn cancelled_reverse_bake_keeps_the_sampled_end_of_the_range() {
+    use vizij_animation_core::baking::{bake_animation_data_with_progress, BakeCancelToken};
+
+    let track = mk_scalar_track_linear("node.s", &[(0.0, 0.0), (1.0, 1.0)]);
+    let anim = mk_anim("clip", 1.0, vec![track]);
+    let cfg = BakingConfig {
+        frame_rate: 10.0,
+        start_time: 0.2,
+        end_time: Some(0.8),
+        reverse: true,
+        ..Default::default()
+    };
+    let full = vizij_animation_core::baking::bake_animation_data(AnimId(0), &anim, &cfg);
+
+    let cancel = BakeCancelToken::new();
+    let handle = cancel.clone();
+    let mut frames = 0;
+    let partial = bake_animation_data_with_progress(
+        AnimId(0),
+        &anim,
+        &cfg,
+        |_| {
+            frames += 1;
+            if frames == 3 {
+                handle.cancel();
+            }
+        },
+        &cancel,
+    );
+    // Frames 0.8, 0.7, 0.6 were sampled, so the window shrinks to [0.6, 0.8].
+    assert_eq!(partial.tracks[0].values[..], full.tracks[0].values[..3]);
+    approx(partial.start_time, 0.6, 1e-5);
+    approx(partial.end_time, 0.8, 1e-5);
+}
+
 /// it should align value/derivative tracks in bake bundle output
 #[test]
 fn baking_with_derivatives_aligns_tracks() {
@@ -859,8 +1543,10 @@ fn sampling_boundaries_single_and_empty() {
             stamp: 0.5,
             value: TrackValue::Float(7.0),
             transitions: None,
+            interpolation: None,
         }],
         settings: None,
+        units: None,
     };
     if let TrackValue::Float(v) = sample_track(&single, 0.0) {
         approx(v, 7.0, 1e-6)
@@ -880,6 +1566,7 @@ fn sampling_boundaries_single_and_empty() {
         animatable_id: "node.empty".into(),
         points: vec![],
         settings: None,
+        units: None,
     };
     let anim = mk_anim("clip", 1.0, vec![empty]);
     let mut eng = Engine::new(Config::default());
@@ -1047,15 +1734,18 @@ fn mixed_kind_same_target_safe() {
                 stamp: 0.0,
                 value: TrackValue::Vec3([0.0, 0.0, 0.0]),
                 transitions: None,
+                interpolation: None,
             },
             Keypoint {
                 id: "k1".into(),
                 stamp: 1.0,
                 value: TrackValue::Vec3([1.0, 1.0, 1.0]),
                 transitions: None,
+                interpolation: None,
             },
         ],
         settings: None,
+        units: None,
     };
     let a0 = mk_anim("a0", 1.0, vec![ta]);
     let a1 = mk_anim("a1", 1.0, vec![tb]);
@@ -1139,6 +1829,7 @@ fn baking_empty_and_single_key_tracks() {
         animatable_id: "node.empty".into(),
         points: vec![],
         settings: None,
+        units: None,
     };
     let single = Track {
         id: "t-single".into(),
@@ -1149,8 +1840,10 @@ fn baking_empty_and_single_key_tracks() {
             stamp: 0.5,
             value: TrackValue::Float(3.14),
             transitions: None,
+            interpolation: None,
         }],
         settings: None,
+        units: None,
     };
     let anim = mk_anim("clip", 1.0, vec![empty.clone(), single.clone()]);
     let cfg = BakingConfig {
@@ -1210,6 +1903,7 @@ fn config_and_value_serde_roundtrip() {
         stamp: 0.5,
         value: TrackValue::Vec3([1.0, 2.0, 3.0]),
         transitions: None,
+        interpolation: None,
     };
     let skp = serde_json::to_string(&kp).unwrap();
     let kp2: Keypoint = serde_json::from_str(&skp).unwrap();
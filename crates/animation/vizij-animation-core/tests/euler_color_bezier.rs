@@ -1,5 +1,5 @@
 use vizij_animation_core::{
-    data::{Keypoint, Track, Transitions, Vec2},
+    data::{AnimationData, InterpolationType, Keypoint, Track, Transitions, Vec2},
     sampling::sample_track,
     value::TrackValue,
 };
@@ -20,15 +20,18 @@ fn euler_vec3_bezier_vs_linear_shape() {
                 stamp: 0.0,
                 value: TrackValue::Vec3([0.0, 0.0, 0.0]),
                 transitions: None,
+                interpolation: None,
             },
             Keypoint {
                 id: "k1".into(),
                 stamp: 1.0,
                 value: TrackValue::Vec3([1.0, 2.0, 3.0]),
                 transitions: None,
+                interpolation: None,
             },
         ],
         settings: None,
+        units: None,
     };
 
     // Linear curve encoded via per-point transitions:
@@ -46,6 +49,7 @@ fn euler_vec3_bezier_vs_linear_shape() {
                     r#in: None,
                     r#out: Some(Vec2 { x: 0.0, y: 0.0 }),
                 }),
+                interpolation: None,
             },
             Keypoint {
                 id: "k1".into(),
@@ -55,9 +59,11 @@ fn euler_vec3_bezier_vs_linear_shape() {
                     r#in: Some(Vec2 { x: 1.0, y: 1.0 }),
                     r#out: None,
                 }),
+                interpolation: None,
             },
         ],
         settings: None,
+        units: None,
     };
 
     let vb = sample_track(&track_bez, 0.25);
@@ -89,6 +95,7 @@ fn color_bezier_with_explicit_ctrl_points() {
                     r#in: None,
                     r#out: Some(Vec2 { x: 0.8, y: 0.0 }),
                 }),
+                interpolation: None,
             },
             Keypoint {
                 id: "k1".into(),
@@ -98,9 +105,11 @@ fn color_bezier_with_explicit_ctrl_points() {
                     r#in: Some(Vec2 { x: 0.2, y: 1.0 }),
                     r#out: None,
                 }),
+                interpolation: None,
             },
         ],
         settings: None,
+        units: None,
     };
 
     let v = sample_track(&track, 0.25);
@@ -116,3 +125,65 @@ fn color_bezier_with_explicit_ctrl_points() {
         _ => panic!("expected Color value"),
     }
 }
+
+#[test]
+fn explicit_bezier_segment_matches_reference_curve() {
+    // CSS "ease": (0.25, 0.1) / (0.25, 1.0). The handles on the keypoints must be ignored.
+    let (p1, p2) = (Vec2 { x: 0.25, y: 0.1 }, Vec2 { x: 0.25, y: 1.0 });
+    let track = Track {
+        id: "t-ease".into(),
+        name: "Ease".into(),
+        animatable_id: "node.value".into(),
+        points: vec![
+            Keypoint {
+                id: "k0".into(),
+                stamp: 0.0,
+                value: TrackValue::Float(2.0),
+                transitions: Some(Transitions {
+                    r#in: None,
+                    r#out: Some(Vec2 { x: 0.9, y: 0.0 }),
+                }),
+                interpolation: Some(InterpolationType::Bezier { p1, p2 }),
+            },
+            Keypoint {
+                id: "k1".into(),
+                stamp: 1.0,
+                value: TrackValue::Float(6.0),
+                transitions: None,
+                interpolation: None,
+            },
+        ],
+        settings: None,
+        units: None,
+    };
+
+    // Reference: walk the curve parameter directly, so no inversion is involved.
+    let cubic = |a: f32, b: f32, s: f32| {
+        let r = 1.0 - s;
+        3.0 * r * r * s * a + 3.0 * r * s * s * b + s * s * s
+    };
+    for s in [0.1f32, 0.3, 0.5, 0.7, 0.9] {
+        let (x, y) = (cubic(p1.x, p2.x, s), cubic(p1.y, p2.y, s));
+        match sample_track(&track, x) {
+            TrackValue::Float(v) => {
+                let expected = 2.0 + 4.0 * y;
+                assert!((v - expected).abs() < 1e-3, "t={x}: {v} vs {expected}");
+            }
+            other => panic!("expected Float, got {other:?}"),
+        }
+    }
+
+    let mut bad = track.clone();
+    bad.points[0].interpolation = Some(InterpolationType::Bezier {
+        p1: Vec2 { x: 1.5, y: 0.0 },
+        p2,
+    });
+    let clip = AnimationData {
+        id: None,
+        name: "bad".into(),
+        tracks: vec![bad],
+        groups: serde_json::Value::Null,
+        duration_ms: 1000,
+    };
+    assert!(clip.validate_basic().is_err());
+}
@@ -0,0 +1,139 @@
+use serde_json::json;
+use vizij_animation_core::{
+    data::{AnimationData, Keypoint, Track},
+    engine::InstanceCfg,
+    Config, Engine, Inputs, TimeWarp, TrackValue,
+};
+
+fn ramp(duration_s: f32) -> AnimationData {
+    let key = |id: &str, stamp: f32, v: f32| Keypoint {
+        id: id.into(),
+        stamp,
+        value: TrackValue::Float(v),
+        transitions: None,
+        interpolation: None,
+    };
+    AnimationData {
+        id: None,
+        name: "ramp".into(),
+        tracks: vec![Track {
+            id: "t0".into(),
+            name: "Ramp".into(),
+            animatable_id: "ramp/value".into(),
+            points: vec![key("k0", 0.0, 0.0), key("k1", 1.0, 1.0)],
+            settings: None,
+            units: None,
+        }],
+        groups: json!({}),
+        duration_ms: (duration_s * 1000.0) as u32,
+    }
+}
+
+fn engine_with_players(count: usize) -> Engine {
+    let mut eng = Engine::new(Config::default());
+    let anim = eng.load_animation(ramp(10.0));
+    for i in 0..count {
+        let player = eng.create_player(&format!("P{i}"));
+        eng.add_instance(player, anim, InstanceCfg::default());
+    }
+    eng
+}
+
+fn ramp_values(eng: &mut Engine, dt: f32) -> Vec<f32> {
+    eng.update_values(dt, Inputs::default())
+        .changes
+        .iter()
+        .map(|c| vizij_api_core::value::as_float(&c.value).expect("float output"))
+        .collect()
+}
+
+fn player_times(eng: &Engine) -> Vec<f32> {
+    eng.list_players().into_iter().map(|p| p.time).collect()
+}
+
+#[test]
+fn time_scale_slows_every_player() {
+    let mut eng = engine_with_players(3);
+    eng.set_time_scale(0.5);
+    assert_eq!(eng.time_scale(), 0.5);
+
+    for _ in 0..4 {
+        let _ = eng.update_values(0.25, Inputs::default());
+    }
+    for t in player_times(&eng) {
+        assert!(
+            (t - 0.5).abs() < 1e-5,
+            "expected half-rate time 0.5, got {t}"
+        );
+    }
+
+    eng.set_time_scale(1.0);
+    let _ = eng.update_values(0.5, Inputs::default());
+    for t in player_times(&eng) {
+        assert!(
+            (t - 1.0).abs() < 1e-5,
+            "expected 1.0 after restoring scale, got {t}"
+        );
+    }
+}
+
+#[test]
+fn time_warp_ramps_on_engine_clock() {
+    let mut eng = engine_with_players(2);
+    // Full speed for the first second of engine time, quarter speed afterwards.
+    eng.set_time_warp(Some(TimeWarp::new(
+        |clock| {
+            if clock < 1.0 {
+                1.0
+            } else {
+                0.25
+            }
+        },
+    )));
+
+    for _ in 0..4 {
+        let _ = eng.update_values(0.5, Inputs::default());
+    }
+    // 0.5 + 0.5 at full speed, then 0.125 + 0.125.
+    for t in player_times(&eng) {
+        assert!((t - 1.25).abs() < 1e-5, "got {t}");
+    }
+
+    eng.set_time_warp(None);
+    let _ = eng.update_values(0.5, Inputs::default());
+    for t in player_times(&eng) {
+        assert!((t - 1.75).abs() < 1e-5, "got {t}");
+    }
+}
+
+#[test]
+fn pause_all_freezes_outputs_and_resume_continues() {
+    let mut eng = engine_with_players(2);
+    let before = ramp_values(&mut eng, 1.0);
+    assert_eq!(before.len(), 2);
+
+    eng.pause_all();
+    assert!(eng.is_paused());
+    for _ in 0..3 {
+        assert_eq!(
+            ramp_values(&mut eng, 0.5),
+            before,
+            "outputs must hold while paused"
+        );
+    }
+    for t in player_times(&eng) {
+        assert!((t - 1.0).abs() < 1e-5, "got {t}");
+    }
+
+    eng.resume_all();
+    let after = ramp_values(&mut eng, 1.0);
+    for (a, b) in after.iter().zip(&before) {
+        assert!(
+            a > b,
+            "resume should advance from the frozen time: {a} vs {b}"
+        );
+    }
+    for t in player_times(&eng) {
+        assert!((t - 2.0).abs() < 1e-5, "got {t}");
+    }
+}
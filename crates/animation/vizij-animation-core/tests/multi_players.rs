@@ -17,6 +17,7 @@ fn mk_anim(name: &str, duration_s: f32) -> AnimationData {
                 r#in: None,
                 r#out: Some(Vec2 { x: 0.0, y: 0.0 }),
             }),
+            interpolation: None,
         },
         Keypoint {
             id: "k1".into(),
@@ -26,6 +27,7 @@ fn mk_anim(name: &str, duration_s: f32) -> AnimationData {
                 r#in: Some(Vec2 { x: 1.0, y: 1.0 }),
                 r#out: None,
             }),
+            interpolation: None,
         },
     ];
     let track = Track {
@@ -34,6 +36,7 @@ fn mk_anim(name: &str, duration_s: f32) -> AnimationData {
         animatable_id: "Dummy".into(),
         points,
         settings: None,
+        units: None,
     };
     AnimationData {
         id: None,
@@ -61,6 +64,7 @@ fn durations_are_independent_per_player() {
             time_scale: 1.0,
             start_offset: 0.0,
             enabled: true,
+            priority: 0,
         },
     );
     // P2: two instances with different spans (multiplier semantics):
@@ -73,6 +77,7 @@ fn durations_are_independent_per_player() {
             time_scale: 1.0 / 3.0,
             start_offset: 0.0,
             enabled: true,
+            priority: 0,
         },
     );
     eng.add_instance(
@@ -83,6 +88,7 @@ fn durations_are_independent_per_player() {
             time_scale: 2.0,
             start_offset: 0.0,
             enabled: true,
+            priority: 0,
         },
     );
 
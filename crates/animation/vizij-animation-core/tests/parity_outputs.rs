@@ -234,15 +234,18 @@ fn parity_quat_layered_normalized() {
                 stamp: 0.0,
                 value: TrackValue::Quat(q0),
                 transitions: None,
+                interpolation: None,
             },
             Keypoint {
                 id: "k1".into(),
                 stamp: 1.0,
                 value: TrackValue::Quat(q0),
                 transitions: None,
+                interpolation: None,
             },
         ],
         settings: None,
+        units: None,
     };
     let t1 = Track {
         id: "t1".into(),
@@ -254,15 +257,18 @@ fn parity_quat_layered_normalized() {
                 stamp: 0.0,
                 value: TrackValue::Quat(q1),
                 transitions: None,
+                interpolation: None,
             },
             Keypoint {
                 id: "k1".into(),
                 stamp: 1.0,
                 value: TrackValue::Quat(q1),
                 transitions: None,
+                interpolation: None,
             },
         ],
         settings: None,
+        units: None,
     };
 
     let a0 = AnimationData {
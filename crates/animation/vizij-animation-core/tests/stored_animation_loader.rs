@@ -1,7 +1,7 @@
 use vizij_animation_core::value::TrackValue;
 use vizij_animation_core::{
-    data::{AnimationData, Track},
-    parse_stored_animation_json,
+    data::{AnimationData, Track, TrackUnits},
+    parse_stored_animation_json, sample_track, BakingConfig, Config, Engine, Value,
 };
 
 fn approx(a: f32, b: f32, eps: f32) {
@@ -102,3 +102,77 @@ fn parses_pose_quat_transform_fixture_with_extended_values() {
         other => panic!("expected transform value, got {other:?}"),
     }
 }
+
+#[test]
+fn degrees_annotated_rotation_track_is_sampled_in_radians() {
+    let json = r#"{
+        "id": "deg",
+        "name": "deg",
+        "duration": 1000,
+        "groups": {},
+        "tracks": [
+            {
+                "id": "t0",
+                "name": "yaw",
+                "animatableId": "rig/head.yaw",
+                "units": "degrees",
+                "points": [
+                    { "id": "k0", "stamp": 0.0, "value": 0 },
+                    { "id": "k1", "stamp": 1.0, "value": 180 }
+                ]
+            },
+            {
+                "id": "t1",
+                "name": "euler",
+                "animatableId": "rig/head.euler",
+                "units": "degrees",
+                "points": [
+                    { "id": "k0", "stamp": 0.0, "value": { "r": 90, "p": 45, "y": -90 } }
+                ]
+            },
+            {
+                "id": "t2",
+                "name": "plain",
+                "animatableId": "rig/head.plain",
+                "points": [
+                    { "id": "k0", "stamp": 0.0, "value": 90 }
+                ]
+            }
+        ]
+    }"#;
+    let anim = parse_stored_animation_json(json).expect("parse degrees clip");
+
+    let yaw = &anim.tracks[0];
+    assert_eq!(yaw.units, Some(TrackUnits::Radians));
+    match sample_track(yaw, 1.0) {
+        TrackValue::Float(v) => approx(v, std::f32::consts::PI, 1e-5),
+        other => panic!("expected float, got {other:?}"),
+    }
+    match sample_track(&anim.tracks[1], 0.0) {
+        TrackValue::Vec3(v) => {
+            approx(v[0], std::f32::consts::FRAC_PI_2, 1e-6);
+            approx(v[1], std::f32::consts::FRAC_PI_4, 1e-6);
+            approx(v[2], -std::f32::consts::FRAC_PI_2, 1e-6);
+        }
+        other => panic!("expected vec3, got {other:?}"),
+    }
+    // Unannotated tracks keep their authored values.
+    assert_eq!(anim.tracks[2].units, None);
+    assert_eq!(anim.tracks[2].points[0].value, TrackValue::Float(90.0));
+
+    // Loading a degree-annotated AnimationData directly into the engine converts too, once.
+    let mut raw = anim.clone();
+    raw.tracks[0].units = Some(TrackUnits::Degrees);
+    raw.tracks[0].points[1].value = TrackValue::Float(90.0);
+    let mut engine = Engine::new(Config::default());
+    let id = engine.load_animation(raw);
+    let cfg = BakingConfig {
+        frame_rate: 1.0,
+        ..Default::default()
+    };
+    let baked = engine.bake_animation(id, &cfg).expect("loaded clip");
+    match baked.tracks[0].values.last() {
+        Some(Value::F32(v)) => approx(*v, std::f32::consts::FRAC_PI_2, 1e-5),
+        other => panic!("expected float, got {other:?}"),
+    }
+}
@@ -17,6 +17,7 @@ fn mk_anim(name: &str, duration_s: f32) -> AnimationData {
                 r#in: None,
                 r#out: Some(Vec2 { x: 0.0, y: 0.0 }),
             }),
+            interpolation: None,
         },
         Keypoint {
             id: "k1".into(),
@@ -26,6 +27,7 @@ fn mk_anim(name: &str, duration_s: f32) -> AnimationData {
                 r#in: Some(Vec2 { x: 1.0, y: 1.0 }),
                 r#out: None,
             }),
+            interpolation: None,
         },
     ];
     let track = Track {
@@ -34,6 +36,7 @@ fn mk_anim(name: &str, duration_s: f32) -> AnimationData {
         animatable_id: "Dummy".into(),
         points,
         settings: None,
+        units: None,
     };
     AnimationData {
         id: None,
@@ -61,6 +64,7 @@ fn total_duration_multiple_instances_basic() {
             time_scale: 1.0,
             start_offset: 0.0,
             enabled: true,
+            priority: 0,
         },
     );
 
@@ -73,6 +77,7 @@ fn total_duration_multiple_instances_basic() {
             time_scale: 2.0,
             start_offset: 0.0,
             enabled: true,
+            priority: 0,
         },
     );
 
@@ -125,6 +130,7 @@ fn total_duration_with_offsets_and_negative_scale() {
             time_scale: 1.0,
             start_offset: 1.5,
             enabled: true,
+            priority: 0,
         },
     );
 
@@ -137,6 +143,7 @@ fn total_duration_with_offsets_and_negative_scale() {
             time_scale: -1.0,
             start_offset: 0.3,
             enabled: true,
+            priority: 0,
         },
     );
 
@@ -187,12 +194,14 @@ fn interpolation_bezier_and_bezier_ease_in_smoke() {
             stamp: 0.0,
             value: TrackValue::Float(0.0),
             transitions: None, // default out (0.42,0)
+            interpolation: None,
         },
         Keypoint {
             id: "k1".into(),
             stamp: 1.0,
             value: TrackValue::Float(1.0),
             transitions: None, // default in (0.58,1)
+            interpolation: None,
         },
     ];
     let track_bez = Track {
@@ -201,6 +210,7 @@ fn interpolation_bezier_and_bezier_ease_in_smoke() {
         animatable_id: "node.v".into(),
         points: bez_points,
         settings: None,
+        units: None,
     };
     let v_mid = sample_track(&track_bez, 0.5);
     if let TrackValue::Float(x) = v_mid {
@@ -222,6 +232,7 @@ fn interpolation_bezier_and_bezier_ease_in_smoke() {
                 r#in: None,
                 r#out: Some(Vec2 { x: 0.42, y: 0.0 }),
             }),
+            interpolation: None,
         },
         Keypoint {
             id: "k1".into(),
@@ -231,6 +242,7 @@ fn interpolation_bezier_and_bezier_ease_in_smoke() {
                 r#in: Some(Vec2 { x: 1.0, y: 1.0 }),
                 r#out: None,
             }),
+            interpolation: None,
         },
     ];
     let track_ease_in = Track {
@@ -239,6 +251,7 @@ fn interpolation_bezier_and_bezier_ease_in_smoke() {
         animatable_id: "node.v".into(),
         points: ei_points,
         settings: None,
+        units: None,
     };
     let v_mid_bz = sample_track(&track_ease_in, 0.5);
     if let TrackValue::Float(x) = v_mid_bz {
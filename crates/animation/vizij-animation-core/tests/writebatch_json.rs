@@ -30,6 +30,7 @@ fn mk_scalar_track_linear(path: &str, keys: &[(f32, f32)]) -> Track {
             stamp: *stamp,
             value: TrackValue::Float(*v),
             transitions,
+            interpolation: None,
         });
     }
     Track {
@@ -38,6 +39,7 @@ fn mk_scalar_track_linear(path: &str, keys: &[(f32, f32)]) -> Track {
         animatable_id: path.to_string(),
         points,
         settings: None,
+        units: None,
     }
 }
 
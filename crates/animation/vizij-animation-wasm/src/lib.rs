@@ -14,19 +14,39 @@ use js_sys::{Function, JSON};
 use serde_wasm_bindgen as swb;
 use wasm_bindgen::prelude::*;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::{json, to_value, Map};
 use vizij_animation_core::{
-    parse_stored_animation_json, AnimId, AnimationData, BakedAnimationData,
+    parse_stored_animation_json, AnimId, AnimationData, BakeCancelToken, BakedAnimationData,
     BakedDerivativeAnimationData, BakingConfig, Config, Engine, Inputs, InstId, InstanceCfg,
-    Outputs, OutputsWithDerivatives, PlayerId, TargetResolver,
+    Outputs, OutputsWithDerivatives, PlayerId, TargetResolver, TimedKeypoint,
 };
+use vizij_api_core::panic::catch_panic;
+
+/// One row of [`VizijAnimation::binding_report`].
+#[derive(Serialize)]
+struct BindingReportRow {
+    anim: u32,
+    track_idx: u32,
+    target: Option<String>,
+}
 
 #[wasm_bindgen]
 pub struct VizijAnimation {
     core: Engine,
 }
 
+impl VizijAnimation {
+    /// Run an engine step under [`catch_panic`].
+    ///
+    /// A caught panic leaves the engine mid-frame, so its output buffers are discarded before
+    /// the error is returned; the next update starts from a clean frame.
+    fn guarded<T>(&mut self, ctx: &str, f: impl FnOnce(&mut Engine) -> T) -> Result<T, String> {
+        let core = &mut self.core;
+        catch_panic(ctx, || f(core)).inspect_err(|_| self.core.discard_outputs())
+    }
+}
+
 fn jsvalue_is_undefined_or_null(v: &JsValue) -> bool {
     v.is_undefined() || v.is_null()
 }
@@ -97,6 +117,10 @@ struct BakingConfigOptions {
     end_time: Option<Option<f32>>,
     #[serde(default)]
     derivative_epsilon: Option<f32>,
+    #[serde(default)]
+    reverse: bool,
+    #[serde(default)]
+    quantize_bits: Option<u8>,
 }
 
 impl BakingConfigOptions {
@@ -139,6 +163,13 @@ impl BakingConfigOptions {
             }
             cfg.derivative_epsilon = Some(eps);
         }
+        cfg.reverse = self.reverse;
+        if let Some(bits) = self.quantize_bits {
+            if !(1..=16).contains(&bits) {
+                return Err(format!("quantize_bits must be in 1..=16 (got {bits})"));
+            }
+            cfg.quantize_bits = Some(bits);
+        }
         Ok(cfg)
     }
 }
@@ -248,11 +279,18 @@ impl VizijAnimation {
     }
 
     /// Step the simulation by `dt` seconds with inputs JSON and return `Outputs` JSON.
+    ///
+    /// Like the other `update_*` methods, a panic inside the engine is thrown as an error in
+    /// builds that unwind; the default `panic=abort` wasm build still traps.
     #[wasm_bindgen(js_name = update_values)]
     pub fn update_values(&mut self, dt: f32, inputs_json: JsValue) -> Result<JsValue, JsError> {
         let inputs = parse_inputs_js(inputs_json)?;
-        let out: &Outputs = self.core.update_values(dt, inputs);
-        swb::to_value(out).map_err(|e| JsError::new(&format!("outputs error: {e}")))
+        self.guarded("update_values", |core| {
+            let out: &Outputs = core.update_values(dt, inputs);
+            swb::to_value(out)
+        })
+        .map_err(|e| JsError::new(&e))?
+        .map_err(|e| JsError::new(&format!("outputs error: {e}")))
     }
 
     /// Step the simulation by `dt` seconds and return `OutputsWithDerivatives` JSON.
@@ -263,8 +301,12 @@ impl VizijAnimation {
         inputs_json: JsValue,
     ) -> Result<JsValue, JsError> {
         let inputs = parse_inputs_js(inputs_json)?;
-        let out: &OutputsWithDerivatives = self.core.update_values_and_derivatives(dt, inputs);
-        swb::to_value(out).map_err(|e| JsError::new(&format!("outputs error: {e}")))
+        self.guarded("update_values_and_derivatives", |core| {
+            let out: &OutputsWithDerivatives = core.update_values_and_derivatives(dt, inputs);
+            swb::to_value(out)
+        })
+        .map_err(|e| JsError::new(&e))?
+        .map_err(|e| JsError::new(&format!("outputs error: {e}")))
     }
 
     /// Backwards-compatible alias for `update_values`.
@@ -277,13 +319,56 @@ impl VizijAnimation {
     ///
     /// The config validator requires finite values, `frame_rate > 0`, positive derivative epsilon,
     /// and `end_time >= start_time` when `end_time` is supplied.
+    ///
+    /// When `progress` is supplied it is called with the completion fraction after each frame.
+    /// Returning `false` from it cancels the bake and yields the frames sampled so far; a thrown
+    /// exception also cancels, and `bake_animation` then throws a new error carrying the
+    /// exception's message (not the original object).
     #[wasm_bindgen(js_name = bake_animation)]
-    pub fn bake_animation(&self, anim_id: u32, cfg: JsValue) -> Result<JsValue, JsError> {
+    pub fn bake_animation(
+        &self,
+        anim_id: u32,
+        cfg: JsValue,
+        progress: Option<Function>,
+    ) -> Result<JsValue, JsError> {
         let cfg_rs = parse_baking_config(cfg)?;
         let aid = AnimId(anim_id);
-        let baked = self.core.bake_animation(aid, &cfg_rs).ok_or_else(|| {
-            JsError::new(&format!("bake_animation: unknown animation id {anim_id}"))
-        })?;
+        let baked = match progress {
+            None => self.core.bake_animation(aid, &cfg_rs),
+            Some(f) => {
+                let cancel = BakeCancelToken::new();
+                let mut thrown: Option<JsValue> = None;
+                let baked = self.core.bake_animation_with_progress(
+                    aid,
+                    &cfg_rs,
+                    |fraction| match f
+                        .call1(&JsValue::UNDEFINED, &JsValue::from_f64(fraction as f64))
+                    {
+                        Ok(ret) => {
+                            if ret.as_bool() == Some(false) {
+                                cancel.cancel();
+                            }
+                        }
+                        Err(err) => {
+                            thrown = Some(err);
+                            cancel.cancel();
+                        }
+                    },
+                    &cancel,
+                );
+                if let Some(err) = thrown {
+                    let msg = err
+                        .as_string()
+                        .or_else(|| JSON::stringify(&err).ok().and_then(|s| s.as_string()))
+                        .unwrap_or_else(|| "unknown error".to_string());
+                    return Err(JsError::new(&format!(
+                        "bake_animation progress callback error: {msg}"
+                    )));
+                }
+                baked
+            }
+        }
+        .ok_or_else(|| JsError::new(&format!("bake_animation: unknown animation id {anim_id}")))?;
         swb::to_value(&baked)
             .map_err(|e| JsError::new(&format!("bake_animation serialize error: {e}")))
     }
@@ -308,7 +393,11 @@ impl VizijAnimation {
         };
 
         // Produce a typed WriteBatch from the engine (skips keys that don't parse).
-        let batch = self.core.update_writebatch(dt, inputs);
+        let batch = self
+            .guarded("update_nodes_writes", |core| {
+                core.update_writebatch(dt, inputs)
+            })
+            .map_err(|e| JsError::new(&e))?;
 
         // Serialize the WriteBatch into JSON (an array of { path, value } objects).
         let writes_json =
@@ -323,6 +412,33 @@ impl VizijAnimation {
         swb::to_value(&out_obj).map_err(|e| JsError::new(&format!("outputs error: {e}")))
     }
 
+    /// Set the global time scale applied to every player's `dt` (e.g. `0.5` for engine-wide
+    /// slow motion). Distinct from per-player speed and per-instance time scale.
+    #[wasm_bindgen(js_name = set_time_scale)]
+    pub fn set_time_scale(&mut self, scale: f32) {
+        self.core.set_time_scale(scale);
+    }
+
+    /// Freeze all players (e.g. when the app loses focus). Updates keep producing outputs for
+    /// the current time but do not advance it.
+    #[wasm_bindgen(js_name = pause_all)]
+    pub fn pause_all(&mut self) {
+        self.core.pause_all();
+    }
+
+    /// Resume all players from where [`Self::pause_all`] froze them.
+    #[wasm_bindgen(js_name = resume_all)]
+    pub fn resume_all(&mut self) {
+        self.core.resume_all();
+    }
+
+    /// Resolve output `key` by instance `priority` (highest wins) instead of weighted blending,
+    /// or restore blending with `enabled = false`.
+    #[wasm_bindgen(js_name = set_key_override)]
+    pub fn set_key_override(&mut self, key: String, enabled: bool) {
+        self.core.set_key_override(&key, enabled);
+    }
+
     /// Remove a player and all its instances. Returns boolean success.
     #[wasm_bindgen(js_name = remove_player)]
     pub fn remove_player(&mut self, player_id: u32) -> bool {
@@ -336,6 +452,66 @@ impl VizijAnimation {
             .remove_instance(PlayerId(player_id), InstId(inst_id))
     }
 
+    /// Merge two loaded animations track-wise into a new clip and return its animation id.
+    ///
+    /// Duplicate target paths are rejected unless `overwrite` is true, in which case the second
+    /// clip's track wins. The merged clip lasts as long as the longer input.
+    #[wasm_bindgen(js_name = merge_animations)]
+    pub fn merge_animations(&mut self, a: u32, b: u32, overwrite: bool) -> Result<u32, JsError> {
+        self.core
+            .merge_animations(AnimId(a), AnimId(b), overwrite)
+            .map(|id| id.0)
+            .map_err(|msg| JsError::new(&msg))
+    }
+
+    /// Extract `[start_s, end_s]` (clip seconds) of a loaded animation as a new clip starting
+    /// at 0 and return its animation id.
+    #[wasm_bindgen(js_name = subclip)]
+    pub fn subclip(&mut self, anim_id: u32, start_s: f32, end_s: f32) -> Result<u32, JsError> {
+        self.core
+            .subclip_animation(AnimId(anim_id), start_s, end_s)
+            .map(|id| id.0)
+            .map_err(|msg| JsError::new(&msg))
+    }
+
+    /// Reduce a loaded animation's keypoints within `tolerance` and return the new clip's id.
+    #[wasm_bindgen(js_name = simplify)]
+    pub fn simplify(&mut self, anim_id: u32, tolerance: f32) -> Result<u32, JsError> {
+        self.core
+            .simplify_animation(AnimId(anim_id), tolerance)
+            .map(|id| id.0)
+            .map_err(|msg| JsError::new(&msg))
+    }
+
+    /// Resample a loaded animation onto a uniform `fps` keypoint grid and return the new clip's id.
+    #[wasm_bindgen(js_name = resample)]
+    pub fn resample(&mut self, anim_id: u32, fps: f32) -> Result<u32, JsError> {
+        self.core
+            .resample_animation(AnimId(anim_id), fps)
+            .map(|id| id.0)
+            .map_err(|msg| JsError::new(&msg))
+    }
+
+    /// Stream keypoints into the track of a loaded animation that targets `track_path`.
+    ///
+    /// `keypoints_json` is an array of `{ time, value, id?, transitions? }` with `time` in clip
+    /// seconds and values in Arora `Value` serde form. The clip grows when a keypoint lies past
+    /// its end. Out-of-order keypoints are rejected unless `sort` is true.
+    #[wasm_bindgen(js_name = append_keypoints)]
+    pub fn append_keypoints(
+        &mut self,
+        anim_id: u32,
+        track_path: String,
+        keypoints_json: JsValue,
+        sort: Option<bool>,
+    ) -> Result<(), JsError> {
+        let points: Vec<TimedKeypoint> = swb::from_value(keypoints_json)
+            .map_err(|e| JsError::new(&format!("append_keypoints parse error: {e}")))?;
+        self.core
+            .append_keypoints(AnimId(anim_id), &track_path, points, sort.unwrap_or(false))
+            .map_err(|msg| JsError::new(&msg))
+    }
+
     /// Unload an animation and detach all referencing instances. Returns boolean success.
     #[wasm_bindgen(js_name = unload_animation)]
     pub fn unload_animation(&mut self, anim_id: u32) -> bool {
@@ -363,6 +539,13 @@ impl VizijAnimation {
         swb::to_value(&v).map_err(|e| JsError::new(&format!("list_instances error: {e}")))
     }
 
+    /// Effective duration of a player in seconds: the latest instance end for `once` players,
+    /// `Infinity` for looping ones, 0 for unknown ids.
+    #[wasm_bindgen(js_name = player_duration)]
+    pub fn player_duration(&self, player_id: u32) -> f32 {
+        self.core.player_duration(PlayerId(player_id))
+    }
+
     /// List the set of resolved output keys currently associated with the player's instances.
     #[wasm_bindgen(js_name = list_player_keys)]
     pub fn list_player_keys(&self, player_id: u32) -> Result<JsValue, JsError> {
@@ -370,6 +553,23 @@ impl VizijAnimation {
         swb::to_value(&v).map_err(|e| JsError::new(&format!("list_player_keys error: {e}")))
     }
 
+    /// List every animation channel as `{ anim, track_idx, target }`, with `target` null for
+    /// channels the resolver did not bind.
+    #[wasm_bindgen(js_name = binding_report)]
+    pub fn binding_report(&self) -> Result<JsValue, JsError> {
+        let rows: Vec<BindingReportRow> = self
+            .core
+            .binding_report()
+            .into_iter()
+            .map(|(ch, target)| BindingReportRow {
+                anim: ch.anim.0,
+                track_idx: ch.track_idx,
+                target,
+            })
+            .collect();
+        swb::to_value(&rows).map_err(|e| JsError::new(&format!("binding_report error: {e}")))
+    }
+
     /// Bake animation samples and derivatives for the specified animation id.
     #[wasm_bindgen(js_name = bake_animation_with_derivatives)]
     pub fn bake_animation_with_derivatives(
@@ -395,5 +595,76 @@ impl VizijAnimation {
 /// Numeric ABI version for compatibility checks at init.
 #[wasm_bindgen]
 pub fn abi_version() -> u32 {
-    2
+    vizij_api_core::abi::ANIMATION_WASM
+}
+
+/// Fail with a descriptive error unless this module's ABI equals `expected`.
+#[wasm_bindgen]
+pub fn check_abi(expected: u32) -> Result<(), JsValue> {
+    vizij_api_core::abi::check(
+        "vizij-animation-wasm",
+        vizij_api_core::abi::ANIMATION_WASM,
+        expected,
+    )
+    .map_err(|e| JsValue::from_str(&e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vizij_animation_core::{Keypoint, Track, TrackValue};
+
+    fn engine_with_scalar_track() -> VizijAnimation {
+        let keypoint = |stamp: f32| Keypoint {
+            id: format!("k{stamp}"),
+            stamp,
+            value: TrackValue::Float(2.0),
+            transitions: None,
+            interpolation: None,
+        };
+        let data = AnimationData {
+            id: None,
+            name: "hold".to_string(),
+            tracks: vec![Track {
+                id: "t0".to_string(),
+                name: "x".to_string(),
+                animatable_id: "node/x".to_string(),
+                points: vec![keypoint(0.0), keypoint(1.0)],
+                settings: None,
+                units: None,
+            }],
+            groups: serde_json::json!({}),
+            duration_ms: 1000,
+        };
+        let mut anim = VizijAnimation {
+            core: Engine::new(Config::default()),
+        };
+        let aid = anim.core.load_animation(data);
+        let pid = anim.core.create_player("p");
+        anim.core.add_instance(pid, aid, InstanceCfg::default());
+        anim
+    }
+
+    #[test]
+    fn panicking_step_returns_error_and_recovers() {
+        let mut anim = engine_with_scalar_track();
+
+        let err = anim
+            .guarded::<()>("update_values", |core| {
+                core.update_values(0.25, Inputs::default());
+                panic!("injected engine panic");
+            })
+            .expect_err("panic surfaces as an error");
+        assert_eq!(err, "update_values panicked: injected engine panic");
+
+        let batch = anim
+            .guarded("update_nodes_writes", |core| {
+                core.update_writebatch(0.25, Inputs::default())
+            })
+            .expect("engine steps again after a panic");
+        assert_eq!(batch.len(), 1);
+        let op = batch.iter().next().unwrap();
+        assert_eq!(op.path.to_string(), "node/x");
+        assert_eq!(vizij_api_core::value::as_float(&op.value), Some(2.0));
+    }
 }
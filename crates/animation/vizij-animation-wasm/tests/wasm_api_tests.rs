@@ -20,15 +20,18 @@ fn test_animation_json() -> JsValue {
                 stamp: 0.0,
                 value: TrackValue::Float(0.0),
                 transitions: None,
+                interpolation: None,
             },
             Keypoint {
                 id: "k1".into(),
                 stamp: 1.0,
                 value: TrackValue::Float(1.0),
                 transitions: None,
+                interpolation: None,
             },
         ],
         settings: None,
+        units: None,
     };
     let anim = AnimationData {
         id: None,
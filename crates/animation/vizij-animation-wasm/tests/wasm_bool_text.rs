@@ -37,6 +37,7 @@ fn mk_bool_track(path: &str, keys: &[(f32, bool)]) -> Track {
             stamp: *stamp,
             value: TrackValue::Bool(*v),
             transitions: None,
+            interpolation: None,
         });
     }
     Track {
@@ -45,6 +46,7 @@ fn mk_bool_track(path: &str, keys: &[(f32, bool)]) -> Track {
         animatable_id: path.to_string(),
         points,
         settings: None,
+        units: None,
     }
 }
 
@@ -56,6 +58,7 @@ fn mk_text_track(path: &str, keys: &[(f32, &str)]) -> Track {
             stamp: *stamp,
             value: TrackValue::Text((*s).to_string()),
             transitions: None,
+            interpolation: None,
         });
     }
     Track {
@@ -64,6 +67,7 @@ fn mk_text_track(path: &str, keys: &[(f32, &str)]) -> Track {
         animatable_id: path.to_string(),
         points,
         settings: None,
+        units: None,
     }
 }
 
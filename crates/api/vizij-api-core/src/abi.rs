@@ -0,0 +1,62 @@
+//! ABI versions of the Vizij wasm bindings.
+//!
+//! Each binding reports its own constant from `abi_version()`; keeping them together lets any
+//! binding answer for the whole set, so a JS loader can validate every module in one call.
+
+/// ABI version of `vizij-api-wasm`.
+pub const API_WASM: u32 = 1;
+/// ABI version of `vizij-graph-wasm`.
+pub const GRAPH_WASM: u32 = 2;
+/// ABI version of `vizij-animation-wasm`.
+pub const ANIMATION_WASM: u32 = 2;
+
+/// `(crate name, ABI version)` for every wasm binding in the workspace.
+pub fn versions() -> [(&'static str, u32); 3] {
+    [
+        ("vizij-api-wasm", API_WASM),
+        ("vizij-graph-wasm", GRAPH_WASM),
+        ("vizij-animation-wasm", ANIMATION_WASM),
+    ]
+}
+
+/// [`versions`] as a JSON object keyed by crate name.
+pub fn versions_json() -> String {
+    let map: serde_json::Map<String, serde_json::Value> = versions()
+        .into_iter()
+        .map(|(name, version)| (name.to_string(), version.into()))
+        .collect();
+    serde_json::Value::Object(map).to_string()
+}
+
+/// Compare a binding's ABI against the version a host was built for.
+pub fn check(crate_name: &str, actual: u32, expected: u32) -> Result<(), String> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(format!(
+            "{crate_name} ABI mismatch: module reports {actual}, host expects {expected}; \
+             rebuild the wasm package or update the host"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versions_json_reports_every_constant() {
+        let parsed: serde_json::Value = serde_json::from_str(&versions_json()).unwrap();
+        assert_eq!(parsed["vizij-api-wasm"], API_WASM);
+        assert_eq!(parsed["vizij-graph-wasm"], GRAPH_WASM);
+        assert_eq!(parsed["vizij-animation-wasm"], ANIMATION_WASM);
+        assert_eq!(parsed.as_object().unwrap().len(), versions().len());
+    }
+
+    #[test]
+    fn check_reports_mismatches() {
+        assert!(check("vizij-graph-wasm", GRAPH_WASM, 2).is_ok());
+        let err = check("vizij-graph-wasm", GRAPH_WASM, 1).unwrap_err();
+        assert!(err.contains("vizij-graph-wasm") && err.contains("expects 1"));
+    }
+}
@@ -1,7 +1,9 @@
 //! Blackboard storage and per-entry conflict tracking across writes.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
+use std::mem;
+use std::sync::Arc;
 
 /// Error from a blackboard JSON write: what failed to parse, as text.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -19,6 +21,7 @@ type Result<T> = std::result::Result<T, BlackboardError>;
 
 use serde::{Deserialize, Serialize};
 
+use crate::value::{self as vocab, VizijKind};
 use crate::{json, Shape, TypedPath, Value, WriteBatch};
 
 /// Single blackboard entry with provenance information.
@@ -55,6 +58,17 @@ impl BlackboardEntry {
     }
 }
 
+/// Write metadata for one blackboard entry, as returned by [`Blackboard::get_meta`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlackboardMeta {
+    /// Current value stored at the path.
+    pub value: Value,
+    /// Epoch of the most recent write.
+    pub last_modified: u64,
+    /// Writer/controller label of the most recent write.
+    pub last_writer: String,
+}
+
 /// A conflict record produced when a write overwrote an existing entry.
 /// Provides prior metadata for diagnostics.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -80,18 +94,113 @@ pub struct ConflictLog {
     pub new_source: String,
 }
 
-#[derive(Debug, Default)]
+/// Callback registered with [`Blackboard::on_change`], called with the written path and value.
+pub type ChangeCallback = Box<dyn Fn(&str, &Value) + Send + Sync>;
+
+/// A registered [`ChangeCallback`], shared between the watcher list and queued notifications.
+type SharedCallback = Arc<dyn Fn(&str, &Value) + Send + Sync>;
+
+/// Watcher notifications queued by blackboard writes, as returned by
+/// [`Blackboard::drain_notifications`].
+///
+/// Holds each written path and value together with the callbacks it matched, so it can be
+/// dispatched after any lock around the blackboard has been released.
+#[derive(Default)]
+pub struct ChangeNotifications(Vec<(String, Value, Vec<SharedCallback>)>);
+
+impl ChangeNotifications {
+    /// Whether no write matched a watcher.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Run every queued callback, in write order.
+    pub fn dispatch(self) {
+        for (path, value, callbacks) in self.0 {
+            for callback in callbacks {
+                callback(&path, &value);
+            }
+        }
+    }
+}
+
+impl fmt::Debug for ChangeNotifications {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|(path, value, _)| (path, value)))
+            .finish()
+    }
+}
+
+#[derive(Default)]
 pub struct Blackboard {
     // Map from canonical TypedPath -> entry
     inner: HashMap<TypedPath, BlackboardEntry>,
+    // (path prefix, callback) pairs matched against each write
+    watchers: Vec<(String, SharedCallback)>,
+    // Matched writes waiting for `drain_notifications`
+    pending: ChangeNotifications,
+}
+
+impl fmt::Debug for Blackboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Blackboard")
+            .field("inner", &self.inner)
+            .field("watchers", &self.watchers.len())
+            .field("pending", &self.pending.0.len())
+            .finish()
+    }
 }
 
 impl Blackboard {
     /// Create a new empty blackboard.
     pub fn new() -> Self {
-        Self {
-            inner: HashMap::new(),
+        Self::default()
+    }
+
+    /// Register `callback` for every write to a path at or below `path_prefix`.
+    ///
+    /// A prefix matches the path itself and anything beneath it across either separator
+    /// (`"robot/arm"` matches `"robot/arm/Joint1.angle"` and `"robot/arm.enabled"`); an empty
+    /// prefix matches every write. Writes do not call the callback themselves: they queue a
+    /// notification that runs when the caller dispatches [`Self::drain_notifications`], so a
+    /// store wrapping this blackboard can release its lock first and callbacks may read it back.
+    pub fn on_change(&mut self, path_prefix: &str, callback: ChangeCallback) -> Result<()> {
+        let prefix = path_prefix.trim();
+        if !prefix.is_empty() {
+            TypedPath::parse(prefix)
+                .map_err(|e| BlackboardError(format!("typedpath parse error: {}", e)))?;
+        }
+        self.watchers
+            .push((prefix.to_string(), Arc::from(callback)));
+        Ok(())
+    }
+
+    /// Take the notifications queued by writes since the last call.
+    ///
+    /// Call [`ChangeNotifications::dispatch`] on the result once the blackboard is no longer
+    /// borrowed. Notifications accumulate until drained.
+    pub fn drain_notifications(&mut self) -> ChangeNotifications {
+        mem::take(&mut self.pending)
+    }
+
+    /// Store `entry` at `path` and queue a notification for matching [`Self::on_change`]
+    /// watchers.
+    fn insert(&mut self, path: TypedPath, entry: BlackboardEntry) -> Option<BlackboardEntry> {
+        if self.watchers.is_empty() {
+            return self.inner.insert(path, entry);
         }
+        let key = path.to_string();
+        let callbacks: Vec<SharedCallback> = self
+            .watchers
+            .iter()
+            .filter(|(prefix, _)| path_has_prefix(&key, prefix))
+            .map(|(_, callback)| Arc::clone(callback))
+            .collect();
+        if !callbacks.is_empty() {
+            self.pending.0.push((key, entry.value.clone(), callbacks));
+        }
+        self.inner.insert(path, entry)
     }
 
     /// Set a value at a path given as a string.
@@ -109,10 +218,67 @@ impl Blackboard {
         let tp = TypedPath::parse(path)
             .map_err(|e| BlackboardError(format!("typedpath parse error: {}", e)))?;
         let entry = BlackboardEntry::new(value, shape, epoch, source, 0);
-        self.inner.insert(tp, entry);
+        self.insert(tp, entry);
         Ok(())
     }
 
+    /// Set several values as one all-or-nothing update.
+    ///
+    /// Every path is parsed before anything is written, so a bad entry leaves the blackboard
+    /// unchanged. Callers sharing the blackboard behind a lock see either none or all of the
+    /// updates, since the whole batch is applied under one `&mut` borrow.
+    pub fn set_batch(
+        &mut self,
+        updates: Vec<(String, Value)>,
+        epoch: u64,
+        source: String,
+    ) -> Result<()> {
+        let parsed = updates
+            .into_iter()
+            .map(|(path, value)| {
+                TypedPath::parse(&path).map(|tp| (tp, value)).map_err(|e| {
+                    BlackboardError(format!("typedpath parse error at '{}': {}", path, e))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        for (tp, value) in parsed {
+            self.insert(
+                tp,
+                BlackboardEntry::new(value, None, epoch, source.clone(), 0),
+            );
+        }
+        Ok(())
+    }
+
+    /// Set a value, optionally refusing to change the kind of an existing entry.
+    ///
+    /// With `strict` set, writing over an entry whose value has a different [`VizijKind`] (or,
+    /// for values outside the vocabulary, a different Arora variant) errors and leaves the entry
+    /// untouched. Without it this behaves exactly like [`Self::set`].
+    pub fn set_typed(
+        &mut self,
+        path: &str,
+        value: Value,
+        shape: Option<Shape>,
+        strict: bool,
+        epoch: u64,
+        source: String,
+    ) -> Result<()> {
+        if strict {
+            if let Some(existing) = self.get(path) {
+                if !same_kind(&existing.value, &value) {
+                    return Err(BlackboardError(format!(
+                        "type mismatch at '{}': stored {:?}, got {:?}",
+                        path,
+                        vocab::kind(&existing.value),
+                        vocab::kind(&value)
+                    )));
+                }
+            }
+        }
+        self.set(path, value, shape, epoch, source)
+    }
+
     /// Set a value from JSON payloads: the value goes through the api-core
     /// normalizer ([`json::parse_value`], accepting every payload form vizij
     /// hosts have emitted) and the shape through plain serde. Existing entries
@@ -145,7 +311,7 @@ impl Blackboard {
         path: TypedPath,
         entry: BlackboardEntry,
     ) -> Option<BlackboardEntry> {
-        self.inner.insert(path, entry)
+        self.insert(path, entry)
     }
 
     /// Get an entry by path string. Returns `None` if absent or if the path fails to parse.
@@ -157,6 +323,16 @@ impl Blackboard {
         }
     }
 
+    /// Get the value at `path` with when and by whom it was last written. Returns `None` if
+    /// absent or if the path fails to parse.
+    pub fn get_meta(&self, path: &str) -> Option<BlackboardMeta> {
+        self.get(path).map(|entry| BlackboardMeta {
+            value: entry.value.clone(),
+            last_modified: entry.epoch,
+            last_writer: entry.source.clone(),
+        })
+    }
+
     /// Fetch an entry using a pre-parsed TypedPath (avoids re-parse per tick).
     pub fn get_tp(&self, path: &TypedPath) -> Option<&BlackboardEntry> {
         self.inner.get(path)
@@ -176,9 +352,129 @@ impl Blackboard {
         self.inner.iter()
     }
 
+    /// Find every entry whose path matches `pattern`, sorted by path.
+    ///
+    /// Patterns split on both `/` and `.`, so `robot.*.angle` and `robot/*.angle` are the same
+    /// query. `*` matches exactly one segment and `**` matches any number of segments (including
+    /// none). Errors on an empty pattern or an empty segment.
+    pub fn query(&self, pattern: &str) -> Result<Vec<(String, Value)>> {
+        let segments: Vec<&str> = pattern.split(['/', '.']).collect();
+        if segments.iter().any(|segment| segment.is_empty()) {
+            return Err(BlackboardError(format!(
+                "invalid query pattern '{}': empty segment",
+                pattern
+            )));
+        }
+        let pattern = segments;
+        let mut matches: Vec<(String, Value)> = self
+            .inner
+            .iter()
+            .filter_map(|(tp, entry)| {
+                let segments: Vec<&str> = tp
+                    .namespaces()
+                    .chain(std::iter::once(tp.target_name()))
+                    .chain(tp.fields())
+                    .collect();
+                glob_segments(&pattern, &segments).then(|| (tp.to_string(), entry.value.clone()))
+            })
+            .collect();
+        matches.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(matches)
+    }
+
+    /// Export the entries at or below `path` as nested JSON, e.g. for persistence.
+    ///
+    /// Subtrees follow `/` namespace boundaries: each `/` segment below `path` becomes an object
+    /// key, field selectors stay part of the last key (`"Joint3.angle"`), and every leaf holds the
+    /// entry's value in its JSON wire form. An empty `path` exports the whole blackboard, and a
+    /// `path` naming a single entry exports just that value. Returns `Ok(None)` when nothing lives
+    /// under `path`; errors when `path` fails to parse or an entry is also the namespace of
+    /// another entry.
+    pub fn export_subtree(&self, path: &str) -> Result<Option<serde_json::Value>> {
+        let root = namespace_segments(path)?;
+        let mut leaves: Vec<(Vec<String>, &BlackboardEntry)> = self
+            .inner
+            .iter()
+            .filter_map(|(tp, entry)| {
+                let segments = path_segments(tp);
+                segments
+                    .starts_with(&root)
+                    .then(|| (segments[root.len()..].to_vec(), entry))
+            })
+            .collect();
+        if leaves.is_empty() {
+            return Ok(None);
+        }
+        leaves.sort_by(|a, b| a.0.cmp(&b.0));
+
+        // Leaf values serialize to JSON objects too, so descending into one has to be caught
+        // explicitly rather than by the shape of the tree.
+        let mut leaf_paths: HashSet<&[String]> = HashSet::with_capacity(leaves.len());
+        let mut tree = serde_json::Value::Null;
+        for (rest, entry) in &leaves {
+            if let Some(depth) = (0..rest.len()).find(|&i| leaf_paths.contains(&rest[..i])) {
+                return Err(namespace_conflict(&root, &rest[..depth]));
+            }
+            leaf_paths.insert(rest);
+            let value = serde_json::to_value(&entry.value)
+                .map_err(|e| BlackboardError(format!("value serialize: {}", e)))?;
+            let mut node = &mut tree;
+            for segment in rest {
+                if node.is_null() {
+                    *node = serde_json::Value::Object(serde_json::Map::new());
+                }
+                node = match node {
+                    serde_json::Value::Object(map) => map
+                        .entry(segment.clone())
+                        .or_insert(serde_json::Value::Null),
+                    _ => return Err(namespace_conflict(&root, rest)),
+                };
+            }
+            if !node.is_null() {
+                return Err(namespace_conflict(&root, rest));
+            }
+            *node = value;
+        }
+        Ok(Some(tree))
+    }
+
+    /// Import a nested JSON tree (as produced by [`Self::export_subtree`]) below `path`.
+    ///
+    /// Object keys become `/` segments under `path`. An object that deserializes as a value wire
+    /// form (`{"f32": 1.0}`, `{"struct": ...}`, ...) is a leaf; any other object is a namespace
+    /// and other JSON goes through [`json::parse_value`]. With `overwrite` set, every existing
+    /// entry under `path` is removed first; otherwise the tree is merged in and only the imported
+    /// leaves are replaced. Nothing is written if any key or leaf fails to parse. Returns the
+    /// number of entries written.
+    pub fn import_subtree(
+        &mut self,
+        path: &str,
+        tree: serde_json::Value,
+        overwrite: bool,
+        epoch: u64,
+        source: String,
+    ) -> Result<usize> {
+        let root = namespace_segments(path)?;
+        let mut leaves = Vec::new();
+        collect_leaves(&mut root.clone(), tree, &mut leaves)?;
+
+        if overwrite {
+            self.inner
+                .retain(|tp, _| !path_segments(tp).starts_with(&root));
+        }
+        let count = leaves.len();
+        for (tp, value) in leaves {
+            self.insert(
+                tp,
+                BlackboardEntry::new(value, None, epoch, source.clone(), 0),
+            );
+        }
+        Ok(count)
+    }
+
     /// Apply a [`WriteBatch`] onto the blackboard using last-writer-wins semantics.
     ///
-    /// Batch order determines the final value when multiple ops target the same path. Returned
+    /// A batch holds one write per path, so each path yields at most one conflict log. Returned
     /// conflict logs include both the overwritten entry metadata and the replacement metadata.
     pub fn apply_writebatch(
         &mut self,
@@ -211,7 +507,7 @@ impl Blackboard {
 
             // last-writer-wins: overwrite unconditionally
             let entry = BlackboardEntry::new(new_value, new_shape, epoch, source.clone(), 0);
-            self.inner.insert(tp, entry);
+            self.insert(tp, entry);
 
             if let Some(c) = conflict {
                 conflicts.push(c);
@@ -222,10 +518,106 @@ impl Blackboard {
     }
 }
 
+/// Whether `a` and `b` are the same kind of value for [`Blackboard::set_typed`].
+fn same_kind(a: &Value, b: &Value) -> bool {
+    match (vocab::kind(a), vocab::kind(b)) {
+        (VizijKind::Other, VizijKind::Other) => mem::discriminant(a) == mem::discriminant(b),
+        (ka, kb) => ka == kb,
+    }
+}
+
+/// Whether `path` equals `prefix` or lies beneath it across a `/` or `.` boundary.
+fn path_has_prefix(path: &str, prefix: &str) -> bool {
+    prefix.is_empty()
+        || path
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with(['/', '.']))
+}
+
+/// Match path `segments` against a query `pattern` of literal, `*` and `**` segments.
+fn glob_segments(pattern: &[&str], segments: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => segments.is_empty(),
+        Some((&"**", rest)) => {
+            (0..=segments.len()).any(|skip| glob_segments(rest, &segments[skip..]))
+        }
+        Some((&head, rest)) => segments.split_first().is_some_and(|(&segment, tail)| {
+            (head == "*" || head == segment) && glob_segments(rest, tail)
+        }),
+    }
+}
+
+/// The `/` segments of `tp`, with field selectors left on the last segment.
+fn path_segments(tp: &TypedPath) -> Vec<String> {
+    tp.to_string().split('/').map(str::to_string).collect()
+}
+
+/// Split a subtree root into its `/` segments; an empty (or blank) path is the blackboard root.
+fn namespace_segments(path: &str) -> Result<Vec<String>> {
+    if path.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    let tp = TypedPath::parse(path)
+        .map_err(|e| BlackboardError(format!("typedpath parse error: {}", e)))?;
+    Ok(path_segments(&tp))
+}
+
+/// Walk `tree` below `segments`, collecting each leaf with its parsed path.
+fn collect_leaves(
+    segments: &mut Vec<String>,
+    tree: serde_json::Value,
+    out: &mut Vec<(TypedPath, Value)>,
+) -> Result<()> {
+    let tree = match tree {
+        serde_json::Value::Object(map) => {
+            match serde_json::from_value::<Value>(serde_json::Value::Object(map.clone())) {
+                Ok(value) => return push_leaf(segments, value, out),
+                Err(_) => map,
+            }
+        }
+        other => {
+            let value = json::parse_value(other)
+                .map_err(|e| BlackboardError(format!("value deserialize: {}", e)))?;
+            return push_leaf(segments, value, out);
+        }
+    };
+    for (key, child) in tree {
+        segments.push(key);
+        collect_leaves(segments, child, out)?;
+        segments.pop();
+    }
+    Ok(())
+}
+
+fn push_leaf(segments: &[String], value: Value, out: &mut Vec<(TypedPath, Value)>) -> Result<()> {
+    if segments.is_empty() {
+        return Err(BlackboardError(
+            "cannot import a value at the blackboard root".to_string(),
+        ));
+    }
+    let tp = TypedPath::parse(&segments.join("/"))
+        .map_err(|e| BlackboardError(format!("typedpath parse error: {}", e)))?;
+    out.push((tp, value));
+    Ok(())
+}
+
+fn namespace_conflict(root: &[String], rest: &[String]) -> BlackboardError {
+    let path = root
+        .iter()
+        .chain(rest)
+        .cloned()
+        .collect::<Vec<_>>()
+        .join("/");
+    BlackboardError(format!(
+        "blackboard path '{}' is both a value and a namespace",
+        path
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::value::{as_vec3, float, vec3};
+    use crate::value::{as_bool, as_float, as_text, as_vec3, bool_, float, text, vec3};
     use crate::{WriteBatch, WriteOp};
 
     #[test]
@@ -261,4 +653,285 @@ mod tests {
         assert_eq!(c.new_epoch, 2);
         assert_eq!(c.new_source, "anim");
     }
+
+    #[test]
+    fn export_subtree_nests_namespaces() {
+        let mut bb = Blackboard::new();
+        for (path, value) in [
+            ("robot/arm/Joint1.angle", float(0.25)),
+            ("robot/arm/Joint2.angle", float(-0.5)),
+            ("robot/arm/enabled", bool_(true)),
+            ("robot/base/pose", vec3([1.0, 2.0, 3.0])),
+            ("camera/fov", float(60.0)),
+        ] {
+            bb.set(path, value, None, 1, "test".into()).unwrap();
+        }
+
+        let arm = bb
+            .export_subtree("robot/arm")
+            .unwrap()
+            .expect("arm subtree");
+        assert_eq!(
+            arm,
+            serde_json::json!({
+                "Joint1.angle": { "f32": 0.25 },
+                "Joint2.angle": { "f32": -0.5 },
+                "enabled": { "bool": true },
+            })
+        );
+
+        let all = bb.export_subtree("").unwrap().expect("whole blackboard");
+        assert_eq!(all["camera"]["fov"], serde_json::json!({ "f32": 60.0 }));
+        assert!(all["robot"]["base"]["pose"]["struct"].is_object());
+
+        assert_eq!(
+            bb.export_subtree("robot/arm/enabled").unwrap(),
+            Some(serde_json::json!({ "bool": true }))
+        );
+        assert_eq!(bb.export_subtree("robot/legs").unwrap(), None);
+    }
+
+    #[test]
+    fn export_subtree_rejects_value_namespace_collision() {
+        let mut bb = Blackboard::new();
+        bb.set("robot/arm", float(1.0), None, 1, "test".into())
+            .unwrap();
+        bb.set(
+            "robot/arm/Joint1.angle",
+            float(0.25),
+            None,
+            1,
+            "test".into(),
+        )
+        .unwrap();
+        let err = bb.export_subtree("robot").unwrap_err();
+        assert!(err.0.contains("robot/arm"), "unexpected error: {err}");
+    }
+
+    #[test]
+    fn import_subtree_merges_or_overwrites() {
+        let mut bb = Blackboard::new();
+        bb.set("robot/arm/stale", float(9.0), None, 1, "test".into())
+            .unwrap();
+
+        let tree = serde_json::json!({
+            "Joint1.angle": { "f32": 0.25 },
+            "gripper": { "open": true, "force": 2.5 },
+            "base": { "pose": serde_json::to_value(vec3([1.0, 2.0, 3.0])).unwrap() },
+        });
+        let written = bb
+            .import_subtree("robot/arm", tree.clone(), false, 2, "import".into())
+            .unwrap();
+        assert_eq!(written, 4);
+
+        let angle = bb.get("robot/arm/Joint1.angle").expect("angle");
+        assert_eq!(as_float(&angle.value), Some(0.25));
+        assert_eq!(angle.source, "import");
+        assert_eq!(
+            as_bool(&bb.get("robot/arm/gripper/open").expect("open").value),
+            Some(true)
+        );
+        assert_eq!(
+            as_float(&bb.get("robot/arm/gripper/force").expect("force").value),
+            Some(2.5)
+        );
+        assert_eq!(
+            as_vec3(&bb.get("robot/arm/base/pose").expect("pose").value),
+            Some([1.0, 2.0, 3.0])
+        );
+        assert!(bb.get("robot/arm/stale").is_some(), "merge keeps siblings");
+
+        bb.import_subtree("robot/arm", tree, true, 3, "import".into())
+            .unwrap();
+        assert!(
+            bb.get("robot/arm/stale").is_none(),
+            "overwrite clears subtree"
+        );
+        assert_eq!(
+            bb.export_subtree("robot/arm").unwrap().unwrap()["Joint1.angle"],
+            serde_json::json!({ "f32": 0.25 })
+        );
+    }
+
+    #[test]
+    fn set_typed_strict_rejects_kind_changes() {
+        let mut bb = Blackboard::new();
+        bb.set("robot/arm/speed", float(1.0), None, 1, "test".into())
+            .unwrap();
+
+        bb.set_typed("robot/arm/speed", float(2.0), None, true, 2, "test".into())
+            .expect("same kind is accepted");
+        let err = bb
+            .set_typed(
+                "robot/arm/speed",
+                text("fast"),
+                None,
+                true,
+                3,
+                "test".into(),
+            )
+            .unwrap_err();
+        assert!(err.0.contains("type mismatch"), "unexpected error: {err}");
+        let entry = bb.get("robot/arm/speed").unwrap();
+        assert_eq!(as_float(&entry.value), Some(2.0));
+        assert_eq!(entry.epoch, 2);
+
+        bb.set_typed(
+            "robot/arm/speed",
+            text("fast"),
+            None,
+            false,
+            4,
+            "test".into(),
+        )
+        .expect("non-strict overwrites");
+        assert_eq!(
+            as_text(&bb.get("robot/arm/speed").unwrap().value),
+            Some("fast")
+        );
+    }
+
+    #[test]
+    fn get_meta_reports_latest_writer() {
+        let mut bb = Blackboard::new();
+        bb.set("robot/arm/speed", float(1.0), None, 3, "planner".into())
+            .unwrap();
+        bb.set("robot/arm/speed", float(2.0), None, 7, "teleop".into())
+            .unwrap();
+
+        let meta = bb.get_meta("robot/arm/speed").expect("meta");
+        assert_eq!(as_float(&meta.value), Some(2.0));
+        assert_eq!(meta.last_modified, 7);
+        assert_eq!(meta.last_writer, "teleop");
+        assert!(bb.get_meta("robot/arm/missing").is_none());
+    }
+
+    #[test]
+    fn on_change_fires_for_matching_writes() {
+        use std::sync::{Arc, Mutex};
+
+        type Seen = Vec<(String, Option<f32>)>;
+
+        let mut bb = Blackboard::new();
+        let seen: Arc<Mutex<Seen>> = Arc::default();
+        let sink = Arc::clone(&seen);
+        bb.on_change(
+            "robot/arm",
+            Box::new(move |path, value| {
+                sink.lock()
+                    .unwrap()
+                    .push((path.to_string(), as_float(value)));
+            }),
+        )
+        .unwrap();
+
+        bb.set("robot/arm/Joint1.angle", float(0.5), None, 1, "test".into())
+            .unwrap();
+        bb.set("robot/armature", float(1.0), None, 1, "test".into())
+            .unwrap();
+        bb.set("camera/fov", float(60.0), None, 1, "test".into())
+            .unwrap();
+        let mut batch = WriteBatch::new();
+        batch.push(WriteOp::new(
+            TypedPath::parse("robot/arm.enabled").unwrap(),
+            float(1.0),
+        ));
+        bb.apply_writebatch(batch, 2, "anim".into());
+
+        // Nothing runs until the queued notifications are dispatched.
+        assert!(seen.lock().unwrap().is_empty());
+        bb.drain_notifications().dispatch();
+        assert!(bb.drain_notifications().is_empty());
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![
+                ("robot/arm/Joint1.angle".to_string(), Some(0.5)),
+                ("robot/arm.enabled".to_string(), Some(1.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn set_batch_is_all_or_nothing() {
+        let mut bb = Blackboard::new();
+        bb.set("robot/arm/speed", float(1.0), None, 1, "test".into())
+            .unwrap();
+
+        let err = bb
+            .set_batch(
+                vec![
+                    ("robot/arm/speed".into(), float(2.0)),
+                    ("robot/arm/bad path".into(), float(3.0)),
+                ],
+                2,
+                "batch".into(),
+            )
+            .unwrap_err();
+        assert!(err.0.contains("bad path"), "unexpected error: {err}");
+        assert_eq!(bb.iter().count(), 1);
+        assert_eq!(
+            as_float(&bb.get("robot/arm/speed").unwrap().value),
+            Some(1.0)
+        );
+
+        bb.set_batch(
+            vec![
+                ("robot/arm/speed".into(), float(2.0)),
+                ("robot/arm/enabled".into(), bool_(true)),
+            ],
+            2,
+            "batch".into(),
+        )
+        .unwrap();
+        assert_eq!(
+            as_float(&bb.get("robot/arm/speed").unwrap().value),
+            Some(2.0)
+        );
+        assert_eq!(
+            as_bool(&bb.get("robot/arm/enabled").unwrap().value),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn query_matches_single_and_deep_wildcards() {
+        let mut bb = Blackboard::new();
+        for (path, value) in [
+            ("robot/shoulder.angle", 0.1),
+            ("robot/elbow.angle", 0.2),
+            ("robot/elbow.velocity", 0.3),
+            ("robot/wrist/roll.angle", 0.4),
+            ("camera/fov", 60.0),
+        ] {
+            bb.set(path, float(value), None, 1, "test".into()).unwrap();
+        }
+        let paths = |pattern: &str| -> Vec<String> {
+            bb.query(pattern)
+                .unwrap()
+                .into_iter()
+                .map(|(path, _)| path)
+                .collect()
+        };
+
+        assert_eq!(
+            paths("robot.*.angle"),
+            vec!["robot/elbow.angle", "robot/shoulder.angle"]
+        );
+        assert_eq!(
+            paths("robot.**.angle"),
+            vec![
+                "robot/elbow.angle",
+                "robot/shoulder.angle",
+                "robot/wrist/roll.angle"
+            ]
+        );
+        assert_eq!(paths("**.velocity"), vec!["robot/elbow.velocity"]);
+        assert_eq!(paths("camera.fov"), vec!["camera/fov"]);
+        assert!(paths("robot.*").is_empty());
+
+        let hits = bb.query("robot/elbow.*").unwrap();
+        assert_eq!(hits.len(), 2);
+        assert_eq!(as_float(&hits[0].1), Some(0.2));
+        assert!(bb.query("robot..angle").is_err());
+    }
 }
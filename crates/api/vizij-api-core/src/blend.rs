@@ -12,7 +12,13 @@
 //! - mismatched kinds fall back to numeric coercion (vector-shaped operands
 //!   blend as vectors, anything else as scalars);
 //! - [`step_blend`] picks an operand whole (`t < 0.5` -> left), for
-//!   step-only kinds such as booleans, text, and enumerations.
+//!   step-only kinds such as booleans, text, and enumerations;
+//! - [`weighted_average`] combines any number of same-kind operands by weight,
+//!   normalizing quaternions after a hemisphere-aligned sum (nlerp);
+//! - [`lerp`] (or [`ValueLerp::lerp`]) is the strict interpolation: matching
+//!   kinds only (`None` otherwise), nlerp for quaternions;
+//! - [`apply`] layers one value over another under a [`BlendMode`]
+//!   (override, additive, multiply), composing rotations as quaternions.
 
 use crate::coercion;
 use crate::value::{
@@ -21,6 +27,7 @@ use crate::value::{
     Transform, VizijKind,
 };
 use crate::Value;
+use serde::{Deserialize, Serialize};
 
 /// Linear interpolation for f32
 #[inline]
@@ -267,6 +274,310 @@ pub fn step_blend(a: &Value, b: &Value, t: f32) -> Value {
     }
 }
 
+/// Interpolate `a` toward `b` by `t`, requiring both to share a kind.
+///
+/// Floats, fixed-size vectors, colors, and equal-length vectors lerp
+/// componentwise; quaternions nlerp (shortest arc, normalized); transforms
+/// lerp translation/scale and slerp rotation; records and sequences
+/// interpolate entrywise and must carry the same field names / length.
+/// Returns `None` on a kind, length, or field mismatch and for kinds with no
+/// interpolation (booleans, text, enumerations, ...); use [`blend_values`]
+/// for the lenient, coercing blend.
+pub fn lerp(a: &Value, b: &Value, t: f32) -> Option<Value> {
+    use VizijKind as K;
+    match (kind(a), kind(b)) {
+        (K::Float, K::Float)
+        | (K::Vec2, K::Vec2)
+        | (K::Vec3, K::Vec3)
+        | (K::Vec4, K::Vec4)
+        | (K::ColorRgba, K::ColorRgba)
+        | (K::Transform, K::Transform) => Some(blend_values(a, b, t)),
+        (K::Vector, K::Vector) => {
+            let (va, vb) = (as_vector(a)?, as_vector(b)?);
+            (va.len() == vb.len()).then(|| vector(blend_vector(va, vb, t)))
+        }
+        (K::Quat, K::Quat) => nlerp(
+            [as_quat(a), as_quat(b)].into_iter(),
+            [1.0 - t, t].into_iter(),
+        )
+        .map(quat),
+        (K::Record, K::Record) => {
+            let (ra, rb) = (as_record(a)?, as_record(b)?);
+            if ra.len() != rb.len() {
+                return None;
+            }
+            let entries = ra
+                .iter()
+                .map(|(name, va)| {
+                    let (_, vb) = rb.iter().find(|(other, _)| other == name)?;
+                    Some((*name, lerp(va, vb, t)?))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some(record(entries))
+        }
+        (K::Array, K::Array) => {
+            let (ia, ib) = (as_array(a)?, as_array(b)?);
+            if ia.len() != ib.len() {
+                return None;
+            }
+            let items = ia
+                .iter()
+                .zip(ib)
+                .map(|(va, vb)| lerp(va, vb, t))
+                .collect::<Option<Vec<_>>>()?;
+            Some(array(items))
+        }
+        _ => None,
+    }
+}
+
+/// Method-call form of [`lerp`], e.g. `a.lerp(&b, 0.5)`.
+///
+/// [`Value`] is Arora's type, so this extension trait stands in for an
+/// inherent `Value::lerp`.
+pub trait ValueLerp {
+    /// Strict interpolation toward `other`; see [`lerp`].
+    fn lerp(&self, other: &Value, t: f32) -> Option<Value>;
+}
+
+impl ValueLerp for Value {
+    fn lerp(&self, other: &Value, t: f32) -> Option<Value> {
+        lerp(self, other, t)
+    }
+}
+
+/// How [`apply`] combines a layer with the value beneath it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BlendMode {
+    /// Interpolate from the base toward the layer by the weight.
+    #[default]
+    Override,
+    /// Add the weighted layer to the base; rotations compose (`base * layer^weight`).
+    Additive,
+    /// Scale the base by the layer, faded from 1 by the weight; rotations compose.
+    Multiply,
+}
+
+/// Layer `layer` over `base` with `mode` at `weight` (typically `0..=1`).
+///
+/// `Override` is [`blend_values`]. `Additive` computes `base + layer * weight`
+/// and `Multiply` computes `base * lerp(1, layer, weight)` componentwise for
+/// floats, fixed-size vectors, colors, and vectors (missing layer elements
+/// leave the base untouched). In both modes quaternions compose as
+/// `base * slerp(identity, layer, weight)`, and transforms combine
+/// translation/scale with the mode's arithmetic and compose rotation (an
+/// additive transform still scales multiplicatively). Records and sequences
+/// apply fieldwise/index-wise, keeping base entries the layer lacks.
+/// Step-only or mismatched kinds fall back to the override blend.
+pub fn apply(base: &Value, layer: &Value, mode: BlendMode, weight: f32) -> Value {
+    use VizijKind as K;
+    if mode == BlendMode::Override {
+        return blend_values(base, layer, weight);
+    }
+    let combine = |b: f32, l: f32| match mode {
+        BlendMode::Additive => b + l * weight,
+        _ => b * lerp_f(1.0, l, weight),
+    };
+    let combine_array = |b: &[f32], l: &[f32]| -> Vec<f32> {
+        b.iter()
+            .enumerate()
+            .map(|(i, &bi)| l.get(i).map_or(bi, |&li| combine(bi, li)))
+            .collect()
+    };
+    let compose = |b: [f32; 4], l: [f32; 4]| {
+        normalize_quat(quat_mul(b, slerp([0.0, 0.0, 0.0, 1.0], l, weight)))
+    };
+
+    match (kind(base), kind(layer)) {
+        (K::Float, K::Float) => float(combine(
+            as_float(base).unwrap_or(0.0),
+            as_float(layer).unwrap_or(0.0),
+        )),
+        (K::Vec2, K::Vec2)
+        | (K::Vec3, K::Vec3)
+        | (K::Vec4, K::Vec4)
+        | (K::ColorRgba, K::ColorRgba)
+        | (K::Vector, K::Vector) => {
+            let out = combine_array(&coercion::to_vector(base), &coercion::to_vector(layer));
+            match kind(base) {
+                K::Vec2 => vec2([out[0], out[1]]),
+                K::Vec3 => vec3([out[0], out[1], out[2]]),
+                K::Vec4 => vec4([out[0], out[1], out[2], out[3]]),
+                K::ColorRgba => color_rgba([out[0], out[1], out[2], out[3]]),
+                _ => vector(out),
+            }
+        }
+        (K::Quat, K::Quat) => quat(compose(
+            as_quat(base).unwrap_or([0.0, 0.0, 0.0, 1.0]),
+            as_quat(layer).unwrap_or([0.0, 0.0, 0.0, 1.0]),
+        )),
+        (K::Transform, K::Transform) => {
+            let tb = as_transform(base).unwrap_or(IDENTITY_TRANSFORM);
+            let tl = as_transform(layer).unwrap_or(IDENTITY_TRANSFORM);
+            let translation = combine_array(&tb.translation, &tl.translation);
+            let scale: Vec<f32> = tb
+                .scale
+                .iter()
+                .zip(tl.scale)
+                .map(|(b, l)| b * lerp_f(1.0, l, weight))
+                .collect();
+            transform(Transform {
+                translation: [translation[0], translation[1], translation[2]],
+                rotation: compose(tb.rotation, tl.rotation),
+                scale: [scale[0], scale[1], scale[2]],
+            })
+        }
+        (K::Record, K::Record) => {
+            let layer_fields = as_record(layer).unwrap_or_default();
+            let entries: Vec<(&str, Value)> = as_record(base)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(name, base_value)| {
+                    let value = match layer_fields.iter().find(|(n, _)| *n == name) {
+                        Some((_, layer_value)) => apply(base_value, layer_value, mode, weight),
+                        None => base_value.clone(),
+                    };
+                    (name, value)
+                })
+                .collect();
+            record(entries)
+        }
+        (K::Array, K::Array) => {
+            let layer_items = as_array(layer).unwrap_or_default();
+            array(
+                as_array(base)
+                    .unwrap_or_default()
+                    .iter()
+                    .enumerate()
+                    .map(|(i, item)| match layer_items.get(i) {
+                        Some(l) => apply(item, l, mode, weight),
+                        None => item.clone(),
+                    })
+                    .collect(),
+            )
+        }
+        _ => blend_values(base, layer, weight),
+    }
+}
+
+/// Hamilton product of quaternions stored as `[x, y, z, w]`.
+fn quat_mul(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+    let [ax, ay, az, aw] = a;
+    let [bx, by, bz, bw] = b;
+    [
+        aw * bx + ax * bw + ay * bz - az * by,
+        aw * by - ax * bz + ay * bw + az * bx,
+        aw * bz + ax * by - ay * bx + az * bw,
+        aw * bw - ax * bx - ay * by - az * bz,
+    ]
+}
+
+/// Weighted average of same-kind values.
+///
+/// Weights are normalized by their sum. Floats, `vec2`/`vec3`/`vec4`, colors,
+/// and equal-length vectors average componentwise; quaternions are flipped
+/// into the first operand's hemisphere, summed, and normalized (nlerp);
+/// transforms average translation/scale and nlerp rotation. Returns `None`
+/// for an empty list, a non-positive total weight, mixed kinds, vectors of
+/// different lengths, or kinds without a numeric average (records, text, ...).
+pub fn weighted_average(values: &[(Value, f32)]) -> Option<Value> {
+    use VizijKind as K;
+    let (first, _) = values.first()?;
+    let first_kind = kind(first);
+    if values.iter().any(|(v, _)| kind(v) != first_kind) {
+        return None;
+    }
+    let total: f32 = values.iter().map(|(_, w)| w).sum();
+    if total <= 0.0 || !total.is_finite() {
+        return None;
+    }
+    let weights = values.iter().map(|(_, w)| w / total);
+
+    match first_kind {
+        K::Float => Some(float(
+            values
+                .iter()
+                .zip(weights)
+                .map(|((v, _), w)| as_float(v).unwrap_or(0.0) * w)
+                .sum(),
+        )),
+        K::Vec2 => weighted_sum(values.iter().map(|(v, _)| as_vec2(v)), weights).map(vec2),
+        K::Vec3 => weighted_sum(values.iter().map(|(v, _)| as_vec3(v)), weights).map(vec3),
+        K::Vec4 => weighted_sum(values.iter().map(|(v, _)| as_vec4(v)), weights).map(vec4),
+        K::ColorRgba => {
+            weighted_sum(values.iter().map(|(v, _)| as_color_rgba(v)), weights).map(color_rgba)
+        }
+        K::Vector => {
+            let len = as_vector(first)?.len();
+            let mut out = vec![0.0; len];
+            for ((v, _), w) in values.iter().zip(weights) {
+                let xs = as_vector(v).filter(|xs| xs.len() == len)?;
+                for (o, x) in out.iter_mut().zip(xs) {
+                    *o += x * w;
+                }
+            }
+            Some(vector(out))
+        }
+        K::Quat => nlerp(values.iter().map(|(v, _)| as_quat(v)), weights).map(quat),
+        K::Transform => {
+            let transforms: Vec<Transform> = values
+                .iter()
+                .map(|(v, _)| as_transform(v))
+                .collect::<Option<_>>()?;
+            let weights: Vec<f32> = weights.collect();
+            Some(transform(Transform {
+                translation: weighted_sum(
+                    transforms.iter().map(|t| Some(t.translation)),
+                    weights.iter().copied(),
+                )?,
+                rotation: nlerp(
+                    transforms.iter().map(|t| Some(t.rotation)),
+                    weights.iter().copied(),
+                )?,
+                scale: weighted_sum(
+                    transforms.iter().map(|t| Some(t.scale)),
+                    weights.iter().copied(),
+                )?,
+            }))
+        }
+        _ => None,
+    }
+}
+
+/// Componentwise weighted sum of fixed-size arrays; `None` if any operand failed to decode.
+fn weighted_sum<const N: usize>(
+    items: impl Iterator<Item = Option<[f32; N]>>,
+    weights: impl Iterator<Item = f32>,
+) -> Option<[f32; N]> {
+    let mut out = [0.0f32; N];
+    for (item, w) in items.zip(weights) {
+        for (o, x) in out.iter_mut().zip(item?) {
+            *o += x * w;
+        }
+    }
+    Some(out)
+}
+
+/// Weighted quaternion average: align each operand with the first, sum, normalize.
+fn nlerp(
+    items: impl Iterator<Item = Option<[f32; 4]>>,
+    weights: impl Iterator<Item = f32>,
+) -> Option<[f32; 4]> {
+    let mut reference: Option<[f32; 4]> = None;
+    let mut out = [0.0f32; 4];
+    for (item, w) in items.zip(weights) {
+        let q = normalize_quat(item?);
+        let r = *reference.get_or_insert(q);
+        let dot = q[0] * r[0] + q[1] * r[1] + q[2] * r[2] + q[3] * r[3];
+        let sign = if dot < 0.0 { -1.0 } else { 1.0 };
+        for (o, x) in out.iter_mut().zip(q) {
+            *o += x * w * sign;
+        }
+    }
+    Some(normalize_quat(out))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -364,6 +675,127 @@ mod tests {
         assert_eq!(as_float(&items[1]), Some(10.0));
     }
 
+    #[test]
+    fn weighted_average_scalars() {
+        let r = weighted_average(&[(float(1.0), 1.0), (float(4.0), 3.0)]).expect("average");
+        assert_eq!(as_float(&r), Some(3.25));
+    }
+
+    #[test]
+    fn weighted_average_vec3() {
+        let r = weighted_average(&[
+            (vec3([0.0, 0.0, 0.0]), 0.5),
+            (vec3([2.0, 4.0, 6.0]), 0.25),
+            (vec3([4.0, 0.0, 2.0]), 0.25),
+        ])
+        .expect("average");
+        assert_eq!(as_vec3(&r), Some([1.5, 1.0, 2.0]));
+    }
+
+    #[test]
+    fn weighted_average_quats_nlerp() {
+        let inv_sqrt2 = 1.0 / 2.0f32.sqrt();
+        let identity = quat([0.0, 0.0, 0.0, 1.0]);
+        // 90 degrees about Z, expressed in the opposite hemisphere.
+        let quarter_z = quat([0.0, 0.0, -inv_sqrt2, -inv_sqrt2]);
+        let r = weighted_average(&[(identity, 1.0), (quarter_z, 1.0)]).expect("average");
+        let q = as_quat(&r).expect("quat");
+        // Equal weights land on the 45-degree rotation about Z.
+        let (s, c) = (
+            std::f32::consts::FRAC_PI_8.sin(),
+            std::f32::consts::FRAC_PI_8.cos(),
+        );
+        for (got, want) in q.iter().zip([0.0, 0.0, s, c]) {
+            assert!((got - want).abs() < 1e-5, "{q:?}");
+        }
+    }
+
+    #[test]
+    fn weighted_average_rejects_mismatches() {
+        assert!(weighted_average(&[]).is_none());
+        assert!(weighted_average(&[(float(1.0), 1.0), (vec3([0.0; 3]), 1.0)]).is_none());
+        assert!(weighted_average(&[(float(1.0), 0.0)]).is_none());
+        assert!(
+            weighted_average(&[(vector(vec![1.0]), 1.0), (vector(vec![1.0, 2.0]), 1.0)]).is_none()
+        );
+    }
+
+    #[test]
+    fn lerp_matching_kinds() {
+        assert_eq!(
+            as_float(&lerp(&float(1.0), &float(3.0), 0.25).unwrap()),
+            Some(1.5)
+        );
+        assert_eq!(
+            as_vec3(&lerp(&vec3([0.0; 3]), &vec3([2.0, 4.0, 8.0]), 0.5).unwrap()),
+            Some([1.0, 2.0, 4.0])
+        );
+
+        let inv_sqrt2 = 1.0 / 2.0f32.sqrt();
+        let a = transform(IDENTITY_TRANSFORM);
+        let b = transform(Transform {
+            translation: [2.0, 0.0, -2.0],
+            rotation: [0.0, 0.0, 1.0, 0.0],
+            scale: [3.0; 3],
+        });
+        let r = as_transform(&lerp(&a, &b, 0.5).unwrap()).expect("transform");
+        assert_eq!(r.translation, [1.0, 0.0, -1.0]);
+        assert_eq!(r.scale, [2.0; 3]);
+        for (got, want) in r.rotation.iter().zip([0.0, 0.0, inv_sqrt2, inv_sqrt2]) {
+            assert!((got - want).abs() < 1e-5, "{:?}", r.rotation);
+        }
+    }
+
+    #[test]
+    fn lerp_method_form() {
+        let r = float(1.0).lerp(&float(3.0), 0.5);
+        assert_eq!(r.as_ref().and_then(as_float), Some(2.0));
+        assert!(float(1.0).lerp(&text("a"), 0.5).is_none());
+    }
+
+    #[test]
+    fn lerp_mismatch_is_none() {
+        assert!(lerp(&float(1.0), &vec3([0.0; 3]), 0.5).is_none());
+        assert!(lerp(&vector(vec![1.0]), &vector(vec![1.0, 2.0]), 0.5).is_none());
+        assert!(lerp(&text("a"), &text("b"), 0.5).is_none());
+        assert!(lerp(
+            &record([("x", float(0.0))]),
+            &record([("y", float(1.0))]),
+            0.5
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn apply_modes_on_scalars() {
+        let (base, layer) = (float(2.0), float(4.0));
+        let at = |mode| as_float(&apply(&base, &layer, mode, 0.5));
+        assert_eq!(at(BlendMode::Override), Some(3.0));
+        assert_eq!(at(BlendMode::Additive), Some(4.0));
+        assert_eq!(at(BlendMode::Multiply), Some(5.0));
+        assert_eq!(
+            as_float(&apply(&base, &layer, BlendMode::Multiply, 0.0)),
+            Some(2.0)
+        );
+    }
+
+    #[test]
+    fn apply_additive_composes_quaternions() {
+        let inv_sqrt2 = 1.0 / 2.0f32.sqrt();
+        // 90 degrees about Z layered onto 90 degrees about Z: 180 degrees about Z.
+        let quarter_z = quat([0.0, 0.0, inv_sqrt2, inv_sqrt2]);
+        let q = as_quat(&apply(&quarter_z, &quarter_z, BlendMode::Additive, 1.0)).expect("quat");
+        for (got, want) in q.iter().zip([0.0, 0.0, 1.0, 0.0]) {
+            assert!((got - want).abs() < 1e-5, "{q:?}");
+        }
+        // Half weight adds 45 degrees on top of the base 90.
+        let q = as_quat(&apply(&quarter_z, &quarter_z, BlendMode::Additive, 0.5)).expect("quat");
+        let half = (3.0 * std::f32::consts::PI / 8.0).sin_cos();
+        for (got, want) in q.iter().zip([0.0, 0.0, half.0, half.1]) {
+            assert!((got - want).abs() < 1e-5, "{q:?}");
+        }
+    }
+
     #[test]
     fn step_bool_text() {
         let a = bool_(false);
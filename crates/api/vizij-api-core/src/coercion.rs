@@ -1,4 +1,5 @@
-//! Lossy numeric coercions from any [`Value`] into scalar/vector PODs.
+//! Lossy coercions from any [`Value`] into scalar/vector PODs, booleans, and
+//! display text.
 //!
 //! These helpers give every value a numeric reading so mixed-kind blends and
 //! adapters always have something sensible to work with: scalars pass
@@ -6,11 +7,20 @@
 //! sequences flatten (records in name order, for determinism over the
 //! unordered field map), enumerations read through to their payload, and
 //! anything without a numeric reading coerces to zero/empty.
+//!
+//! [`broadcast`] aligns two values for point-wise math; it is the one
+//! definition of the scalar-broadcast rules, and the graph evaluator's
+//! point-wise nodes align their operands through it.
+//!
+//! [`matches_shape`] and [`validate_enum`] are the strict counterpart: they
+//! check a value against a declared [`ShapeId`] without coercing it.
 
 use crate::value::{
-    as_array, as_enumeration, as_record, as_transform, as_vec2, as_vec3, as_vec4, kind, VizijKind,
+    array, as_array, as_bool, as_color_rgba, as_enumeration, as_quat, as_record, as_text,
+    as_transform, as_vec2, as_vec3, as_vec4, as_vector, color_rgba, float, kind, quat, record,
+    transform, variant_id, vec2, vec3, vec4, vector, VizijKind,
 };
-use crate::Value;
+use crate::{ShapeId, Transform, Value};
 
 /// Coerce a value into a scalar `f32`.
 ///
@@ -163,13 +173,325 @@ pub fn to_vec3(v: &Value) -> [f32; 3] {
     }
 }
 
+/// Coerce a value into a truth value.
+///
+/// Booleans pass through; text is true when non-empty; everything else is
+/// true when any component of its [`to_vector`] reading is non-zero (so NaN
+/// counts as true and value-less kinds as false).
+pub fn to_bool(v: &Value) -> bool {
+    match kind(v) {
+        VizijKind::Bool => as_bool(v).unwrap_or(false),
+        VizijKind::Text => as_text(v).is_some_and(|s| !s.is_empty()),
+        _ => to_vector(v).iter().any(|x| *x != 0.0),
+    }
+}
+
+/// Coerce a value into display text.
+///
+/// Text passes through; booleans print `true`/`false`; scalars use the
+/// shortest exact `f32` form; fixed-size composites and vectors print as
+/// `[x, y, ...]`; a transform prints its `translation`/`rotation`/`scale`;
+/// records (name order) print as `{name: value, ...}` and sequences as
+/// `[...]`, recursively; enums print their payload; value-less kinds yield
+/// an empty string.
+pub fn to_text(v: &Value) -> String {
+    fn list(items: impl IntoIterator<Item = String>) -> String {
+        format!("[{}]", items.into_iter().collect::<Vec<_>>().join(", "))
+    }
+    fn floats(xs: &[f32]) -> String {
+        list(xs.iter().map(|x| x.to_string()))
+    }
+
+    match kind(v) {
+        VizijKind::Text => as_text(v).unwrap_or_default().to_string(),
+        VizijKind::Bool => as_bool(v).unwrap_or(false).to_string(),
+        VizijKind::Float => to_float(v).to_string(),
+        VizijKind::Vec2 => as_vec2(v).map(|a| floats(&a)).unwrap_or_default(),
+        VizijKind::Vec3 => as_vec3(v).map(|a| floats(&a)).unwrap_or_default(),
+        VizijKind::Vec4 => as_vec4(v).map(|a| floats(&a)).unwrap_or_default(),
+        VizijKind::Quat => as_quat(v).map(|a| floats(&a)).unwrap_or_default(),
+        VizijKind::ColorRgba => as_color_rgba(v).map(|a| floats(&a)).unwrap_or_default(),
+        VizijKind::Vector => as_vector(v).map(floats).unwrap_or_default(),
+        VizijKind::Transform => as_transform(v)
+            .map(|t| {
+                format!(
+                    "{{translation: {}, rotation: {}, scale: {}}}",
+                    floats(&t.translation),
+                    floats(&t.rotation),
+                    floats(&t.scale)
+                )
+            })
+            .unwrap_or_default(),
+        VizijKind::Record => as_record(v)
+            .map(|entries| {
+                let fields: Vec<String> = entries
+                    .iter()
+                    .map(|(name, value)| format!("{name}: {}", to_text(value)))
+                    .collect();
+                format!("{{{}}}", fields.join(", "))
+            })
+            .unwrap_or_default(),
+        VizijKind::Array => as_array(v)
+            .map(|items| list(items.iter().map(to_text)))
+            .unwrap_or_default(),
+        VizijKind::Enum => as_enumeration(v)
+            .map(|(_, payload)| to_text(payload))
+            .unwrap_or_default(),
+        VizijKind::Other => match v {
+            Value::Option(Some(inner)) => to_text(inner),
+            Value::Option(None) | Value::Unit => String::new(),
+            Value::U8(_)
+            | Value::U16(_)
+            | Value::U32(_)
+            | Value::U64(_)
+            | Value::I8(_)
+            | Value::I16(_)
+            | Value::I32(_)
+            | Value::I64(_) => to_float(v).to_string(),
+            _ => floats(&to_vector(v)),
+        },
+    }
+}
+
+/// Align two values for point-wise numeric operations.
+///
+/// These are the graph evaluator's broadcast rules too: it applies them to
+/// flattened operands and defers to this function for records and sequences.
+/// Values with the same numeric layout (booleans and floats both count as
+/// scalars; vectors must agree in length, records in field names, sequences in
+/// length, all recursively) pass through unchanged, and a scalar on either
+/// side is repeated into the other side's layout. Anything else — including
+/// text, enums, and value-less kinds, which have no numeric layout — is an
+/// error.
+pub fn broadcast(a: &Value, b: &Value) -> Result<(Value, Value), String> {
+    let len_a = numeric_len(a).ok_or_else(|| format!("{:?} has no numeric layout", kind(a)))?;
+    let len_b = numeric_len(b).ok_or_else(|| format!("{:?} has no numeric layout", kind(b)))?;
+    if same_layout(a, b) {
+        return Ok((a.clone(), b.clone()));
+    }
+    if is_scalar(a) {
+        return Ok((fill_like(b, to_float(a)), b.clone()));
+    }
+    if is_scalar(b) {
+        return Ok((a.clone(), fill_like(a, to_float(b))));
+    }
+    Err(format!(
+        "cannot broadcast {:?} ({len_a} components) with {:?} ({len_b} components)",
+        kind(a),
+        kind(b)
+    ))
+}
+
+fn is_scalar(v: &Value) -> bool {
+    matches!(kind(v), VizijKind::Float | VizijKind::Bool)
+}
+
+/// Flat component count of a value's numeric layout, or `None` when it has
+/// none.
+fn numeric_len(v: &Value) -> Option<usize> {
+    match kind(v) {
+        VizijKind::Float | VizijKind::Bool => Some(1),
+        VizijKind::Vec2 => Some(2),
+        VizijKind::Vec3 => Some(3),
+        VizijKind::Vec4 | VizijKind::Quat | VizijKind::ColorRgba => Some(4),
+        VizijKind::Transform => Some(10),
+        VizijKind::Vector => as_vector(v).map(<[f32]>::len),
+        VizijKind::Record => as_record(v)?
+            .iter()
+            .map(|(_, value)| numeric_len(value))
+            .sum(),
+        VizijKind::Array => as_array(v)?.iter().map(numeric_len).sum(),
+        _ => None,
+    }
+}
+
+fn same_layout(a: &Value, b: &Value) -> bool {
+    if is_scalar(a) && is_scalar(b) {
+        return true;
+    }
+    if kind(a) != kind(b) {
+        return false;
+    }
+    match kind(a) {
+        VizijKind::Vector => as_vector(a).map(<[f32]>::len) == as_vector(b).map(<[f32]>::len),
+        VizijKind::Record => match (as_record(a), as_record(b)) {
+            (Some(xs), Some(ys)) => {
+                xs.len() == ys.len()
+                    && xs
+                        .iter()
+                        .zip(&ys)
+                        .all(|((kx, vx), (ky, vy))| kx == ky && same_layout(vx, vy))
+            }
+            _ => false,
+        },
+        VizijKind::Array => match (as_array(a), as_array(b)) {
+            (Some(xs), Some(ys)) => {
+                xs.len() == ys.len() && xs.iter().zip(ys).all(|(x, y)| same_layout(x, y))
+            }
+            _ => false,
+        },
+        _ => true,
+    }
+}
+
+/// Rebuild `template`'s numeric layout with every component set to `x`.
+fn fill_like(template: &Value, x: f32) -> Value {
+    match kind(template) {
+        VizijKind::Vec2 => vec2([x; 2]),
+        VizijKind::Vec3 => vec3([x; 3]),
+        VizijKind::Vec4 => vec4([x; 4]),
+        VizijKind::Quat => quat([x; 4]),
+        VizijKind::ColorRgba => color_rgba([x; 4]),
+        VizijKind::Transform => transform(Transform {
+            translation: [x; 3],
+            rotation: [x; 4],
+            scale: [x; 3],
+        }),
+        VizijKind::Vector => vector(vec![x; as_vector(template).map_or(0, <[f32]>::len)]),
+        VizijKind::Record => record(
+            as_record(template)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(key, value)| (key, fill_like(value, x))),
+        ),
+        VizijKind::Array => array(
+            as_array(template)
+                .unwrap_or_default()
+                .iter()
+                .map(|item| fill_like(item, x))
+                .collect(),
+        ),
+        _ => float(x),
+    }
+}
+
+/// Check whether `value` conforms to the declared `shape`.
+///
+/// Kinds must match exactly (no coercion); a `Vector` with a length hint
+/// checks the length; records check every declared field (extra fields are
+/// allowed); `Array`/`List`/`Tuple` all validate against the single wire
+/// sequence kind; enums defer to [`validate_enum`].
+pub fn matches_shape(shape: &ShapeId, value: &Value) -> bool {
+    match shape {
+        ShapeId::Scalar => kind(value) == VizijKind::Float,
+        ShapeId::Bool => kind(value) == VizijKind::Bool,
+        ShapeId::Vec2 => kind(value) == VizijKind::Vec2,
+        ShapeId::Vec3 => kind(value) == VizijKind::Vec3,
+        ShapeId::Vec4 => kind(value) == VizijKind::Vec4,
+        ShapeId::Quat => kind(value) == VizijKind::Quat,
+        ShapeId::ColorRgba => kind(value) == VizijKind::ColorRgba,
+        ShapeId::Transform => kind(value) == VizijKind::Transform,
+        ShapeId::Text => kind(value) == VizijKind::Text,
+        ShapeId::Vector { len } => match as_vector(value) {
+            Some(items) => match len {
+                Some(expected) => items.len() == *expected,
+                None => true,
+            },
+            None => false,
+        },
+        ShapeId::Record(fields) => match as_record(value) {
+            Some(entries) => fields.iter().all(|field| {
+                entries
+                    .iter()
+                    .find(|(name, _)| *name == field.name)
+                    .is_some_and(|(_, v)| matches_shape(&field.shape, v))
+            }),
+            None => false,
+        },
+        ShapeId::Array(inner, len) => match as_array(value) {
+            Some(items) => {
+                items.len() == *len && items.iter().all(|item| matches_shape(inner, item))
+            }
+            None => false,
+        },
+        ShapeId::List(inner) => match as_array(value) {
+            Some(items) => items.iter().all(|item| matches_shape(inner, item)),
+            None => false,
+        },
+        ShapeId::Tuple(entries) => match as_array(value) {
+            Some(items) => {
+                items.len() == entries.len()
+                    && items
+                        .iter()
+                        .zip(entries)
+                        .all(|(item, shape)| matches_shape(shape, item))
+            }
+            None => false,
+        },
+        ShapeId::Enum(_) => validate_enum(value, shape).is_ok(),
+    }
+}
+
+/// Validate an enum value against a declared [`ShapeId::Enum`] variant set.
+///
+/// The value's variant must name one of the declared tags (compared through
+/// [`variant_id`]; a tag equal to the id's string form is also accepted,
+/// covering shapes inferred from values) and its payload must match that
+/// variant's shape. Errors when `shape` is not an enum shape, the value is
+/// not an enum, the variant is unknown, or the payload does not match.
+pub fn validate_enum(value: &Value, shape: &ShapeId) -> Result<(), String> {
+    let ShapeId::Enum(variants) = shape else {
+        return Err(format!("expected an enum shape, got {shape:?}"));
+    };
+    let (variant, payload) = as_enumeration(value)
+        .ok_or_else(|| format!("expected an enum value, got {:?}", kind(value)))?;
+    let (tag, payload_shape) = variants
+        .iter()
+        .find(|(tag, _)| variant_id(tag) == variant || variant.to_string() == *tag)
+        .ok_or_else(|| {
+            let known: Vec<&str> = variants.iter().map(|(tag, _)| tag.as_str()).collect();
+            format!("unknown enum variant {variant}; expected one of {known:?}")
+        })?;
+    if matches_shape(payload_shape, payload) {
+        Ok(())
+    } else {
+        Err(format!(
+            "enum variant '{tag}' payload {:?} does not match declared shape {payload_shape:?}",
+            kind(payload)
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::value::{
-        array, bool_, enumeration, float, quat, record, text, transform, vec2, vec3, vec4, vector,
-        Transform,
-    };
+    use crate::value::{bool_, enumeration, text};
+
+    fn on_off() -> ShapeId {
+        ShapeId::Enum(vec![
+            ("On".to_string(), ShapeId::Scalar),
+            ("Off".to_string(), ShapeId::Bool),
+        ])
+    }
+
+    #[test]
+    fn enum_with_known_tag_and_payload_validates() {
+        assert_eq!(
+            validate_enum(&enumeration("On", float(0.5)), &on_off()),
+            Ok(())
+        );
+        assert_eq!(
+            validate_enum(&enumeration("Off", bool_(false)), &on_off()),
+            Ok(())
+        );
+        assert!(matches_shape(&on_off(), &enumeration("On", float(1.0))));
+    }
+
+    #[test]
+    fn enum_with_unknown_tag_is_rejected() {
+        let err = validate_enum(&enumeration("Dim", float(0.5)), &on_off()).unwrap_err();
+        assert!(err.contains("unknown enum variant"), "{err}");
+        assert!(err.contains("[\"On\", \"Off\"]"), "{err}");
+        assert!(!matches_shape(&on_off(), &enumeration("Dim", float(0.5))));
+    }
+
+    #[test]
+    fn enum_payload_shape_mismatch_is_rejected() {
+        let err = validate_enum(&enumeration("On", text("bright")), &on_off()).unwrap_err();
+        assert!(err.contains("enum variant 'On' payload Text"), "{err}");
+        assert!(validate_enum(&float(1.0), &on_off()).is_err());
+        assert!(validate_enum(&enumeration("On", float(1.0)), &ShapeId::Scalar).is_err());
+    }
 
     #[test]
     fn floats_from_everything() {
@@ -226,6 +548,36 @@ mod tests {
         assert_eq!(to_vector(&t), vec![1.0, 2.0, 3.0]);
     }
 
+    #[test]
+    fn bools_from_floats_vectors_and_text() {
+        assert!(to_bool(&bool_(true)));
+        assert!(!to_bool(&bool_(false)));
+        assert!(to_bool(&float(0.5)));
+        assert!(!to_bool(&float(0.0)));
+        assert!(to_bool(&float(f32::NAN)));
+        assert!(to_bool(&vec3([0.0, 0.0, 2.0])));
+        assert!(!to_bool(&vec3([0.0, 0.0, 0.0])));
+        assert!(!to_bool(&vector(Vec::new())));
+        assert!(to_bool(&text("no")));
+        assert!(!to_bool(&text("")));
+    }
+
+    #[test]
+    fn text_from_scalars_and_composites() {
+        assert_eq!(to_text(&vec3([1.0, 2.5, -3.0])), "[1, 2.5, -3]");
+        assert_eq!(to_text(&float(0.25)), "0.25");
+        assert_eq!(to_text(&bool_(true)), "true");
+        assert_eq!(to_text(&text("label")), "label");
+        assert_eq!(
+            to_text(&record([("b", float(2.0)), ("a", text("x"))])),
+            "{a: x, b: 2}"
+        );
+        assert_eq!(
+            to_text(&array(vec![float(1.0), vec2([0.0, 1.0])])),
+            "[1, [0, 1]]"
+        );
+    }
+
     #[test]
     fn vec3_broadcasts_and_truncates() {
         assert_eq!(to_vec3(&vec3([1.0, 2.0, 3.0])), [1.0, 2.0, 3.0]);
@@ -236,4 +588,26 @@ mod tests {
         assert_eq!(to_vec3(&vector(vec![5.0])), [5.0, 0.0, 0.0]);
         assert_eq!(to_vec3(&text("hi")), [0.0, 0.0, 0.0]);
     }
+
+    #[test]
+    fn scalar_broadcasts_to_vec3() {
+        let (a, b) = broadcast(&float(2.0), &vec3([1.0, 2.0, 3.0])).unwrap();
+        assert_eq!(as_vec3(&a), Some([2.0, 2.0, 2.0]));
+        assert_eq!(as_vec3(&b), Some([1.0, 2.0, 3.0]));
+
+        let (a, b) = broadcast(&vec3([1.0, 2.0, 3.0]), &bool_(true)).unwrap();
+        assert_eq!(as_vec3(&a), Some([1.0, 2.0, 3.0]));
+        assert_eq!(as_vec3(&b), Some([1.0, 1.0, 1.0]));
+    }
+
+    #[test]
+    fn mismatched_lengths_do_not_broadcast() {
+        let err = broadcast(&vector(vec![1.0, 2.0]), &vector(vec![1.0, 2.0, 3.0])).unwrap_err();
+        assert!(
+            err.contains("2 components") && err.contains("3 components"),
+            "{err}"
+        );
+        assert!(broadcast(&vec2([0.0, 1.0]), &vec3([0.0, 1.0, 2.0])).is_err());
+        assert!(broadcast(&text("hi"), &float(1.0)).is_err());
+    }
 }
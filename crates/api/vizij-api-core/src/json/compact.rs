@@ -0,0 +1,392 @@
+//! Compact tagged JSON form for values and write batches.
+//!
+//! The canonical Arora serde form spells out structure and field ids, which
+//! dominates the size of large batches. The compact form is a two-element
+//! `[tag, data]` array per value:
+//!
+//! | tag | kind | data |
+//! |-----|------|------|
+//! | `"f"` / `"d"` | float (`F32` / `F64`) | number |
+//! | `"b"` | bool | `true`/`false` |
+//! | `"s"` | text | string |
+//! | `"v"` | vector | `[x, ...]` |
+//! | `"v2"` / `"v3"` / `"v4"` | vec2/vec3/vec4 | `[x, y, ...]` |
+//! | `"q"` | quat | `[x, y, z, w]` |
+//! | `"c"` | color-rgba | `[r, g, b, a]` |
+//! | `"t"` | transform | `[tx, ty, tz, rx, ry, rz, rw, sx, sy, sz]` |
+//! | `"r"` | record | `{ "key": <compact>, ... }` |
+//! | `"a"` | array | `[<compact>, ...]` |
+//! | `"e"` | enum | `[variant_id, <compact payload>]` |
+//! | `"x"` | anything else | canonical Arora serde |
+//!
+//! A write op is `[path, <compact value>]`, or `[path, <compact value>, shape]`
+//! when it carries a declared shape; a batch is an array of ops. The default
+//! serde form of [`Value`] and [`WriteBatch`] is unchanged.
+
+use arora_types::value::Enumeration;
+use serde::de::Error as _;
+use serde_json::{json, Map, Value as JsonValue};
+use uuid::Uuid;
+
+use crate::value::{
+    array, as_array, as_bool, as_color_rgba, as_enumeration, as_quat, as_record, as_text,
+    as_transform, as_vec2, as_vec3, as_vec4, as_vector, bool_, color_rgba, enumeration, kind, quat,
+    record, text, transform, variant_id, vec2, vec3, vec4, vector, Transform, VizijKind, ENUM_TYPE,
+    RECORD_TYPE,
+};
+use crate::{TypedPath, Value, WriteBatch, WriteOp};
+
+/// Encode `value` in the compact `[tag, data]` form.
+///
+/// A value the compact tags cannot reproduce exactly (integers, unknown
+/// structures, records or enums with non-vizij ids, non-finite floats, ...)
+/// falls back to `["x", <canonical serde>]`.
+pub fn value_to_compact(value: &Value) -> JsonValue {
+    encode(value).unwrap_or_else(|| json!(["x", value]))
+}
+
+/// Decode a value from the compact `[tag, data]` form.
+pub fn value_from_compact(compact: JsonValue) -> Result<Value, serde_json::Error> {
+    let JsonValue::Array(mut parts) = compact else {
+        return Err(serde_json::Error::custom(
+            "compact value must be [tag, data]",
+        ));
+    };
+    if parts.len() != 2 {
+        return Err(serde_json::Error::custom(
+            "compact value must be [tag, data]",
+        ));
+    }
+    let data = parts.pop().unwrap_or(JsonValue::Null);
+    let tag = parts
+        .pop()
+        .and_then(|tag| tag.as_str().map(str::to_string))
+        .ok_or_else(|| serde_json::Error::custom("compact value tag must be a string"))?;
+
+    match tag.as_str() {
+        "f" => Ok(Value::F32(number(&data)? as f32)),
+        "d" => Ok(Value::F64(number(&data)?)),
+        "b" => data
+            .as_bool()
+            .map(bool_)
+            .ok_or_else(|| serde_json::Error::custom("compact bool must be true or false")),
+        "s" => data
+            .as_str()
+            .map(text)
+            .ok_or_else(|| serde_json::Error::custom("compact text must be a string")),
+        "v" => numbers(&data).map(vector),
+        "v2" => components::<2>(&data).map(vec2),
+        "v3" => components::<3>(&data).map(vec3),
+        "v4" => components::<4>(&data).map(vec4),
+        "q" => components::<4>(&data).map(quat),
+        "c" => components::<4>(&data).map(color_rgba),
+        "t" => {
+            let c = components::<10>(&data)?;
+            Ok(transform(Transform {
+                translation: [c[0], c[1], c[2]],
+                rotation: [c[3], c[4], c[5], c[6]],
+                scale: [c[7], c[8], c[9]],
+            }))
+        }
+        "r" => {
+            let JsonValue::Object(entries) = data else {
+                return Err(serde_json::Error::custom(
+                    "compact record must be an object",
+                ));
+            };
+            let mut fields = Vec::with_capacity(entries.len());
+            for (key, item) in &entries {
+                fields.push((key.as_str(), value_from_compact(item.clone())?));
+            }
+            Ok(record(fields))
+        }
+        "a" => {
+            let JsonValue::Array(items) = data else {
+                return Err(serde_json::Error::custom("compact array must be an array"));
+            };
+            items
+                .into_iter()
+                .map(value_from_compact)
+                .collect::<Result<Vec<_>, _>>()
+                .map(array)
+        }
+        "e" => {
+            let JsonValue::Array(mut parts) = data else {
+                return Err(serde_json::Error::custom(
+                    "compact enum must be [variant, payload]",
+                ));
+            };
+            if parts.len() != 2 {
+                return Err(serde_json::Error::custom(
+                    "compact enum must be [variant, payload]",
+                ));
+            }
+            let payload = value_from_compact(parts.pop().unwrap_or(JsonValue::Null))?;
+            let variant = parts.pop().unwrap_or(JsonValue::Null);
+            let variant = variant.as_str().ok_or_else(|| {
+                serde_json::Error::custom("compact enum variant must be a string")
+            })?;
+            // Encoded enums carry the variant id; a variant name is accepted
+            // for hand-written payloads.
+            Ok(match Uuid::parse_str(variant) {
+                Ok(variant_id) => Value::Enumeration(Enumeration {
+                    id: ENUM_TYPE,
+                    variant_id,
+                    value: Box::new(payload),
+                }),
+                Err(_) => enumeration(variant, payload),
+            })
+        }
+        "x" => serde_json::from_value(data),
+        other => Err(serde_json::Error::custom(format!(
+            "unknown compact value tag '{other}'"
+        ))),
+    }
+}
+
+/// Encode a batch as an array of compact `[path, value(, shape)]` ops.
+pub fn writebatch_to_compact(batch: &WriteBatch) -> JsonValue {
+    JsonValue::Array(
+        batch
+            .iter()
+            .map(|op| {
+                let mut entry = vec![json!(op.path.to_string()), value_to_compact(&op.value)];
+                if let Some(shape) = &op.shape {
+                    entry.push(json!(shape));
+                }
+                JsonValue::Array(entry)
+            })
+            .collect(),
+    )
+}
+
+/// Decode a batch from an array of compact `[path, value(, shape)]` ops.
+pub fn writebatch_from_compact(compact: JsonValue) -> Result<WriteBatch, serde_json::Error> {
+    let JsonValue::Array(items) = compact else {
+        return Err(serde_json::Error::custom(
+            "compact write batch must be an array",
+        ));
+    };
+
+    let mut batch = WriteBatch::new();
+    for item in items {
+        let JsonValue::Array(mut parts) = item else {
+            return Err(serde_json::Error::custom(
+                "compact write op must be [path, value] or [path, value, shape]",
+            ));
+        };
+        if !(2..=3).contains(&parts.len()) {
+            return Err(serde_json::Error::custom(
+                "compact write op must be [path, value] or [path, value, shape]",
+            ));
+        }
+        let shape = match parts.len() {
+            3 => Some(serde_json::from_value(
+                parts.pop().unwrap_or(JsonValue::Null),
+            )?),
+            _ => None,
+        };
+        let value = value_from_compact(parts.pop().unwrap_or(JsonValue::Null))?;
+        let path = parts
+            .pop()
+            .and_then(|path| path.as_str().map(TypedPath::parse))
+            .ok_or_else(|| serde_json::Error::custom("compact write op path must be a string"))?
+            .map_err(serde_json::Error::custom)?;
+        batch.push(WriteOp::new_with_shape(path, value, shape));
+    }
+    Ok(batch)
+}
+
+/// Best-effort compact encoding; `None` for values without a compact tag.
+fn encode(value: &Value) -> Option<JsonValue> {
+    let (tag, data) = match kind(value) {
+        VizijKind::Float => match value {
+            Value::F32(x) => ("f", f32_json(*x)?),
+            Value::F64(x) => ("d", serde_json::Number::from_f64(*x)?.into()),
+            _ => return None,
+        },
+        VizijKind::Bool => ("b", json!(as_bool(value)?)),
+        VizijKind::Text => ("s", json!(as_text(value)?)),
+        VizijKind::Vector => ("v", f32s_json(as_vector(value)?)?),
+        VizijKind::Vec2 => ("v2", f32s_json(&exact(value, as_vec2(value)?, vec2)?)?),
+        VizijKind::Vec3 => ("v3", f32s_json(&exact(value, as_vec3(value)?, vec3)?)?),
+        VizijKind::Vec4 => ("v4", f32s_json(&exact(value, as_vec4(value)?, vec4)?)?),
+        VizijKind::Quat => ("q", f32s_json(&exact(value, as_quat(value)?, quat)?)?),
+        VizijKind::ColorRgba => (
+            "c",
+            f32s_json(&exact(value, as_color_rgba(value)?, color_rgba)?)?,
+        ),
+        VizijKind::Transform => {
+            let t = exact(value, as_transform(value)?, transform)?;
+            (
+                "t",
+                f32s_json(&[&t.translation[..], &t.rotation[..], &t.scale[..]].concat())?,
+            )
+        }
+        VizijKind::Record => {
+            let Value::KeyValue(kv) = value else {
+                return None;
+            };
+            let canonical = kv.id == RECORD_TYPE
+                && kv.fields.iter().all(|(key, field)| {
+                    field.value.is_some() && field.name == *key && field.id == variant_id(key)
+                });
+            if !canonical {
+                return None;
+            }
+            let mut entries = Map::new();
+            for (key, item) in as_record(value)? {
+                entries.insert(key.to_string(), encode(item)?);
+            }
+            ("r", JsonValue::Object(entries))
+        }
+        VizijKind::Array => (
+            "a",
+            JsonValue::Array(as_array(value)?.iter().map(encode).collect::<Option<_>>()?),
+        ),
+        VizijKind::Enum => {
+            if !matches!(value, Value::Enumeration(e) if e.id == ENUM_TYPE) {
+                return None;
+            }
+            let (variant, payload) = as_enumeration(value)?;
+            ("e", json!([variant.to_string(), encode(payload)?]))
+        }
+        VizijKind::Other => return None,
+    };
+    Some(json!([tag, data]))
+}
+
+/// `parsed` when rebuilding it with `build` reproduces `value` exactly, i.e. the
+/// structure carries no extra fields or non-`F32` components.
+fn exact<T: Copy>(value: &Value, parsed: T, build: fn(T) -> Value) -> Option<T> {
+    (build(parsed) == *value).then_some(parsed)
+}
+
+/// Shortest decimal form of an `f32` that reads back to the same bits.
+fn f32_json(x: f32) -> Option<JsonValue> {
+    let shortest: f64 = x.to_string().parse().ok()?;
+    serde_json::Number::from_f64(shortest).map(JsonValue::Number)
+}
+
+fn f32s_json(xs: &[f32]) -> Option<JsonValue> {
+    xs.iter()
+        .map(|x| f32_json(*x))
+        .collect::<Option<Vec<_>>>()
+        .map(JsonValue::Array)
+}
+
+fn number(data: &JsonValue) -> Result<f64, serde_json::Error> {
+    data.as_f64()
+        .ok_or_else(|| serde_json::Error::custom("compact float must be a number"))
+}
+
+fn numbers(data: &JsonValue) -> Result<Vec<f32>, serde_json::Error> {
+    data.as_array()
+        .ok_or_else(|| serde_json::Error::custom("compact vector must be an array"))?
+        .iter()
+        .map(|x| number(x).map(|x| x as f32))
+        .collect()
+}
+
+fn components<const N: usize>(data: &JsonValue) -> Result<[f32; N], serde_json::Error> {
+    numbers(data)?.try_into().map_err(|xs: Vec<f32>| {
+        serde_json::Error::custom(format!(
+            "compact value expects {N} components, got {}",
+            xs.len()
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value::float;
+    use crate::{Shape, ShapeId};
+
+    fn samples() -> Vec<Value> {
+        vec![
+            float(1.5),
+            Value::F64(0.1),
+            bool_(true),
+            text("hi"),
+            vector(vec![0.1, 2.0, -3.5]),
+            vec2([1.0, 2.0]),
+            vec3([0.1, 0.2, 0.3]),
+            vec4([1.0, 2.0, 3.0, 4.0]),
+            quat([0.0, 0.0, 0.0, 1.0]),
+            color_rgba([0.1, 0.2, 0.3, 1.0]),
+            transform(Transform {
+                translation: [1.0, 2.0, 3.0],
+                rotation: [0.0, 0.0, 0.0, 1.0],
+                scale: [1.0, 1.0, 1.0],
+            }),
+            record([("pos", vec3([1.0, 2.0, 3.0])), ("name", text("arm"))]),
+            array(vec![float(1.0), bool_(false), array(vec![text("x")])]),
+            enumeration("On", float(0.5)),
+            Value::I64(-42),
+            foreign_enum(),
+        ]
+    }
+
+    /// Enumeration of a non-vizij enum type, which the `"e"` tag cannot carry.
+    fn foreign_enum() -> Value {
+        Value::Enumeration(Enumeration {
+            id: Uuid::nil(),
+            variant_id: variant_id("On"),
+            value: Box::new(float(0.5)),
+        })
+    }
+
+    #[test]
+    fn every_kind_round_trips_through_compact_form() {
+        for value in samples() {
+            let compact = value_to_compact(&value);
+            assert_eq!(
+                value_from_compact(compact.clone()).unwrap(),
+                value,
+                "{compact}"
+            );
+        }
+    }
+
+    #[test]
+    fn compact_tags_are_short() {
+        assert_eq!(value_to_compact(&float(1.5)), json!(["f", 1.5]));
+        assert_eq!(
+            value_to_compact(&vec3([0.1, 0.2, 0.3])),
+            json!(["v3", [0.1, 0.2, 0.3]])
+        );
+        assert_eq!(value_to_compact(&Value::I64(-42))[0], "x");
+        assert_eq!(value_to_compact(&foreign_enum())[0], "x");
+        assert_eq!(
+            value_to_compact(&enumeration("On", float(0.5))),
+            json!(["e", [variant_id("On").to_string(), ["f", 0.5]]])
+        );
+        assert_eq!(
+            value_from_compact(json!(["e", ["On", ["f", 0.5]]])).unwrap(),
+            enumeration("On", float(0.5))
+        );
+        assert!(value_from_compact(json!(["zz", 1])).is_err());
+        assert!(value_from_compact(json!(["v3", [1, 2]])).is_err());
+    }
+
+    #[test]
+    fn writebatch_round_trips_and_is_smaller() {
+        let mut batch = WriteBatch::new();
+        for (i, value) in samples().into_iter().enumerate() {
+            let path = TypedPath::parse(&format!("demo/node{i}.value")).unwrap();
+            batch.push(WriteOp::new(path, value));
+        }
+        batch.push(WriteOp::new_with_shape(
+            TypedPath::parse("demo/shaped.value").unwrap(),
+            vec3([1.0, 2.0, 3.0]),
+            Some(Shape::new(ShapeId::Vec3)),
+        ));
+
+        let compact = writebatch_to_compact(&batch);
+        assert_eq!(writebatch_from_compact(compact.clone()).unwrap(), batch);
+
+        let canonical = serde_json::to_string(&batch).unwrap();
+        assert!(compact.to_string().len() * 2 < canonical.len());
+    }
+}
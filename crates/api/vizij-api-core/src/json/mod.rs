@@ -23,6 +23,10 @@
 //! the canonical form. Values serialize back to JSON with plain `serde_json`;
 //! there is no producer of the legacy forms.
 //!
+//! A compact `[tag, data]` wire form for values and write batches lives in
+//! [`value_to_compact`] / [`writebatch_to_compact`] and their decoders; it is
+//! opt-in and does not change the default serde form.
+//!
 //! Graph-spec normalization (`inputs` -> `edges`, operand aliasing, shape
 //! shorthand) also lives here; value payloads inside specs normalize through
 //! the same rules.
@@ -38,6 +42,12 @@ use crate::value::{
 };
 use crate::{TypedPath, Value, WriteBatch, WriteOp};
 
+pub mod compact;
+
+pub use compact::{
+    value_from_compact, value_to_compact, writebatch_from_compact, writebatch_to_compact,
+};
+
 /// Policy describing how purely numeric JSON arrays are read.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NumericArrayPolicy {
@@ -179,26 +189,37 @@ fn parse_object(
     if let Some(v) = obj.get("color") {
         return parse_components::<4>(v, "color").map(color_rgba);
     }
-    if let Some(items) = obj.get("vector").and_then(|x| x.as_array()) {
-        let xs: Vec<f32> = items
-            .iter()
-            .map(|x| x.as_f64().unwrap_or(0.0) as f32)
-            .collect();
-        return Ok(vector(xs));
+    // The shorthand keys below name their kind explicitly, so their payloads
+    // ignore the numeric-array policy and a malformed payload is an error
+    // rather than a fall-through.
+    if let Some(v) = obj.get("vector") {
+        return parse_numbers(v, "vector").map(vector);
     }
-    if let Some(t) = obj.get("transform").and_then(|x| x.as_object()) {
-        return parse_transform(t);
+    if let Some(t) = obj.get("transform") {
+        return parse_transform(
+            t.as_object()
+                .ok_or_else(|| serde_json::Error::custom("transform must be an object"))?,
+        );
     }
-    if let Some(e) = obj.get("enum").and_then(|x| x.as_object()) {
+    if let Some(e) = obj.get("enum") {
+        let e = e
+            .as_object()
+            .ok_or_else(|| serde_json::Error::custom("enum must be an object"))?;
         let tag = e.get("tag").and_then(|x| x.as_str()).unwrap_or_default();
         let payload = e.get("value").cloned().unwrap_or(JsonValue::Null);
         return Ok(enumeration(tag, parse_value_with_policy(payload, policy)?));
     }
-    if let Some(entries) = obj.get("record").and_then(|x| x.as_object()) {
+    if let Some(entries) = obj.get("record") {
+        let entries = entries
+            .as_object()
+            .ok_or_else(|| serde_json::Error::custom("record must be an object"))?;
         return parse_record(entries, policy);
     }
     for key in ["array", "list", "tuple"] {
-        if let Some(items) = obj.get(key).and_then(|x| x.as_array()) {
+        if let Some(items) = obj.get(key) {
+            let items = items
+                .as_array()
+                .ok_or_else(|| serde_json::Error::custom(format!("{key} must be an array")))?;
             return Ok(array(
                 items
                     .iter()
@@ -286,17 +307,7 @@ fn parse_tagged(
         "vec4" => parse_components::<4>(&data, "vec4").map(vec4),
         "quat" => parse_components::<4>(&data, "quat").map(quat),
         "colorrgba" => parse_components::<4>(&data, "colorrgba").map(color_rgba),
-        "vector" => {
-            let items = data
-                .as_array()
-                .ok_or_else(|| serde_json::Error::custom("vector data must be an array"))?;
-            Ok(vector(
-                items
-                    .iter()
-                    .map(|x| x.as_f64().unwrap_or(0.0) as f32)
-                    .collect(),
-            ))
-        }
+        "vector" => parse_numbers(&data, "vector").map(vector),
         "transform" => {
             let t = data
                 .as_object()
@@ -399,6 +410,20 @@ fn parse_components<const N: usize>(
     )))
 }
 
+/// Read a variable-length list of float components from a JSON array of
+/// numbers.
+fn parse_numbers(v: &JsonValue, what: &str) -> Result<Vec<f32>, serde_json::Error> {
+    v.as_array()
+        .ok_or_else(|| serde_json::Error::custom(format!("{what} must be an array")))?
+        .iter()
+        .map(|item| {
+            item.as_f64()
+                .map(|x| x as f32)
+                .ok_or_else(|| serde_json::Error::custom(format!("{what} component not a number")))
+        })
+        .collect()
+}
+
 // ---- write batches ---------------------------------------------------------------
 
 /// Deserialize a JSON write-batch array (`[{ "path": ..., "value": ...,
@@ -445,6 +470,32 @@ pub fn writebatch_from_pairs(pairs: impl IntoIterator<Item = (TypedPath, Value)>
     batch
 }
 
+/// Serialize `value` to its canonical JSON form with every float rounded to
+/// `decimals` places, for snapshot comparisons that must not depend on
+/// platform float formatting. Integers, text, and ids are left untouched;
+/// [`serde_json::to_value`] remains the exact form.
+pub fn value_to_json_rounded(value: &Value, decimals: u32) -> Result<JsonValue, serde_json::Error> {
+    let mut out = serde_json::to_value(value)?;
+    round_floats(&mut out, 10f64.powi(decimals as i32));
+    Ok(out)
+}
+
+fn round_floats(value: &mut JsonValue, scale: f64) {
+    match value {
+        JsonValue::Number(n) if n.is_f64() => {
+            if let Some(rounded) = n
+                .as_f64()
+                .and_then(|x| serde_json::Number::from_f64((x * scale).round() / scale))
+            {
+                *n = rounded;
+            }
+        }
+        JsonValue::Array(items) => items.iter_mut().for_each(|item| round_floats(item, scale)),
+        JsonValue::Object(map) => map.values_mut().for_each(|item| round_floats(item, scale)),
+        _ => {}
+    }
+}
+
 // ---- graph specs ----------------------------------------------------------------
 
 fn normalize_shape_json(shape: JsonValue) -> JsonValue {
@@ -506,6 +557,8 @@ fn normalize_operand_key(
 
 /// Normalize a graph specification JSON value in-place. This mirrors the wasm
 /// helpers previously implemented in individual crates.
+///
+/// Child graphs under `params.subgraph.graph` are normalized recursively.
 pub fn normalize_graph_spec_value(root: &mut JsonValue) -> Result<(), JsonError> {
     if root.get("links").is_some() {
         return Err(JsonError::LegacyLinksField);
@@ -532,9 +585,11 @@ pub fn normalize_graph_spec_value(root: &mut JsonValue) -> Result<(), JsonError>
             }
 
             if let Some(params) = node.get_mut("params").and_then(|p| p.as_object_mut()) {
-                if let Some(value) = params.get_mut("value") {
-                    let taken = std::mem::take(value);
-                    *value = normalize_value_json(taken);
+                for key in ["value", "default"] {
+                    if let Some(value) = params.get_mut(key) {
+                        let taken = std::mem::take(value);
+                        *value = normalize_value_json(taken);
+                    }
                 }
 
                 if let Some(path_val) = params.get_mut("path") {
@@ -567,6 +622,14 @@ pub fn normalize_graph_spec_value(root: &mut JsonValue) -> Result<(), JsonError>
                         *sizes_val = JsonValue::Array(normalized);
                     }
                 }
+
+                // Subgraph children accept the same legacy forms as the top-level graph.
+                if let Some(child) = params
+                    .get_mut("subgraph")
+                    .and_then(|sub| sub.get_mut("graph"))
+                {
+                    normalize_graph_spec_value(child)?;
+                }
             }
 
             if let Some(outputs) = node
@@ -861,6 +924,27 @@ mod tests {
         assert_eq!(as_vec3(&parsed), Some([1.0, 2.0, 3.0]));
     }
 
+    #[test]
+    fn rounded_json_is_stable_for_long_fractions() {
+        let a = vec3([1.2346, 2.000_4, -1.0 / 3.0]);
+        let b = vec3([1.2349, 1.999_6, -0.3334]);
+        let ra = value_to_json_rounded(&a, 3).unwrap();
+        let rb = value_to_json_rounded(&b, 3).unwrap();
+        assert_eq!(ra, rb);
+
+        let components: Vec<JsonValue> = ra["struct"]["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|field| field["value"]["f32"].clone())
+            .collect();
+        assert_eq!(components, vec![json!(1.235), json!(2.0), json!(-0.333)]);
+        assert_eq!(
+            ra["struct"]["id"],
+            serde_json::to_value(&a).unwrap()["struct"]["id"]
+        );
+    }
+
     #[test]
     fn legacy_shorthand_objects_parse() {
         assert_eq!(
@@ -956,6 +1040,66 @@ mod tests {
         }
     }
 
+    #[test]
+    fn legacy_aliases_ignore_numeric_array_policy() {
+        use crate::VizijKind as K;
+        let cases = [
+            (json!({ "vec2": [1, 2] }), K::Vec2),
+            (json!({ "vec3": [1, 2, 3] }), K::Vec3),
+            (json!({ "vec4": [1, 2, 3, 4] }), K::Vec4),
+            (json!({ "quat": [0, 0, 0, 1] }), K::Quat),
+            (json!({ "color": [0.1, 0.2, 0.3, 1.0] }), K::ColorRgba),
+            (json!({ "vector": [1, 2, 3] }), K::Vector),
+            (
+                json!({ "transform": {
+                    "translation": [1, 2, 3],
+                    "rotation": [0, 0, 0, 1],
+                    "scale": [1, 1, 1]
+                } }),
+                K::Transform,
+            ),
+            (json!({ "enum": { "tag": "On", "value": 1.0 } }), K::Enum),
+            (json!({ "record": { "x": 1.0 } }), K::Record),
+            (json!({ "array": [1.0, true] }), K::Array),
+            (json!({ "list": [1.0, true] }), K::Array),
+            (json!({ "tuple": [1.0, true] }), K::Array),
+        ];
+        for (payload, expected) in cases {
+            let auto = parse_value(payload.clone()).unwrap();
+            let staging = parse_value_staging(payload.clone()).unwrap();
+            assert_eq!(crate::kind(&auto), expected, "{payload}");
+            assert_eq!(auto, staging, "{payload} differs across policies");
+            assert_eq!(
+                normalize_value_json(payload.clone()),
+                normalize_value_json_staging(payload.clone()),
+                "{payload} normalizes differently across policies"
+            );
+        }
+
+        // Bare numeric arrays nested inside a composite still follow the policy.
+        let nested = json!({ "record": { "pos": [1, 2, 3] } });
+        let auto = parse_value(nested.clone()).unwrap();
+        let staging = parse_value_staging(nested).unwrap();
+        assert_eq!(crate::kind(as_record(&auto).unwrap()[0].1), K::Vec3);
+        assert_eq!(crate::kind(as_record(&staging).unwrap()[0].1), K::Vector);
+    }
+
+    #[test]
+    fn malformed_legacy_payloads_error() {
+        for payload in [
+            json!({ "vector": [1, "two"] }),
+            json!({ "vector": 3 }),
+            json!({ "transform": [1, 2, 3] }),
+            json!({ "record": [1, 2] }),
+            json!({ "list": { "x": 1 } }),
+            json!({ "enum": ["On", 1.0] }),
+            json!({ "type": "vector", "data": [1, null] }),
+        ] {
+            assert!(parse_value(payload.clone()).is_err(), "{payload}");
+            assert!(parse_value_staging(payload.clone()).is_err(), "{payload}");
+        }
+    }
+
     #[test]
     fn tagged_forms_parse() {
         assert_eq!(
@@ -1100,6 +1244,42 @@ mod tests {
         assert_eq!(root["nodes"][0]["output_shapes"]["value"]["id"], "vec3");
     }
 
+    #[test]
+    fn graph_spec_normalization_recurses_into_subgraphs() {
+        let shorthand_child = json!({
+            "nodes": [
+                { "id": "k", "kind": "Constant", "params": { "value": 2 } },
+                { "id": "out", "type": "Output", "inputs": { "in": "k" } }
+            ]
+        });
+        let mut root = json!({
+            "nodes": [{
+                "id": "outer",
+                "type": "subgraph",
+                "params": { "subgraph": { "graph": {
+                    "nodes": [{
+                        "id": "inner",
+                        "type": "subgraph",
+                        "params": { "subgraph": { "graph": shorthand_child } }
+                    }]
+                } } }
+            }]
+        });
+        normalize_graph_spec_value(&mut root).expect("normalize graph spec");
+
+        let child = &root["nodes"][0]["params"]["subgraph"]["graph"];
+        assert_eq!(child["edges"], json!([]));
+        let grandchild = &child["nodes"][0]["params"]["subgraph"]["graph"];
+        assert_eq!(grandchild["nodes"][0]["type"], "constant");
+        assert_eq!(
+            grandchild["nodes"][0]["params"]["value"],
+            json!({ "f32": 2.0 })
+        );
+        assert!(grandchild["nodes"][1].get("inputs").is_none());
+        assert_eq!(grandchild["edges"][0]["from"]["node_id"], "k");
+        assert_eq!(grandchild["edges"][0]["to"]["input"], "in");
+    }
+
     #[test]
     fn graph_spec_normalization_converts_inputs_to_edges() {
         let mut root = json!({
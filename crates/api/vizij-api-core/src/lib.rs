@@ -2,6 +2,8 @@
 //! plus the Shape, typed-path, write-batch, blend, coercion, and JSON helpers
 //! shared across Vizij runtimes.
 
+/// ABI versions of the wasm bindings, reported together for loader checks.
+pub mod abi;
 /// Blend helpers shared by animation and graph runtimes.
 pub mod blackboard;
 pub mod blend;
@@ -9,6 +11,8 @@ pub mod blend;
 pub mod coercion;
 /// JSON normalization: legacy payload forms in, Arora `Value` serde out.
 pub mod json;
+/// Panic containment for the wasm bindings.
+pub mod panic;
 /// Canonical shape descriptors used across Vizij crates and wasm bridges.
 pub mod shape;
 /// Typed path parsing and formatting for blackboard/write targets.
@@ -0,0 +1,35 @@
+//! Panic containment shared by the wasm bindings.
+
+/// Run `f`, converting a panic into an error string instead of unwinding into JS.
+///
+/// The error reads `"{ctx} panicked: {message}"`.
+///
+/// This only catches anything in builds that unwind: native builds (tests, Rust hosts) and
+/// wasm builds made with `panic=unwind`. The default wasm32 target uses `panic=abort`, so there
+/// a panic still traps the module (`RuntimeError: unreachable`) after `console_error_panic_hook`
+/// logs it, and the instance must be recreated.
+pub fn catch_panic<T>(ctx: &str, f: impl FnOnce() -> T) -> Result<T, String> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).map_err(|payload| {
+        let msg = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        format!("{ctx} panicked: {msg}")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catch_panic_reports_the_payload() {
+        assert_eq!(catch_panic("step", || 7), Ok(7));
+        let err = catch_panic("step", || -> () { panic!("static message") }).unwrap_err();
+        assert_eq!(err, "step panicked: static message");
+        let n = 3;
+        let err = catch_panic("eval", || -> () { panic!("node {n} failed") }).unwrap_err();
+        assert_eq!(err, "eval panicked: node 3 failed");
+    }
+}
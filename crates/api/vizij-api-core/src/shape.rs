@@ -110,4 +110,151 @@ impl Shape {
         self.meta.insert(key.into(), value.into());
         self
     }
+
+    /// Result shape for blending or merging values shaped `a` and `b`, or `None` if they cannot
+    /// combine.
+    ///
+    /// Promotion rules, applied symmetrically:
+    /// - identical shapes unify to themselves;
+    /// - `Scalar` (or `Bool`) promotes to any numeric shape (`VecN`, `Quat`, `ColorRgba`,
+    ///   `Vector`), and `Bool` with `Scalar` gives `Scalar`;
+    /// - a fixed-size shape absorbs a `Vector` of matching (or unknown) length
+    ///   (`Vec3` + `Vector(3)` -> `Vec3`); two vectors keep whichever length is known;
+    /// - records with the same field names, tuples of the same length, and arrays/lists unify
+    ///   elementwise (array + list -> list, arrays must agree on length);
+    /// - everything else — different fixed sizes, `Vec4` vs `Quat`, text, transforms or enums
+    ///   against anything but themselves — is incompatible.
+    pub fn unify(a: &ShapeId, b: &ShapeId) -> Option<ShapeId> {
+        use ShapeId::*;
+        if a == b {
+            return Some(a.clone());
+        }
+        match (a, b) {
+            (Scalar, Bool) | (Bool, Scalar) => Some(Scalar),
+            (Scalar | Bool, other) | (other, Scalar | Bool) if numeric_len(other).is_some() => {
+                Some(other.clone())
+            }
+            (Vector { len: la }, Vector { len: lb }) => match (la, lb) {
+                (Some(x), Some(y)) if x != y => None,
+                _ => Some(Vector { len: la.or(*lb) }),
+            },
+            (Vector { len }, fixed) | (fixed, Vector { len }) => {
+                let n = numeric_len(fixed)?;
+                (len.is_none() || *len == Some(n)).then(|| fixed.clone())
+            }
+            (Record(fa), Record(fb)) => {
+                if fa.len() != fb.len() {
+                    return None;
+                }
+                let fields = fa
+                    .iter()
+                    .map(|field| {
+                        let other = fb.iter().find(|f| f.name == field.name)?;
+                        Some(Field {
+                            name: field.name.clone(),
+                            shape: Shape::unify(&field.shape, &other.shape)?,
+                        })
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                Some(Record(fields))
+            }
+            (Array(ea, na), Array(eb, nb)) if na == nb => {
+                Some(Array(Box::new(Shape::unify(ea, eb)?), *na))
+            }
+            (Array(ea, _) | List(ea), Array(eb, _) | List(eb)) => match (a, b) {
+                (Array(_, _), Array(_, _)) => None,
+                _ => Some(List(Box::new(Shape::unify(ea, eb)?))),
+            },
+            (Tuple(ta), Tuple(tb)) if ta.len() == tb.len() => ta
+                .iter()
+                .zip(tb)
+                .map(|(x, y)| Shape::unify(x, y))
+                .collect::<Option<Vec<_>>>()
+                .map(Tuple),
+            _ => None,
+        }
+    }
+}
+
+/// Component count of a numeric shape (`0` for a `Vector` without a length hint), or `None` for
+/// shapes without a numeric layout.
+fn numeric_len(shape: &ShapeId) -> Option<usize> {
+    match shape {
+        ShapeId::Vec2 => Some(2),
+        ShapeId::Vec3 => Some(3),
+        ShapeId::Vec4 | ShapeId::Quat | ShapeId::ColorRgba => Some(4),
+        ShapeId::Vector { len } => Some(len.unwrap_or(0)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unify_promotes_compatible_shapes() {
+        let vec3 = ShapeId::Vec3;
+        let vector = |len| ShapeId::Vector { len };
+        assert_eq!(Shape::unify(&vec3, &vector(Some(3))), Some(ShapeId::Vec3));
+        assert_eq!(Shape::unify(&vector(None), &vec3), Some(ShapeId::Vec3));
+        assert_eq!(
+            Shape::unify(&ShapeId::Scalar, &ShapeId::Vec4),
+            Some(ShapeId::Vec4)
+        );
+        assert_eq!(
+            Shape::unify(&vector(Some(5)), &ShapeId::Scalar),
+            Some(vector(Some(5)))
+        );
+        assert_eq!(
+            Shape::unify(&ShapeId::Bool, &ShapeId::Scalar),
+            Some(ShapeId::Scalar)
+        );
+        assert_eq!(
+            Shape::unify(&vector(None), &vector(Some(2))),
+            Some(vector(Some(2)))
+        );
+        assert_eq!(
+            Shape::unify(
+                &ShapeId::record_from_pairs([("pos", ShapeId::Vec3), ("w", ShapeId::Scalar)]),
+                &ShapeId::record_from_pairs([("w", ShapeId::Bool), ("pos", vector(Some(3)))]),
+            ),
+            Some(ShapeId::record_from_pairs([
+                ("pos", ShapeId::Vec3),
+                ("w", ShapeId::Scalar)
+            ]))
+        );
+        assert_eq!(
+            Shape::unify(
+                &ShapeId::Array(Box::new(ShapeId::Scalar), 2),
+                &ShapeId::List(Box::new(ShapeId::Vec2)),
+            ),
+            Some(ShapeId::List(Box::new(ShapeId::Vec2)))
+        );
+    }
+
+    #[test]
+    fn unify_rejects_incompatible_shapes() {
+        let vector = |len| ShapeId::Vector { len };
+        assert_eq!(Shape::unify(&ShapeId::Vec3, &vector(Some(4))), None);
+        assert_eq!(Shape::unify(&ShapeId::Vec3, &ShapeId::Vec4), None);
+        assert_eq!(Shape::unify(&ShapeId::Vec4, &ShapeId::Quat), None);
+        assert_eq!(Shape::unify(&vector(Some(2)), &vector(Some(3))), None);
+        assert_eq!(Shape::unify(&ShapeId::Text, &ShapeId::Scalar), None);
+        assert_eq!(Shape::unify(&ShapeId::Transform, &ShapeId::Vec3), None);
+        assert_eq!(
+            Shape::unify(
+                &ShapeId::record_from_pairs([("x", ShapeId::Scalar)]),
+                &ShapeId::record_from_pairs([("y", ShapeId::Scalar)]),
+            ),
+            None
+        );
+        assert_eq!(
+            Shape::unify(
+                &ShapeId::Array(Box::new(ShapeId::Scalar), 2),
+                &ShapeId::Array(Box::new(ShapeId::Scalar), 3),
+            ),
+            None
+        );
+    }
 }
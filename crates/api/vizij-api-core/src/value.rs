@@ -18,6 +18,7 @@
 //!   a `Value` once into PODs, does its math on those, and re-encodes at the
 //!   store boundary.
 //! - A coarse [`VizijKind`] classifier for dispatch.
+//! - [`estimated_bytes`], an approximate memory footprint for sizing.
 //!
 //! Primitive mapping: `f32` -> `Value::F32`, `bool` -> `Value::Boolean`,
 //! text -> `Value::String`, numeric vector -> `Value::ArrayF32`. Records map
@@ -27,6 +28,8 @@
 //! Arora's native `Value::Enumeration` with variant ids derived from the
 //! variant names via [`variant_id`].
 
+use std::mem;
+
 use arora_types::gen_uuid_from_str;
 use arora_types::keyvalue::{KeyValue, KeyValueField};
 use arora_types::value::{Enumeration, Structure};
@@ -426,10 +429,70 @@ fn read_float_structure<const N: usize>(
     Some(out)
 }
 
+// ---- sizing -----------------------------------------------------------------------
+
+/// Approximate memory footprint of `value` in bytes: the inline size of the
+/// enum plus the heap buffers it owns (text, numeric arrays, sequences,
+/// record and structure fields, boxed payloads), counted recursively.
+///
+/// Buffers are counted by length rather than capacity and map overhead is
+/// ignored, so this is a planning estimate for scratch/bake sizing, not an
+/// exact allocator figure.
+pub fn estimated_bytes(value: &Value) -> usize {
+    mem::size_of::<Value>() + heap_bytes(value)
+}
+
+fn heap_bytes(value: &Value) -> usize {
+    match value {
+        Value::String(s) => s.len(),
+        Value::ArrayF32(xs) => mem::size_of_val(xs.as_slice()),
+        Value::ArrayF64(xs) => mem::size_of_val(xs.as_slice()),
+        Value::ArrayValue(items) => items.iter().map(estimated_bytes).sum(),
+        Value::KeyValue(kv) => kv
+            .fields
+            .iter()
+            .map(|(key, field)| {
+                mem::size_of::<String>()
+                    + key.len()
+                    + mem::size_of_val(field)
+                    + field.value.as_deref().map_or(0, estimated_bytes)
+            })
+            .sum(),
+        Value::Structure(s) => s
+            .fields
+            .iter()
+            .map(|f| mem::size_of_val(f) + estimated_bytes(&f.value))
+            .sum(),
+        Value::Enumeration(e) => estimated_bytes(&e.value),
+        Value::Option(Some(inner)) => estimated_bytes(inner),
+        _ => 0,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn estimated_bytes_tracks_heap_payloads() {
+        let inline = mem::size_of::<Value>();
+        assert_eq!(estimated_bytes(&float(1.0)), inline);
+
+        let big = vector(vec![0.0; 10_000]);
+        let bytes = estimated_bytes(&big);
+        assert!((40_000..40_000 + 2 * inline).contains(&bytes), "{bytes}");
+
+        assert_eq!(estimated_bytes(&text("hello")), inline + 5);
+
+        let leaf = record([("pos", vec3([1.0, 2.0, 3.0])), ("name", text("arm"))]);
+        let nested = record([("leaf", leaf.clone()), ("gain", float(0.5))]);
+        let leaf_bytes = estimated_bytes(&leaf);
+        let nested_bytes = estimated_bytes(&nested);
+        assert!(leaf_bytes > inline * 3, "{leaf_bytes}");
+        assert!(nested_bytes > leaf_bytes + inline, "{nested_bytes}");
+        assert!(nested_bytes < 4096, "{nested_bytes}");
+    }
+
     #[test]
     fn primitives_round_trip() {
         assert_eq!(as_float(&float(1.5)), Some(1.5));
@@ -7,10 +7,18 @@
 //! (a scalar value would be `{ "f32": 1.0 }`). The optional `shape` field
 //! carries declared [`Shape`] metadata and is omitted when absent.
 //!
-//! `WriteBatch` is a simple `Vec<WriteOp>` with helpers.
+//! `WriteBatch` is an ordered list of `WriteOp`s with helpers; pushing to a
+//! path that is already queued overwrites that write in place. [`apply_partial`]
+//! applies component-addressed writes (`node.translation.y`) against a map of
+//! existing values.
 
+use crate::value::{
+    array, as_array, as_float, as_quat, as_record, as_transform, as_vec3, color_rgba, kind, quat,
+    record, transform, vec2, vec3, vec4, vector, VizijKind,
+};
 use crate::{typed_path::TypedPath, Shape, Value};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
 use std::fmt;
 
 /// One write of a value (with optional declared shape) to a typed path.
@@ -39,25 +47,127 @@ impl WriteOp {
 
 /// A batch of write operations. Engines can emit a WriteBatch each tick.
 ///
-/// Batch order is preserved. Duplicate paths are allowed and are resolved later by the consumer
-/// that applies the batch.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
-pub struct WriteBatch(pub Vec<WriteOp>);
+/// A batch holds at most one write per path: [`Self::push`] to a path that is already queued
+/// replaces that write in place (last value wins, first position kept), so iterating a batch
+/// yields distinct paths in first-write order. The path index behind this is not serialized;
+/// the wire form is a plain array of ops.
+pub struct WriteBatch(
+    #[deprecated(
+        note = "use `WriteBatch::from_vec`, `as_slice`, or `into_vec`; direct edits bypass the path index"
+    )]
+    pub Vec<WriteOp>,
+    HashMap<TypedPath, usize>,
+);
 
+#[allow(deprecated)]
+impl Clone for WriteBatch {
+    fn clone(&self) -> Self {
+        WriteBatch(self.0.clone(), self.1.clone())
+    }
+}
+
+impl Default for WriteBatch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[allow(deprecated)]
+impl PartialEq for WriteBatch {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+#[allow(deprecated)]
+impl fmt::Debug for WriteBatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("WriteBatch").field(&self.0).finish()
+    }
+}
+
+impl Serialize for WriteBatch {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for WriteBatch {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<WriteOp>::deserialize(deserializer).map(Self::from_vec)
+    }
+}
+
+#[allow(deprecated)]
 impl WriteBatch {
     /// Construct an empty batch.
     pub fn new() -> Self {
-        WriteBatch(Vec::new())
+        WriteBatch(Vec::new(), HashMap::new())
+    }
+
+    /// Construct an empty batch with room for `n` writes.
+    pub fn with_capacity(n: usize) -> Self {
+        WriteBatch(Vec::with_capacity(n), HashMap::with_capacity(n))
+    }
+
+    /// Build a batch from existing write ops, pushing them in order (a repeated path keeps
+    /// its last value).
+    pub fn from_vec(ops: Vec<WriteOp>) -> Self {
+        let mut batch = Self::with_capacity(ops.len());
+        batch.extend(ops);
+        batch
     }
 
-    /// Append one write op to the batch.
+    /// Queue one write op, replacing the write already queued for its path in place or
+    /// appending it when the path is new.
     pub fn push(&mut self, op: WriteOp) {
-        self.0.push(op);
+        if self.1.len() != self.0.len() {
+            self.reindex();
+        }
+        match self.1.get(&op.path) {
+            Some(&i) if self.0[i].path == op.path => self.0[i] = op,
+            Some(_) => {
+                self.reindex();
+                self.push(op);
+            }
+            None => {
+                self.1.insert(op.path.clone(), self.0.len());
+                self.0.push(op);
+            }
+        }
     }
 
-    /// Extend the batch with multiple write ops in iteration order.
+    /// Rebuild the path index after the deprecated public vector was edited directly,
+    /// collapsing any repeated paths it now holds.
+    fn reindex(&mut self) {
+        let ops = std::mem::take(&mut self.0);
+        self.1.clear();
+        self.extend(ops);
+    }
+
+    /// Mutable access to the write queued for `path`, if any. The op's path must not be
+    /// changed through the returned reference.
+    pub fn get_mut(&mut self, path: &TypedPath) -> Option<&mut WriteOp> {
+        let i = *self.1.get(path)?;
+        self.0.get_mut(i).filter(|op| op.path == *path)
+    }
+
+    /// Push multiple write ops in iteration order.
     pub fn extend(&mut self, other: impl IntoIterator<Item = WriteOp>) {
-        self.0.extend(other);
+        for op in other {
+            self.push(op);
+        }
+    }
+
+    /// Number of writes in the batch.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Remove every write, keeping the allocated capacity.
+    pub fn clear(&mut self) {
+        self.0.clear();
+        self.1.clear();
     }
 
     /// Consume the batch and return the underlying vector.
@@ -65,7 +175,12 @@ impl WriteBatch {
         self.0
     }
 
-    /// Iterate over the batch in append order.
+    /// The queued writes in first-write order.
+    pub fn as_slice(&self) -> &[WriteOp] {
+        &self.0
+    }
+
+    /// Iterate over the batch in first-write order.
     pub fn iter(&self) -> impl Iterator<Item = &WriteOp> {
         self.0.iter()
     }
@@ -75,14 +190,136 @@ impl WriteBatch {
         self.0.is_empty()
     }
 
-    /// Merge another batch in-place by appending it after the existing writes.
-    ///
-    /// This does not deduplicate or reconcile duplicate paths.
-    pub fn append(&mut self, mut other: WriteBatch) {
-        self.0.append(&mut other.0)
+    /// Merge another batch in-place by pushing its writes after the existing ones; a path
+    /// queued in both takes `other`'s value at its existing position.
+    pub fn append(&mut self, other: WriteBatch) {
+        self.extend(other.into_vec())
+    }
+}
+
+/// Apply `op` to `base`, updating a single component when the path addresses
+/// one inside an existing value.
+///
+/// If `op.path` is not stored but a prefix of it is (dropping trailing field
+/// selectors), the remaining selectors walk into the stored value and only
+/// that part is replaced: `x`/`y`/`z`/`w` (or `r`/`g`/`b`/`a`, or a numeric
+/// index) pick a vector component and take a scalar; `translation`,
+/// `rotation`, and `scale` pick a transform part; record keys and sequence
+/// indices descend into composites. Otherwise the value is stored whole at
+/// `op.path`. Errors when a selector does not fit the stored value; `base` is
+/// left unchanged in that case.
+pub fn apply_partial(base: &mut HashMap<TypedPath, Value>, op: &WriteOp) -> Result<(), String> {
+    if !base.contains_key(&op.path) {
+        for keep in (0..op.path.fields.len()).rev() {
+            let prefix = TypedPath::new(
+                op.path.namespaces.clone(),
+                op.path.target.clone(),
+                op.path.fields[..keep].to_vec(),
+            );
+            if let Some(existing) = base.get(&prefix) {
+                let updated = set_component(existing, &op.path.fields[keep..], &op.value)
+                    .map_err(|err| format!("partial write to '{}': {err}", op.path))?;
+                base.insert(prefix, updated);
+                return Ok(());
+            }
+        }
+    }
+    base.insert(op.path.clone(), op.value.clone());
+    Ok(())
+}
+
+/// Rebuild `value` with the part addressed by `selectors` replaced by `new`.
+fn set_component(value: &Value, selectors: &[String], new: &Value) -> Result<Value, String> {
+    let Some((selector, rest)) = selectors.split_first() else {
+        return Ok(new.clone());
+    };
+    match kind(value) {
+        VizijKind::Vec2
+        | VizijKind::Vec3
+        | VizijKind::Vec4
+        | VizijKind::Quat
+        | VizijKind::ColorRgba
+        | VizijKind::Vector => {
+            if !rest.is_empty() {
+                return Err(format!("'{selector}' is a scalar component"));
+            }
+            let mut components = crate::coercion::to_vector(value);
+            let index = component_index(selector, kind(value))
+                .filter(|i| *i < components.len())
+                .ok_or_else(|| format!("no component '{selector}' in {:?}", kind(value)))?;
+            components[index] =
+                as_float(new).ok_or_else(|| format!("component '{selector}' expects a float"))?;
+            let c = &components;
+            Ok(match kind(value) {
+                VizijKind::Vec2 => vec2([c[0], c[1]]),
+                VizijKind::Vec3 => vec3([c[0], c[1], c[2]]),
+                VizijKind::Vec4 => vec4([c[0], c[1], c[2], c[3]]),
+                VizijKind::Quat => quat([c[0], c[1], c[2], c[3]]),
+                VizijKind::ColorRgba => color_rgba([c[0], c[1], c[2], c[3]]),
+                _ => vector(components),
+            })
+        }
+        VizijKind::Transform => {
+            let mut t = as_transform(value).ok_or("malformed transform")?;
+            match selector.as_str() {
+                "translation" => {
+                    t.translation = as_vec3(&set_component(&vec3(t.translation), rest, new)?)
+                        .ok_or("translation expects a vec3")?;
+                }
+                "rotation" => {
+                    t.rotation = as_quat(&set_component(&quat(t.rotation), rest, new)?)
+                        .ok_or("rotation expects a quat")?;
+                }
+                "scale" => {
+                    t.scale = as_vec3(&set_component(&vec3(t.scale), rest, new)?)
+                        .ok_or("scale expects a vec3")?;
+                }
+                other => return Err(format!("no transform part '{other}'")),
+            }
+            Ok(transform(t))
+        }
+        VizijKind::Record => {
+            let mut entries: Vec<(&str, Value)> = as_record(value)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(key, field)| (key, field.clone()))
+                .collect();
+            match entries
+                .iter_mut()
+                .find(|(key, _)| *key == selector.as_str())
+            {
+                Some((_, field)) => *field = set_component(field, rest, new)?,
+                None if rest.is_empty() => entries.push((selector.as_str(), new.clone())),
+                None => return Err(format!("no record field '{selector}'")),
+            }
+            Ok(record(entries))
+        }
+        VizijKind::Array => {
+            let mut items = as_array(value).unwrap_or_default().to_vec();
+            let item = selector
+                .parse::<usize>()
+                .ok()
+                .and_then(|i| items.get_mut(i))
+                .ok_or_else(|| format!("no sequence index '{selector}'"))?;
+            *item = set_component(item, rest, new)?;
+            Ok(array(items))
+        }
+        other => Err(format!("cannot address '{selector}' inside {other:?}")),
     }
 }
 
+/// Index of a named (`x`/`y`/`z`/`w`, `r`/`g`/`b`/`a`) or numeric component.
+fn component_index(selector: &str, kind: VizijKind) -> Option<usize> {
+    let named = match kind {
+        VizijKind::ColorRgba => ["r", "g", "b", "a"],
+        _ => ["x", "y", "z", "w"],
+    };
+    named
+        .iter()
+        .position(|name| *name == selector)
+        .or_else(|| selector.parse().ok())
+}
+
 impl fmt::Display for WriteOp {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let val = serde_json::to_string(&self.value).map_err(|_| fmt::Error)?;
@@ -102,7 +339,7 @@ impl fmt::Display for WriteOp {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::value::{float, text, vec3};
+    use crate::value::{as_transform, as_vec3, float, text, transform, vec3, Transform};
     use crate::{Shape, ShapeId};
 
     #[test]
@@ -134,6 +371,67 @@ mod tests {
         assert_eq!(b, parsed);
     }
 
+    #[test]
+    fn writebatch_push_overwrites_same_path_in_place() {
+        let tp = TypedPath::parse("r/t.a").unwrap();
+        let mut b = WriteBatch::with_capacity(4);
+        b.push(WriteOp::new(tp.clone(), float(0.5)));
+        b.push(WriteOp::new(tp.clone(), float(0.75)));
+        assert_eq!(b.len(), 1);
+        assert_eq!(b.as_slice()[0].value, float(0.75));
+
+        b.get_mut(&tp).unwrap().value = float(1.0);
+        b.push(WriteOp::new(TypedPath::parse("r/t.b").unwrap(), float(2.0)));
+        b.push(WriteOp::new(tp.clone(), float(1.5)));
+        assert_eq!(b.len(), 2);
+        assert_eq!(b.as_slice()[0].value, float(1.5));
+
+        b.clear();
+        assert!(b.get_mut(&tp).is_none());
+        b.push(WriteOp::new(tp.clone(), float(3.0)));
+        assert_eq!(b.len(), 1);
+        assert_eq!(b.as_slice()[0].value, float(3.0));
+    }
+
+    #[test]
+    fn writebatch_push_keeps_first_write_order_for_distinct_paths() {
+        let paths = ["r/c.x", "r/a.x", "r/b.x", "r/d.x", "r/a.x"];
+        let mut b = WriteBatch::new();
+        for (i, path) in paths.iter().enumerate() {
+            b.push(WriteOp::new(
+                TypedPath::parse(path).unwrap(),
+                float(i as f32),
+            ));
+        }
+
+        let order: Vec<String> = b.iter().map(|op| op.path.to_string()).collect();
+        assert_eq!(order, ["r/c.x", "r/a.x", "r/b.x", "r/d.x"]);
+        assert_eq!(b.as_slice()[1].value, float(4.0));
+
+        let json = serde_json::to_value(&b).unwrap();
+        assert_eq!(json.as_array().map(Vec::len), Some(4));
+        let parsed: WriteBatch = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, b);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn writebatch_push_sees_direct_edits_to_the_deprecated_vector() {
+        let tp = TypedPath::parse("r/t.a").unwrap();
+        let mut b = WriteBatch::new();
+        b.push(WriteOp::new(TypedPath::parse("r/t.b").unwrap(), float(0.0)));
+        b.0.insert(0, WriteOp::new(tp.clone(), float(0.5)));
+        b.push(WriteOp::new(tp.clone(), float(0.75)));
+        assert_eq!(b.len(), 2);
+        assert_eq!(b.as_slice()[0].value, float(0.75));
+
+        let repeated = WriteBatch::from_vec(vec![
+            WriteOp::new(tp.clone(), float(1.0)),
+            WriteOp::new(tp, float(2.0)),
+        ]);
+        assert_eq!(repeated.into_vec().len(), 1);
+    }
+
     #[test]
     fn writeop_roundtrip_with_shape() {
         let tp = TypedPath::parse("robot1/Arm/Joint3.angle").unwrap();
@@ -143,4 +441,59 @@ mod tests {
         let parsed: WriteOp = serde_json::from_str(&s).unwrap();
         assert_eq!(op, parsed);
     }
+
+    #[test]
+    fn partial_write_updates_one_vec3_component() {
+        let mut base = HashMap::new();
+        base.insert(
+            TypedPath::parse("robot/target.pos").unwrap(),
+            vec3([1.0, 2.0, 3.0]),
+        );
+
+        let op = WriteOp::new(TypedPath::parse("robot/target.pos.y").unwrap(), float(5.0));
+        apply_partial(&mut base, &op).unwrap();
+
+        assert_eq!(base.len(), 1);
+        let pos = &base[&TypedPath::parse("robot/target.pos").unwrap()];
+        assert_eq!(as_vec3(pos), Some([1.0, 5.0, 3.0]));
+    }
+
+    #[test]
+    fn partial_write_updates_transform_field() {
+        let path = TypedPath::parse("robot/node").unwrap();
+        let mut base = HashMap::new();
+        base.insert(
+            path.clone(),
+            transform(Transform {
+                translation: [1.0, 2.0, 3.0],
+                rotation: [0.0, 0.0, 0.0, 1.0],
+                scale: [1.0; 3],
+            }),
+        );
+
+        let y = WriteOp::new(
+            TypedPath::parse("robot/node.translation.y").unwrap(),
+            float(3.0),
+        );
+        apply_partial(&mut base, &y).unwrap();
+        let scale = WriteOp::new(
+            TypedPath::parse("robot/node.scale").unwrap(),
+            vec3([2.0, 2.0, 2.0]),
+        );
+        apply_partial(&mut base, &scale).unwrap();
+
+        let t = as_transform(&base[&path]).expect("transform");
+        assert_eq!(t.translation, [1.0, 3.0, 3.0]);
+        assert_eq!(t.scale, [2.0; 3]);
+
+        let bad = WriteOp::new(
+            TypedPath::parse("robot/node.translation.q").unwrap(),
+            float(0.0),
+        );
+        assert!(apply_partial(&mut base, &bad).is_err());
+        assert_eq!(
+            as_transform(&base[&path]).unwrap().translation,
+            [1.0, 3.0, 3.0]
+        );
+    }
 }
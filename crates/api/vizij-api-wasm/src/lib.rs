@@ -6,9 +6,12 @@
 //! api-core normalizer ([`vizij_api_core::json`]), so legacy payload forms
 //! (`{"vec3": [1, 2, 3]}`, `{"type": "float", "data": 1}`, bare primitives,
 //! ...) are still accepted; outputs are always the canonical Arora form.
+//! Batches can also cross in the opt-in compact `[tag, data]` form (see
+//! [`vizij_api_core::json::writebatch_to_compact`]).
 
-use serde_wasm_bindgen::to_value;
-use vizij_api_core::{json, Value, WriteBatch};
+use serde::Serialize;
+use serde_wasm_bindgen::{from_value, to_value, Serializer};
+use vizij_api_core::{abi, json, Value, WriteBatch};
 use wasm_bindgen::prelude::*;
 
 fn parse_value_json(value_json: &str) -> Result<Value, JsValue> {
@@ -30,6 +33,29 @@ pub fn validate_writebatch_json(batch_json: &str) -> Result<(), JsValue> {
     parse_writebatch_json(batch_json).map(|_| ())
 }
 
+/// Rewrite a `WriteBatch` JSON string so every op's `value` is in canonical
+/// Arora `Value` serde form (e.g. `{"vec3": [0, 1, 2]}` becomes a `struct`).
+///
+/// Ops are otherwise kept as-is, including duplicate paths. The result is
+/// checked against the canonical serde form, so a value the normalizer cannot
+/// interpret is reported here rather than by a later consumer.
+#[wasm_bindgen]
+pub fn normalize_writebatch_json(batch_json: &str) -> Result<String, JsValue> {
+    let mut raw: serde_json::Value =
+        serde_json::from_str(batch_json).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let ops = raw
+        .as_array_mut()
+        .ok_or_else(|| JsValue::from_str("write batch must be a JSON array"))?;
+    for op in ops.iter_mut() {
+        if let Some(value) = op.get_mut("value") {
+            *value = json::normalize_value_json(value.take());
+        }
+    }
+    serde_json::from_value::<WriteBatch>(raw.clone())
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    serde_json::to_string(&raw).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
 /// Parse a `WriteBatch` JSON string and return it as a JS object with values
 /// in canonical Arora `Value` serde form.
 #[wasm_bindgen]
@@ -52,3 +78,100 @@ pub fn value_to_js(value_json: &str) -> Result<JsValue, JsValue> {
     let value = parse_value_json(value_json)?;
     to_value(&value).map_err(|e| JsValue::from_str(&e.to_string()))
 }
+
+/// Convert a JS `Value` object back into a canonical Arora `Value` JSON
+/// string; the inverse of [`value_to_js`]. Shorthand objects built in TS
+/// (e.g. `{ vec3: [0, 1, 2] }`) are normalized on the way in.
+#[wasm_bindgen]
+pub fn js_to_value_json(value: JsValue) -> Result<String, JsValue> {
+    let raw: serde_json::Value =
+        from_value(value).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    canonical_value_json(raw).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+fn canonical_value_json(raw: serde_json::Value) -> Result<String, serde_json::Error> {
+    serde_json::to_string(&json::parse_value(raw)?)
+}
+
+/// Parse a `WriteBatch` JSON string (any accepted value form) and return it
+/// as a JS array in the compact `[path, [tag, data](, shape)]` form.
+#[wasm_bindgen]
+pub fn writebatch_to_compact_js(batch_json: &str) -> Result<JsValue, JsValue> {
+    let batch = parse_writebatch_json(batch_json)?;
+    // Compact records are plain JSON objects; keep them objects rather than
+    // JS `Map`s.
+    json::writebatch_to_compact(&batch)
+        .serialize(&Serializer::json_compatible())
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// Decode a compact write batch (as produced by [`writebatch_to_compact_js`])
+/// and return it as a JS object with values in canonical Arora `Value` serde
+/// form.
+#[wasm_bindgen]
+pub fn writebatch_from_compact_js(compact: JsValue) -> Result<JsValue, JsValue> {
+    let raw: serde_json::Value =
+        from_value(compact).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let batch =
+        json::writebatch_from_compact(raw).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    to_value(&batch).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// ABI version for compatibility checks with npm wrappers.
+#[wasm_bindgen]
+pub fn abi_version() -> u32 {
+    abi::API_WASM
+}
+
+/// Fail with a descriptive error unless this module's ABI equals `expected`.
+#[wasm_bindgen]
+pub fn check_abi(expected: u32) -> Result<(), JsValue> {
+    abi::check("vizij-api-wasm", abi::API_WASM, expected).map_err(|e| JsValue::from_str(&e))
+}
+
+/// ABI versions of every Vizij wasm binding as a JSON object keyed by crate name
+/// (e.g. `{"vizij-graph-wasm": 2, ...}`), so a loader can validate all modules in one call.
+#[wasm_bindgen]
+pub fn abi_versions_json() -> String {
+    abi::versions_json()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_writebatch_json_canonicalizes_shorthand_values() {
+        let shorthand = r#"[
+            { "path": "robot/Arm.translation", "value": { "vec3": [1, 2, 3] } },
+            { "path": "robot/Arm.weight", "value": 0.5 }
+        ]"#;
+        let normalized = normalize_writebatch_json(shorthand).expect("shorthand normalizes");
+
+        let batch: WriteBatch =
+            serde_json::from_str(&normalized).expect("normalized batch is canonical serde");
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch.as_slice()[1].value, Value::F32(0.5));
+        assert!(validate_writebatch_json(&normalized).is_ok());
+
+        let legacy = parse_writebatch_json(shorthand).expect("legacy parse");
+        assert_eq!(batch.as_slice()[0].value, legacy.as_slice()[0].value);
+    }
+
+    #[test]
+    fn js_to_value_json_round_trips_vec3() {
+        // `js_to_value_json` only adds the JsValue -> serde_json hop in front of this.
+        let shorthand = serde_json::json!({ "vec3": [0.0, 1.0, 2.0] });
+        let canonical = canonical_value_json(shorthand).expect("vec3 shorthand converts");
+        let value = parse_value_json(&canonical).expect("canonical json parses back");
+        assert_eq!(
+            serde_json::to_string(&value).expect("serialize"),
+            canonical,
+            "canonical form is a fixed point"
+        );
+        assert_eq!(
+            value,
+            serde_json::from_str::<Value>(&canonical).expect("canonical serde form")
+        );
+    }
+}
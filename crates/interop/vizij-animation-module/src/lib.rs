@@ -78,6 +78,7 @@ fn load_animation(clip: Option<AnimationClip>) -> u32 {
                 animatable_id: t.animatable_id,
                 points: t.points.into_iter().map(to_core_keypoint).collect(),
                 settings: None,
+                units: None,
             }
         })
         .collect();
@@ -285,6 +286,8 @@ fn baking_config(
         start_time: start_time.unwrap_or(defaults.start_time),
         end_time: end_time.or(defaults.end_time),
         derivative_epsilon: defaults.derivative_epsilon,
+        reverse: defaults.reverse,
+        quantize_bits: defaults.quantize_bits,
     }
 }
 
@@ -384,6 +387,7 @@ fn to_core_keypoint(kp: GenKeypoint) -> CoreKeypoint {
         stamp: kp.stamp,
         value,
         transitions,
+        interpolation: None,
     }
 }
 
@@ -46,7 +46,8 @@
 //! `Literal(JSON)` slot per node (`meta_slot`), present only when a node
 //! actually declares a shape (no real authored graph does — the runtime derives
 //! shapes — but the eval path honors them where present, so they must survive a
-//! round trip). This is the one carrier that is not a per-value slot.
+//! round trip). A node's opaque editor [`metadata`](NodeSpec::metadata) rides
+//! the same blob. This is the one carrier that is not a per-value slot.
 //!
 //! # Not yet mapped
 //!
@@ -126,8 +127,8 @@ fn present_params(params: &NodeParams) -> Result<Vec<(String, serde_json::Value)
 }
 
 /// A node's declared-shape metadata as a JSON string, or `None` when it declares
-/// none. Carries `output_shapes` and any input-default `shape` — see the
-/// module's "Declared-shape metadata" section.
+/// none. Carries `output_shapes`, any input-default `shape`, and non-null editor
+/// `metadata` — see the module's "Declared-shape metadata" section.
 fn node_meta_json(node: &NodeSpec) -> Result<Option<String>, String> {
     let mut input_shapes = serde_json::Map::new();
     for (port, default) in &node.input_defaults {
@@ -136,7 +137,7 @@ fn node_meta_json(node: &NodeSpec) -> Result<Option<String>, String> {
             input_shapes.insert(port.clone(), json);
         }
     }
-    if node.output_shapes.is_empty() && input_shapes.is_empty() {
+    if node.output_shapes.is_empty() && input_shapes.is_empty() && node.metadata.is_null() {
         return Ok(None);
     }
 
@@ -152,6 +153,9 @@ fn node_meta_json(node: &NodeSpec) -> Result<Option<String>, String> {
             serde_json::Value::Object(input_shapes),
         );
     }
+    if !node.metadata.is_null() {
+        meta.insert("metadata".to_string(), node.metadata.clone());
+    }
     serde_json::to_string(&serde_json::Value::Object(meta))
         .map(Some)
         .map_err(|e| format!("meta json: {e}"))
@@ -447,6 +451,10 @@ pub fn decode(graph: &Graph) -> Result<GraphSpec, String> {
             _ => Default::default(),
         };
         let input_shapes = node_meta.get("input_shapes").and_then(|v| v.as_object());
+        let metadata = node_meta
+            .get("metadata")
+            .cloned()
+            .unwrap_or(serde_json::Value::Null);
         // Recombine each inline default's value (the literal `Value` from its
         // `in` slot) with its declared shape (from the metadata blob).
         let mut input_defaults: std::collections::HashMap<String, InputDefault> =
@@ -468,6 +476,7 @@ pub fn decode(graph: &Graph) -> Result<GraphSpec, String> {
             params,
             output_shapes,
             input_defaults: input_defaults.into_iter().collect(),
+            metadata,
         });
     }
     nodes.sort_by(|a, b| a.id.cmp(&b.id));
@@ -700,7 +709,8 @@ mod tests {
     }
 
     /// Declared shape metadata (`output_shapes` and an input default's `shape`)
-    /// rides the reserved metadata slot and survives a round trip.
+    /// and editor `metadata` ride the reserved metadata slot and survive a
+    /// round trip.
     #[test]
     fn declared_shape_metadata_round_trips() {
         let spec: GraphSpec = serde_json::from_value(json!({
@@ -713,6 +723,12 @@ mod tests {
                     "input_defaults": {
                         "seed": { "value": { "f32": 0.0 }, "shape": { "id": { "id": "Scalar" } } }
                     }
+                },
+                {
+                    "id": "note",
+                    "type": "constant",
+                    "params": { "value": { "f32": 2.0 } },
+                    "metadata": { "position": [120, 40], "comment": "tuned by hand" }
                 }
             ],
             "edges": []
@@ -729,6 +745,8 @@ mod tests {
 
         let decoded = decode(&graph).expect("decode");
         assert_eq!(canonical(&spec), canonical(&decoded));
+        let note = decoded.nodes.iter().find(|n| n.id == "note").expect("note");
+        assert_eq!(note.metadata["comment"], "tuned by hand");
     }
 
     /// A spec diff (change a param, add a node, keep the incident edge) applied
@@ -562,10 +562,10 @@ mod tests {
                     "structs": { "id": RECORD_TYPE, "elements": [
                         record("anim/x", 0.25),
                         record("anim/y", 0.5),
-                        // A repeated key: batch order is preserved into the
-                        // write set, and the StateChange flush (a map) keeps
-                        // the last entry. Explicit combination of concurrent
-                        // publishers is VIZ-76's ground.
+                        // A repeated key overwrites the earlier write in the
+                        // write set, so the last entry wins. Explicit
+                        // combination of concurrent publishers is VIZ-76's
+                        // ground.
                         record("anim/x", 0.75),
                     ]}
                 }}},
@@ -19,7 +19,7 @@ use std::sync::mpsc::{channel, Sender};
 use std::sync::{Arc, Mutex, RwLock};
 
 use arora_types::data::{DataError, DataStore, Key, Slot, State, StateChange, Subscription};
-use vizij_api_core::blackboard::{Blackboard, BlackboardEntry};
+use vizij_api_core::blackboard::{Blackboard, BlackboardEntry, ChangeCallback};
 use vizij_api_core::{TypedPath, Value};
 
 /// Source label recorded on entries written through the Arora `DataStore` view.
@@ -79,6 +79,18 @@ impl BlackboardStore {
     pub fn with_blackboard<R>(&self, f: impl FnOnce(&Blackboard) -> R) -> R {
         f(&self.inner.blackboard.read().unwrap())
     }
+
+    /// Register a [`Blackboard::on_change`] watcher on the underlying blackboard.
+    ///
+    /// Callbacks run after the store's lock is released, so they may read or write the store.
+    pub fn on_change(&self, path_prefix: &str, callback: ChangeCallback) -> Result<(), DataError> {
+        self.inner
+            .blackboard
+            .write()
+            .unwrap()
+            .on_change(path_prefix, callback)
+            .map_err(|e| DataError::Other(e.to_string()))
+    }
 }
 
 /// Read one entry's value (`None` if absent or the key is not a valid path).
@@ -118,15 +130,22 @@ impl DataStore for BlackboardStore {
 
     fn write(&self, changes: StateChange) -> Result<(), DataError> {
         let epoch = self.inner.next_epoch();
-        {
+        let (applied, watched) = {
             let mut blackboard = self.inner.blackboard.write().unwrap();
-            for (key, value) in &changes.set {
-                apply_one(&mut blackboard, key, value, epoch)?;
-            }
-            for key in &changes.unset {
-                blackboard.remove(&key.path);
+            let applied = changes
+                .set
+                .iter()
+                .try_for_each(|(key, value)| apply_one(&mut blackboard, key, value, epoch));
+            if applied.is_ok() {
+                for key in &changes.unset {
+                    blackboard.remove(&key.path);
+                }
             }
-        }
+            (applied, blackboard.drain_notifications())
+        };
+        // Watchers run after the guard is dropped so they can read the store back.
+        watched.dispatch();
+        applied?;
         self.inner.notify(changes);
         Ok(())
     }
@@ -183,10 +202,12 @@ impl Slot for BlackboardSlot {
 
     fn set(&self, value: Option<Value>) -> Result<(), DataError> {
         let epoch = self.inner.next_epoch();
-        {
+        let watched = {
             let mut blackboard = self.inner.blackboard.write().unwrap();
             apply_one(&mut blackboard, &self.key, &value, epoch)?;
-        }
+            blackboard.drain_notifications()
+        };
+        watched.dispatch();
         self.inner.notify(StateChange {
             set: HashMap::from([(self.key.clone(), value)]),
             unset: Default::default(),
@@ -249,6 +270,34 @@ mod tests {
         assert_eq!(slot.get(), Some(float(3.0)));
     }
 
+    #[test]
+    fn on_change_callback_can_read_the_store() {
+        let store = BlackboardStore::new();
+        let reader = store.clone();
+        let (tx, rx) = std::sync::mpsc::channel();
+        let tx = Mutex::new(tx);
+        store
+            .on_change(
+                "rig",
+                Box::new(move |path, _| {
+                    let seen = reader.read(&[Key::from(path)]);
+                    tx.lock().unwrap().send(seen).unwrap();
+                }),
+            )
+            .unwrap();
+
+        store
+            .write(StateChange::set("rig/joint.angle", float(1.5)))
+            .unwrap();
+        assert_eq!(rx.try_recv().unwrap(), vec![Some(float(1.5))]);
+
+        store
+            .slot(&Key::from("rig/pos"))
+            .set(Some(float(2.0)))
+            .unwrap();
+        assert_eq!(rx.try_recv().unwrap(), vec![Some(float(2.0))]);
+    }
+
     #[test]
     fn subscribe_delivers_changes() {
         let store = BlackboardStore::new();
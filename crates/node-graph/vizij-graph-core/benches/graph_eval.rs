@@ -1,9 +1,11 @@
-use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
 use hashbrown::HashMap;
+use std::hint::black_box;
 use std::str::FromStr;
 use std::time::Duration;
 use vizij_api_core::json::normalize_graph_spec_value;
 use vizij_api_core::Shape;
+use vizij_graph_core::testutil::chain_of_adds;
 use vizij_graph_core::types::{
     EdgeInputEndpoint, EdgeOutputEndpoint, EdgeSpec, GraphSpec, InputDefault, NodeParams, NodeSpec,
     NodeType,
@@ -35,6 +37,7 @@ fn constant_node(id: impl Into<String>, value: f32) -> NodeSpec {
         },
         output_shapes: HashMap::<String, Shape>::new(),
         input_defaults: HashMap::<String, InputDefault>::new(),
+        metadata: serde_json::Value::Null,
     }
 }
 
@@ -50,6 +53,7 @@ fn vector_constant_node(id: impl Into<String>, xyz: [f32; 3]) -> NodeSpec {
         },
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     }
 }
 
@@ -63,6 +67,7 @@ fn oscillator_node(id: impl Into<String>, freq: f32) -> NodeSpec {
         },
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     }
 }
 
@@ -81,6 +86,7 @@ fn input_node(id: impl Into<String>, path: impl Into<String>, default: f32) -> N
         },
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     }
 }
 
@@ -98,6 +104,7 @@ fn output_node(id: impl Into<String>, path: impl Into<String>) -> NodeSpec {
         },
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     }
 }
 
@@ -108,36 +115,10 @@ fn add_node(id: impl Into<String>) -> NodeSpec {
         params: NodeParams::default(),
         output_shapes: HashMap::<String, Shape>::new(),
         input_defaults: HashMap::<String, InputDefault>::new(),
+        metadata: serde_json::Value::Null,
     }
 }
 
-/// Chain of Add nodes fed by a shared constant; length controls depth.
-fn chain_graph(length: usize) -> GraphSpec {
-    let length = length.max(1);
-    let mut nodes = Vec::with_capacity(length + 2);
-    let mut edges = Vec::with_capacity(length * 2);
-
-    nodes.push(constant_node("const_base", 1.0));
-    let mut prev = "const_base".to_string();
-
-    for i in 0..length {
-        let const_id = format!("bias_{i}");
-        let add_id = format!("add_{i}");
-        nodes.push(constant_node(&const_id, 0.5));
-        nodes.push(add_node(&add_id));
-        edges.push(link(&prev, &add_id, "operand_1"));
-        edges.push(link(&const_id, &add_id, "operand_2"));
-        prev = add_id;
-    }
-
-    GraphSpec {
-        nodes,
-        edges,
-        ..Default::default()
-    }
-    .with_cache()
-}
-
 /// Construct a “kitchen sink” block that hits many node types (excluding robotics/blend).
 /// Returns (nodes, edges, entry_target_id, entry_port, exit_id).
 fn kitchen_block(idx: usize) -> (Vec<NodeSpec>, Vec<EdgeSpec>, String, String, String) {
@@ -190,6 +171,7 @@ fn kitchen_block(idx: usize) -> (Vec<NodeSpec>, Vec<EdgeSpec>, String, String, S
         params: NodeParams::default(),
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     });
     edges.push(link(&sum, &mult, "operand_1"));
     edges.push(link(&const_gain, &mult, "operand_2"));
@@ -201,6 +183,7 @@ fn kitchen_block(idx: usize) -> (Vec<NodeSpec>, Vec<EdgeSpec>, String, String, S
         params: NodeParams::default(),
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     });
     edges.push(link(&mult, &div, "lhs"));
     edges.push(link(&const_div, &div, "rhs"));
@@ -213,6 +196,7 @@ fn kitchen_block(idx: usize) -> (Vec<NodeSpec>, Vec<EdgeSpec>, String, String, S
         params: NodeParams::default(),
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     });
     edges.push(link(&div, &sin, "in"));
 
@@ -223,6 +207,7 @@ fn kitchen_block(idx: usize) -> (Vec<NodeSpec>, Vec<EdgeSpec>, String, String, S
         params: NodeParams::default(),
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     });
     edges.push(link(&sin, &clamp, "in"));
     edges.push(link(&const_min, &clamp, "min"));
@@ -235,6 +220,7 @@ fn kitchen_block(idx: usize) -> (Vec<NodeSpec>, Vec<EdgeSpec>, String, String, S
         params: NodeParams::default(),
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     });
     edges.push(link(&clamp, &remap, "in"));
     edges.push(link(&in_min, &remap, "in_min"));
@@ -253,6 +239,7 @@ fn kitchen_block(idx: usize) -> (Vec<NodeSpec>, Vec<EdgeSpec>, String, String, S
         params: NodeParams::default(),
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     });
     edges.push(link(&sin, &join, "operand_1"));
     edges.push(link(&clamp, &join, "operand_2"));
@@ -265,6 +252,7 @@ fn kitchen_block(idx: usize) -> (Vec<NodeSpec>, Vec<EdgeSpec>, String, String, S
         params: NodeParams::default(),
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     });
     edges.push(link(&vconst, &vadd, "a"));
     edges.push(link(&join, &vadd, "b"));
@@ -276,6 +264,7 @@ fn kitchen_block(idx: usize) -> (Vec<NodeSpec>, Vec<EdgeSpec>, String, String, S
         params: NodeParams::default(),
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     });
     edges.push(link(&vadd, &vscale, "v"));
     edges.push(link(&const_scalar, &vscale, "scalar"));
@@ -287,6 +276,7 @@ fn kitchen_block(idx: usize) -> (Vec<NodeSpec>, Vec<EdgeSpec>, String, String, S
         params: NodeParams::default(),
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     });
     edges.push(link(&vscale, &vnorm, "in"));
 
@@ -297,6 +287,7 @@ fn kitchen_block(idx: usize) -> (Vec<NodeSpec>, Vec<EdgeSpec>, String, String, S
         params: NodeParams::default(),
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     });
     edges.push(link(&vnorm, &vdot, "a"));
     edges.push(link(&vconst, &vdot, "b"));
@@ -309,6 +300,7 @@ fn kitchen_block(idx: usize) -> (Vec<NodeSpec>, Vec<EdgeSpec>, String, String, S
         params: NodeParams::default(),
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     });
     edges.push(link(&clamp, &gt, "lhs"));
     edges.push(link(&remap, &gt, "rhs"));
@@ -320,6 +312,7 @@ fn kitchen_block(idx: usize) -> (Vec<NodeSpec>, Vec<EdgeSpec>, String, String, S
         params: NodeParams::default(),
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     });
     edges.push(link(&sin, &lt, "lhs"));
     edges.push(link(&clamp, &lt, "rhs"));
@@ -331,6 +324,7 @@ fn kitchen_block(idx: usize) -> (Vec<NodeSpec>, Vec<EdgeSpec>, String, String, S
         params: NodeParams::default(),
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     });
     edges.push(link(&gt, &and, "lhs"));
     edges.push(link(&lt, &and, "rhs"));
@@ -342,6 +336,7 @@ fn kitchen_block(idx: usize) -> (Vec<NodeSpec>, Vec<EdgeSpec>, String, String, S
         params: NodeParams::default(),
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     });
     edges.push(link(&lt, &not, "in"));
 
@@ -353,6 +348,7 @@ fn kitchen_block(idx: usize) -> (Vec<NodeSpec>, Vec<EdgeSpec>, String, String, S
         params: NodeParams::default(),
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     });
 
     let osc = format!("osc_{idx}");
@@ -369,6 +365,7 @@ fn kitchen_block(idx: usize) -> (Vec<NodeSpec>, Vec<EdgeSpec>, String, String, S
         },
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     });
     edges.push(link(&osc, &slew, "in"));
 
@@ -382,6 +379,7 @@ fn kitchen_block(idx: usize) -> (Vec<NodeSpec>, Vec<EdgeSpec>, String, String, S
         },
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     });
     edges.push(link(&remap, &damp, "in"));
 
@@ -397,6 +395,7 @@ fn kitchen_block(idx: usize) -> (Vec<NodeSpec>, Vec<EdgeSpec>, String, String, S
         },
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     });
     edges.push(link(&clamp, &spring, "in"));
 
@@ -408,6 +407,7 @@ fn kitchen_block(idx: usize) -> (Vec<NodeSpec>, Vec<EdgeSpec>, String, String, S
         params: NodeParams::default(),
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     });
     edges.push(link(&and, &sel, "cond"));
     edges.push(link(&spring, &sel, "then"));
@@ -525,7 +525,7 @@ fn bench_graphs(c: &mut Criterion) {
     // Tiny smoke
     {
         group.sample_size(50);
-        let spec = chain_graph(8);
+        let spec = chain_of_adds(8);
         group.bench_with_input(
             BenchmarkId::new("cold", "tiny-smoke-8"),
             &spec,
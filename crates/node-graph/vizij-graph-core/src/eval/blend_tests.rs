@@ -23,6 +23,7 @@ fn constant_node(id: &str, value: Value) -> NodeSpec {
         },
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     }
 }
 
@@ -57,6 +58,7 @@ fn weighted_sum_vector_scalar_weight_broadcasts_and_outputs_descriptive_ports()
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![link("vals", "ws", "values"), link("w", "ws", "weights")],
@@ -114,6 +116,7 @@ fn weighted_sum_vector_length_mismatch_returns_nans() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![link("vals", "ws", "values"), link("w", "ws", "weights")],
@@ -155,6 +158,7 @@ fn blend_weighted_average_computes_normalized_average() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "bavg".to_string(),
@@ -162,6 +166,7 @@ fn blend_weighted_average_computes_normalized_average() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![
@@ -198,6 +203,7 @@ fn blend_multiply_computes_product_of_terms() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![link("vals", "mult", "values"), link("w", "mult", "weights")],
@@ -229,6 +235,7 @@ fn blend_max_selects_value_of_highest_effective_weight() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![link("vals", "max", "values"), link("w", "max", "weights")],
@@ -257,6 +264,7 @@ fn blend_max_without_values_or_base_returns_nan() {
             params: NodeParams::default(),
             output_shapes: HashMap::new(),
             input_defaults: HashMap::new(),
+            metadata: serde_json::Value::Null,
         }],
         edges: vec![],
         version: 1,
@@ -297,6 +305,7 @@ fn default_blend_matches_expected_vec3_output() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "blend".to_string(),
@@ -304,6 +313,7 @@ fn default_blend_matches_expected_vec3_output() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![
@@ -358,6 +368,7 @@ fn default_blend_emits_nan_value_when_weight_length_mismatch() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![
@@ -397,6 +408,7 @@ fn case_node_routes_based_on_case_labels_param() {
                 params,
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![
@@ -437,6 +449,7 @@ fn case_node_without_default_emits_nan_when_no_match() {
                 params,
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![
@@ -457,3 +470,68 @@ fn case_node_without_default_emits_nan_when_no_match() {
         other => panic!("expected float NaN out, got {:?}", other),
     }
 }
+
+fn weighted_sum_graph(weights: Vec<f32>) -> GraphSpec {
+    GraphSpec {
+        nodes: vec![
+            constant_node("a", vocab::vec3([1.0, 0.0, 0.0])),
+            constant_node("b", vocab::vec3([0.0, 2.0, 0.0])),
+            constant_node("c", vocab::vec3([0.0, 0.0, 4.0])),
+            NodeSpec {
+                id: "ws".to_string(),
+                kind: NodeType::WeightedSum,
+                params: NodeParams {
+                    weights: Some(weights),
+                    ..Default::default()
+                },
+                output_shapes: HashMap::new(),
+                input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
+            },
+        ],
+        edges: vec![
+            link("a", "ws", "value_1"),
+            link("b", "ws", "value_2"),
+            link("c", "ws", "value_3"),
+        ],
+        ..Default::default()
+    }
+    .with_cache()
+}
+
+#[test]
+fn weighted_sum_blends_vec3_inputs_by_normalized_weight() {
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &weighted_sum_graph(vec![1.0, 2.0, 1.0])).expect("eval");
+    let out = expect_vec3(&rt.outputs["ws"]["out"].value);
+    // (a + 2b + c) / 4
+    let expected = [0.25, 1.0, 1.0];
+    for (got, want) in out.iter().zip(expected) {
+        assert!((got - want).abs() < 1e-6, "{out:?} vs {expected:?}");
+    }
+}
+
+#[test]
+fn weighted_sum_with_zero_total_weight_is_nan() {
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &weighted_sum_graph(vec![0.0, 0.0, 0.0])).expect("eval");
+    let out = expect_vec3(&rt.outputs["ws"]["out"].value);
+    assert!(out.iter().all(|c| c.is_nan()), "{out:?}");
+}
+
+#[test]
+fn weighted_sum_indexes_weights_by_port_number() {
+    let mut graph = weighted_sum_graph(vec![1.0, 100.0, 3.0]);
+    // Leave value_2 unconnected: value_3 must still take the third weight.
+    graph.edges.retain(|edge| edge.to.input != "value_2");
+    let graph = graph.with_cache();
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &graph).expect("eval");
+    let out = expect_vec3(&rt.outputs["ws"]["out"].value);
+    // (a + 3c) / 4
+    let expected = [0.25, 0.0, 3.0];
+    for (got, want) in out.iter().zip(expected) {
+        assert!((got - want).abs() < 1e-6, "{out:?} vs {expected:?}");
+    }
+}
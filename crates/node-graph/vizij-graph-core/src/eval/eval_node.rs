@@ -3,7 +3,7 @@
 use crate::eval::graph_runtime::{GraphRuntime, StagedInput};
 use crate::eval::node_function::NodeFunctions;
 use crate::eval::plan::{PlanCache, PortLayout};
-use crate::eval::variadic::collect_operand_ports;
+use crate::eval::variadic::{collect_operand_ports, parse_variadic_key};
 use crate::types::{NodeParams, NodeSpec, NodeType, RoundMode};
 use hashbrown::HashMap;
 use uuid::Uuid;
@@ -13,14 +13,15 @@ use vizij_api_core::{coercion, Shape, TypedPath, Value, WriteOp};
 use super::noise;
 use super::numeric::{as_bool, as_float, binary_numeric, unary_numeric};
 use super::shape_helpers::{
-    coerce_numeric_to_shape, is_numeric_like, null_of_shape_numeric, project_by_selector,
-    value_matches_shape,
+    check_declared_shape, coerce_numeric_to_shape, is_numeric_like, null_of_shape_numeric,
+    project_by_selector, value_matches_shape,
 };
 use super::urdfik::{
-    apply_joint_positions, fetch_joint_vector, hash_urdf_config, quat_from_value, solve_pose,
-    solve_position, tip_pose, vector_from_value, IkKey,
+    apply_joint_positions, fetch_joint_vector, hash_urdf_config, link_transforms, parse_ik_solver,
+    quat_from_value, solve_orientation, solve_pose, solve_position, solve_position_ccd, tip_pose,
+    vector_from_value, IkKey, IkSolution, IkSolverKind, UrdfKinematicsState,
 };
-use super::value_layout::{align_flattened, flatten_numeric, FlatValue, PortValue};
+use super::value_layout::{align_values, flatten_numeric, FlatValue, PortValue};
 use super::variadic::fold_numeric_variadic;
 
 /// Read-only view of a node's inputs backed by slot-indexed storage.
@@ -92,6 +93,22 @@ impl<'a> InputSlots<'a> {
         }
         &[]
     }
+
+    /// Like [`Self::variadic`], pairing each port with its numeric suffix (`value_3` -> `Some(3)`).
+    pub fn variadic_indexed(
+        &self,
+        group: &str,
+    ) -> impl Iterator<Item = (Option<usize>, &PortValue)> {
+        let names = self
+            .layout
+            .variadic_range(group)
+            .and_then(|range| self.layout.slots.get(range.start..range.start + range.len))
+            .unwrap_or(&[]);
+        names
+            .iter()
+            .map(|name| parse_variadic_key(name).1)
+            .zip(self.variadic(group))
+    }
 }
 
 /// Mutable view of a node's outputs backed by slot-indexed storage.
@@ -201,7 +218,19 @@ pub fn eval_node_inner(
     outputs: &mut OutputSlots,
     functions: Option<&mut dyn NodeFunctions>,
 ) -> Result<(), String> {
-    evaluate_kind_inner(rt, spec, inputs, outputs, functions)?;
+    eval_node_budgeted(rt, spec, inputs, outputs, functions, None)
+}
+
+/// [`eval_node_inner`] drawing nested `Subgraph` evaluations from a remaining node `budget`.
+pub(crate) fn eval_node_budgeted(
+    rt: &mut GraphRuntime,
+    spec: &NodeSpec,
+    inputs: &InputSlots,
+    outputs: &mut OutputSlots,
+    functions: Option<&mut dyn NodeFunctions>,
+    budget: Option<&mut usize>,
+) -> Result<(), String> {
+    evaluate_kind_inner(rt, spec, inputs, outputs, functions, budget)?;
     enforce_output_shapes_slots(spec, outputs.layout, outputs.as_mut_slice())?;
 
     // Only explicit sink nodes (Output) publish external writes.
@@ -209,11 +238,8 @@ pub fn eval_node_inner(
         if let Some(path) = spec.params.path.clone() {
             if let Some(slot) = outputs.layout.slot("out") {
                 if let Some(port) = outputs.as_slice().get(slot) {
-                    rt.writes.push(WriteOp::new_with_shape(
-                        path,
-                        port.value.clone(),
-                        Some(port.shape.clone()),
-                    ));
+                    push_output_write(rt, path, port, spec.params.accumulate.as_deref())
+                        .map_err(|message| format!("node '{}': {message}", spec.id))?;
                 }
             }
         } else if let (Some(key_field), Some(value_field)) =
@@ -230,6 +256,59 @@ pub fn eval_node_inner(
     Ok(())
 }
 
+/// Queue an `Output` node's write, combining it with an earlier write to the same path
+/// according to the node's `accumulate` mode.
+///
+/// - `"last"` (default): replace the earlier write.
+/// - `"sum"`: add componentwise onto the earlier value.
+/// - `"list"`: append to an array collected by earlier `"list"` writes. A write from any other
+///   mode becomes the array's first element as a whole, even when its value is itself an array.
+///
+/// List-collected writes are the ones queued without a shape; `"last"` and `"sum"` writes always
+/// carry their port's shape.
+fn push_output_write(
+    rt: &mut GraphRuntime,
+    path: TypedPath,
+    port: &PortValue,
+    accumulate: Option<&str>,
+) -> Result<(), String> {
+    let mode = accumulate.unwrap_or("last");
+    let existing = match mode {
+        "last" => None,
+        "sum" | "list" => rt.writes.get_mut(&path),
+        other => {
+            return Err(format!(
+                "unknown accumulate mode '{other}' (expected 'last', 'sum', or 'list')"
+            ))
+        }
+    };
+    match (mode, existing) {
+        ("sum", Some(op)) => {
+            op.value = binary_numeric(&op.value, &port.value, |a, b| a + b);
+            op.shape = Some(port.shape.clone());
+        }
+        ("list", Some(op)) => {
+            match (&mut op.value, &op.shape) {
+                (Value::ArrayValue(items), None) => items.push(port.value.clone()),
+                (previous, _) => {
+                    let first = std::mem::replace(previous, Value::ArrayValue(Vec::new()));
+                    *previous = vocab::array(vec![first, port.value.clone()]);
+                }
+            }
+            op.shape = None;
+        }
+        ("list", None) => rt
+            .writes
+            .push(WriteOp::new(path, vocab::array(vec![port.value.clone()]))),
+        _ => rt.writes.push(WriteOp::new_with_shape(
+            path,
+            port.value.clone(),
+            Some(port.shape.clone()),
+        )),
+    }
+    Ok(())
+}
+
 /// Expand a keyed record batch into one write per record: how a **path-less**
 /// [`NodeType::Output`] applies a value that names its own keys (e.g. a module
 /// call's "what changed") onto them by default.
@@ -238,8 +317,8 @@ pub fn eval_node_inner(
 /// the target path as a string and `value_field` the value to write. A record
 /// missing either (or keying on a non-string) is an **evaluation error** — a
 /// miswired batch, not data — while an empty batch simply writes nothing.
-/// Records repeating a key produce writes in batch order, and the per-tick
-/// flush keeps the last one; explicit combination of concurrent publishers is
+/// A record repeating a key overwrites the earlier write to that path in
+/// place, so the last one wins; explicit combination of concurrent publishers is
 /// VIZ-76's ground (https://linear.app/semio-ai/issue/VIZ-76).
 fn expand_keyed_batch(
     rt: &mut GraphRuntime,
@@ -289,6 +368,7 @@ fn evaluate_kind_inner(
     inputs: &InputSlots,
     outputs: &mut OutputSlots,
     functions: Option<&mut dyn NodeFunctions>,
+    budget: Option<&mut usize>,
 ) -> Result<(), String> {
     let params = &spec.params;
     match &spec.kind {
@@ -305,29 +385,49 @@ fn evaluate_kind_inner(
         node_type @ (NodeType::Sin | NodeType::Cos | NodeType::Tan) => {
             eval_trig(node_type, inputs, outputs)
         }
-        node_type @ (NodeType::Abs | NodeType::Sqrt | NodeType::Sign) => {
-            eval_unary_scalar(node_type, inputs, outputs)
-        }
+        node_type @ (NodeType::Abs
+        | NodeType::Sqrt
+        | NodeType::Sign
+        | NodeType::Saturate
+        | NodeType::Negate
+        | NodeType::Reciprocal) => eval_unary_scalar(node_type, inputs, outputs),
         node_type @ (NodeType::Min | NodeType::Max) => eval_min_max(node_type, inputs, outputs),
         NodeType::Round => eval_round(params, inputs, outputs),
+        NodeType::Quantize => eval_quantize(params, inputs, outputs),
         NodeType::Time => eval_time(rt, outputs),
         NodeType::Oscillator => eval_oscillator(rt, inputs, outputs),
-        node_type @ (NodeType::Spring | NodeType::Damp | NodeType::Slew) => {
+        node_type @ (NodeType::Spring | NodeType::Damp | NodeType::Slew | NodeType::Ema) => {
             eval_stateful(node_type, rt, spec, params, inputs, outputs)
         }
+        NodeType::SampleHold => eval_sample_hold(rt, spec, inputs, outputs),
+        NodeType::Delay => eval_delay(rt, spec, params, inputs, outputs),
+        NodeType::MovingAverage => eval_moving_average(rt, spec, params, inputs, outputs),
+        NodeType::FeedbackSource => eval_feedback_source(rt, spec, inputs, outputs),
+        NodeType::FeedbackTap => eval_feedback_tap(rt, spec, params, outputs),
         node_type @ (NodeType::And | NodeType::Or | NodeType::Not | NodeType::Xor) => {
             eval_logic(node_type, inputs, outputs)
         }
+        NodeType::Edge => eval_edge(rt, spec, inputs, outputs),
         node_type @ (NodeType::GreaterThan
         | NodeType::LessThan
         | NodeType::Equal
-        | NodeType::NotEqual) => eval_comparison(node_type, inputs, outputs),
+        | NodeType::NotEqual) => eval_comparison(node_type, params, inputs, outputs),
+        NodeType::Compare => eval_compare(params, inputs, outputs),
         NodeType::If => eval_if(inputs, outputs),
         NodeType::Clamp => eval_clamp(inputs, outputs),
         NodeType::Remap => eval_remap(inputs, outputs),
         NodeType::CenteredRemap => eval_centered_remap(inputs, outputs),
         NodeType::PiecewiseRemap => eval_piecewise_remap(params, inputs, outputs),
+        NodeType::Curve => eval_curve(params, inputs, outputs),
         NodeType::Vec3Cross => eval_vec3_cross(inputs, outputs),
+        NodeType::RgbToHsv => eval_color_map("RgbToHsv", inputs, outputs, rgb_to_hsv),
+        NodeType::HsvToRgb => eval_color_map("HsvToRgb", inputs, outputs, hsv_to_rgb),
+        NodeType::SrgbToLinear => {
+            eval_color_map("SrgbToLinear", inputs, outputs, |c| c.map(srgb_to_linear))
+        }
+        NodeType::LinearToSrgb => {
+            eval_color_map("LinearToSrgb", inputs, outputs, |c| c.map(linear_to_srgb))
+        }
         NodeType::VectorConstant => eval_vector_constant(params, outputs),
         node_type @ (NodeType::VectorAdd
         | NodeType::VectorSubtract
@@ -348,6 +448,7 @@ fn evaluate_kind_inner(
         | NodeType::VectorMode) => eval_vector_reducer(node_type, inputs, outputs),
         NodeType::WeightedSumVector => eval_weighted_sum_vector(inputs, outputs),
         NodeType::DefaultBlend => eval_default_blend(inputs, outputs),
+        NodeType::WeightedSum => eval_weighted_sum(params, inputs, outputs),
         NodeType::BlendWeightedAverage => eval_blend_weighted_average(inputs, outputs),
         NodeType::BlendAdditive => eval_blend_additive(inputs, outputs),
         NodeType::BlendMultiply => eval_blend_multiply(inputs, outputs),
@@ -357,7 +458,7 @@ fn evaluate_kind_inner(
         }
         NodeType::BlendMax => eval_blend_max(inputs, outputs),
         node_type @ (NodeType::SimpleNoise | NodeType::PerlinNoise | NodeType::SimplexNoise) => {
-            eval_noise(node_type, params, inputs, outputs)
+            eval_noise(rt.seed, node_type, params, inputs, outputs)
         }
         NodeType::InverseKinematics => eval_inverse_kinematics(inputs, outputs),
         #[cfg(feature = "urdf_ik")]
@@ -371,10 +472,17 @@ fn evaluate_kind_inner(
         #[cfg(not(feature = "urdf_ik"))]
         NodeType::UrdfIkPose => Err("UrdfIkPose node requires the 'urdf_ik' feature".to_string()),
         #[cfg(feature = "urdf_ik")]
+        NodeType::UrdfIkOrientation => eval_urdf_orientation(rt, spec, params, inputs, outputs),
+        #[cfg(not(feature = "urdf_ik"))]
+        NodeType::UrdfIkOrientation => {
+            Err("UrdfIkOrientation node requires the 'urdf_ik' feature".to_string())
+        }
+        #[cfg(feature = "urdf_ik")]
         NodeType::UrdfFk => eval_urdf_fk(rt, spec, params, inputs, outputs),
         #[cfg(not(feature = "urdf_ik"))]
         NodeType::UrdfFk => Err("UrdfFk node requires the 'urdf_ik' feature".to_string()),
         NodeType::Case => eval_case(params, inputs, outputs),
+        NodeType::CaseRoute => eval_case_route(params, inputs, outputs),
         NodeType::BuildRecord => eval_build_record(params, inputs, outputs),
         NodeType::ReadRecord => eval_read_record(params, inputs, outputs),
         NodeType::SwitchRecord => eval_switch_record(inputs, outputs),
@@ -384,10 +492,73 @@ fn evaluate_kind_inner(
         NodeType::MathAddRecord => eval_math_record(inputs, outputs, |a, b| a + b),
         NodeType::MathDivRecord => eval_math_record(inputs, outputs, |a, b| a / b),
         NodeType::MathSubRecord => eval_math_record(inputs, outputs, |a, b| a - b),
+        NodeType::ListLength => eval_list_length(inputs, outputs),
+        NodeType::ListGet => eval_list_get(params, inputs, outputs),
+        NodeType::NumberToText => eval_number_to_text(params, inputs, outputs),
+        NodeType::TextToNumber => eval_text_to_number(inputs, outputs),
         NodeType::Input => eval_input_node(rt, spec, outputs),
         NodeType::Output => eval_output(inputs, outputs),
         NodeType::ExternalFunction => eval_external_function(params, inputs, outputs, functions),
+        NodeType::Subgraph => eval_subgraph(rt, spec, inputs, outputs, functions, budget),
+    }
+}
+
+/// Evaluate a nested graph on the child runtime kept under this node's id, so stateful child
+/// nodes persist across frames.
+///
+/// Connected mapped inputs are staged into the child for this frame; unconnected ones leave the
+/// child `Input` node on its own default. Child `Output` writes are forwarded to the parent batch.
+/// Under a node `budget`, the child's scheduled nodes (and its own subgraphs) are taken from it.
+fn eval_subgraph(
+    rt: &mut GraphRuntime,
+    spec: &NodeSpec,
+    inputs: &InputSlots,
+    outputs: &mut OutputSlots,
+    functions: Option<&mut dyn NodeFunctions>,
+    budget: Option<&mut usize>,
+) -> Result<(), String> {
+    let sub = spec.params.subgraph.as_deref().ok_or_else(|| {
+        format!(
+            "Subgraph node '{}' missing required 'subgraph' parameter",
+            spec.id
+        )
+    })?;
+
+    let (t, dt, seed) = (rt.t, rt.dt, rt.seed);
+    let child = rt.subgraph_runtime_mut(&spec.id);
+    child.t = t;
+    child.dt = dt;
+    child.seed = seed;
+    for mapping in &sub.inputs {
+        if let Some(port) = inputs.get(&mapping.port) {
+            child.set_input(
+                mapping.path.clone(),
+                port.value.clone(),
+                Some(port.shape.clone()),
+            );
+        }
+    }
+
+    super::evaluate_all_inner(child, &sub.graph, functions, budget)
+        .map_err(|err| format!("subgraph '{}': {}", spec.id, err))?;
+
+    for mapping in &sub.outputs {
+        let port = child
+            .outputs
+            .get(&mapping.node_id)
+            .and_then(|ports| ports.get(&mapping.output))
+            .cloned()
+            .ok_or_else(|| {
+                format!(
+                    "subgraph '{}': output '{}' maps to missing child port '{}.{}'",
+                    spec.id, mapping.port, mapping.node_id, mapping.output
+                )
+            })?;
+        keyed_port(outputs, &mapping.port, port)?;
     }
+    let writes = std::mem::take(&mut child.writes);
+    rt.writes.extend(writes.into_vec());
+    Ok(())
 }
 
 fn eval_external_function(
@@ -541,6 +712,14 @@ fn eval_unary_scalar(
                 }
             }),
         ),
+        NodeType::Saturate => {
+            single_output(outputs, unary_numeric(&input.value, |x| x.clamp(0.0, 1.0)))
+        }
+        NodeType::Negate => single_output(outputs, unary_numeric(&input.value, |x| -x)),
+        NodeType::Reciprocal => single_output(
+            outputs,
+            unary_numeric(&input.value, |x| if x == 0.0 { f32::NAN } else { 1.0 / x }),
+        ),
         _ => Err(format!("unsupported unary scalar node {:?}", kind)),
     }
 }
@@ -579,6 +758,25 @@ fn eval_round(
     single_output(outputs, unary_numeric(&input.value, op))
 }
 
+fn eval_quantize(
+    params: &NodeParams,
+    inputs: &InputSlots,
+    outputs: &mut OutputSlots,
+) -> Result<(), String> {
+    let input = input_or_default(inputs, "in");
+    let step = params.step.unwrap_or(1.0).abs();
+    let offset = params.offset.unwrap_or(0.0);
+    if !step.is_finite() || step <= f32::EPSILON || !offset.is_finite() {
+        return single_output(outputs, input.value);
+    }
+    single_output(
+        outputs,
+        unary_numeric(&input.value, |x| {
+            ((x - offset) / step).round() * step + offset
+        }),
+    )
+}
+
 fn eval_trig(
     kind: &NodeType,
     inputs: &InputSlots,
@@ -613,7 +811,7 @@ fn eval_oscillator(
     let phase_flat = flatten_numeric(&phase_value);
 
     let value = match (freq_flat, phase_flat) {
-        (Some(freq_flat), Some(phase_flat)) => match align_flattened(&freq_flat, &phase_flat) {
+        (Some(_), Some(_)) => match align_values(&freq_value, &phase_value) {
             Ok((layout, freqs, phases)) => {
                 let data: Vec<f32> = freqs
                     .into_iter()
@@ -768,10 +966,135 @@ fn eval_stateful(
             }
             single_output(outputs, state.layout.reconstruct(&state.value))
         }
+        (NodeType::Ema, Some(flat)) => {
+            // Seeded with the first input, so a constant signal passes through unchanged.
+            let alpha = params.alpha.unwrap_or(0.5);
+            let alpha = if alpha.is_finite() {
+                alpha.clamp(0.0, 1.0)
+            } else {
+                0.5
+            };
+            let state = rt.ema_state_mut(&spec.id, &flat);
+            for (value, input) in state.value.iter_mut().zip(flat.data.iter()) {
+                *value = alpha * *input + (1.0 - alpha) * *value;
+            }
+            single_output(outputs, state.layout.reconstruct(&state.value))
+        }
         _ => single_output(outputs, vocab::float(f32::NAN)),
     }
 }
 
+fn eval_sample_hold(
+    rt: &mut GraphRuntime,
+    spec: &NodeSpec,
+    inputs: &InputSlots,
+    outputs: &mut OutputSlots,
+) -> Result<(), String> {
+    let input = input_or_default(inputs, "in");
+    let trigger = as_bool(&input_or_default(inputs, "trigger").value);
+    let state = rt.sample_hold_state_mut(&spec.id, &input.value);
+    if trigger {
+        state.held = input.value;
+    }
+    single_output(outputs, state.held.clone())
+}
+
+fn eval_delay(
+    rt: &mut GraphRuntime,
+    spec: &NodeSpec,
+    params: &NodeParams,
+    inputs: &InputSlots,
+    outputs: &mut OutputSlots,
+) -> Result<(), String> {
+    let input = input_or_default(inputs, "in");
+    let frames = params.frames.unwrap_or(1) as usize;
+    let state = rt.delay_state_mut(&spec.id, &input.shape.id);
+    state.history.push_back(input.value.clone());
+    // Shrinking `frames` between evaluations leaves surplus history; drop the oldest entries so
+    // the lag matches the new length immediately.
+    while state.history.len() > frames + 1 {
+        state.history.pop_front();
+    }
+    let delayed = if state.history.len() > frames {
+        state.history.pop_front()
+    } else {
+        None
+    };
+
+    let value =
+        delayed
+            .or_else(|| params.default.clone())
+            .unwrap_or_else(|| match flatten_numeric(&input.value) {
+                Some(flat) => flat.layout.fill_with(0.0),
+                None => vocab::float(0.0),
+            });
+    single_output(outputs, value)
+}
+
+fn eval_feedback_source(
+    rt: &mut GraphRuntime,
+    spec: &NodeSpec,
+    inputs: &InputSlots,
+    outputs: &mut OutputSlots,
+) -> Result<(), String> {
+    let input = input_or_default(inputs, "in");
+    let epoch = rt.input_epoch;
+    rt.feedback_state_mut(&spec.id)
+        .record(epoch, input.value.clone());
+    keyed_port(outputs, "out", input)
+}
+
+fn eval_feedback_tap(
+    rt: &GraphRuntime,
+    spec: &NodeSpec,
+    params: &NodeParams,
+    outputs: &mut OutputSlots,
+) -> Result<(), String> {
+    let source = params.source.as_ref().ok_or_else(|| {
+        format!(
+            "FeedbackTap node '{}' missing required 'source' parameter",
+            spec.id
+        )
+    })?;
+    let value = rt
+        .feedback_state(source)
+        .and_then(|state| state.previous_frame(rt.input_epoch))
+        .cloned()
+        .or_else(|| params.default.clone())
+        .unwrap_or_else(|| vocab::float(0.0));
+    single_output(outputs, value)
+}
+
+fn eval_moving_average(
+    rt: &mut GraphRuntime,
+    spec: &NodeSpec,
+    params: &NodeParams,
+    inputs: &InputSlots,
+    outputs: &mut OutputSlots,
+) -> Result<(), String> {
+    let input = input_or_default(inputs, "in");
+    let Some(flat) = flatten_numeric(&input.value) else {
+        return single_output(outputs, vocab::float(f32::NAN));
+    };
+    let window = params.window.unwrap_or(4).max(1) as usize;
+    let state = rt.moving_average_state_mut(&spec.id, &flat);
+    state.samples.push_back(flat.data);
+    // Shrinking `window` between evaluations drops the oldest samples immediately.
+    while state.samples.len() > window {
+        state.samples.pop_front();
+    }
+
+    let count = state.samples.len() as f32;
+    let mut average = vec![0.0f32; state.layout.scalar_len()];
+    for sample in &state.samples {
+        for (acc, x) in average.iter_mut().zip(sample) {
+            *acc += x;
+        }
+    }
+    average.iter_mut().for_each(|acc| *acc /= count);
+    single_output(outputs, state.layout.reconstruct(&average))
+}
+
 fn eval_logic(
     kind: &NodeType,
     inputs: &InputSlots,
@@ -796,23 +1119,72 @@ fn eval_logic(
     single_output(outputs, vocab::bool_(value))
 }
 
+fn eval_edge(
+    rt: &mut GraphRuntime,
+    spec: &NodeSpec,
+    inputs: &InputSlots,
+    outputs: &mut OutputSlots,
+) -> Result<(), String> {
+    let current = as_bool(&input_or_default(inputs, "in").value);
+    let state = rt.edge_state_mut(&spec.id);
+    let (rising, falling) = match state.previous.replace(current) {
+        Some(previous) => (!previous && current, previous && !current),
+        None => (false, false),
+    };
+    keyed_output(outputs, "rising", vocab::bool_(rising))?;
+    keyed_output(outputs, "falling", vocab::bool_(falling))?;
+    keyed_output(outputs, "changed", vocab::bool_(rising || falling))
+}
+
 fn eval_comparison(
     kind: &NodeType,
+    params: &NodeParams,
     inputs: &InputSlots,
     outputs: &mut OutputSlots,
 ) -> Result<(), String> {
-    let lhs = input_or_default(inputs, "lhs");
-    let rhs = input_or_default(inputs, "rhs");
+    let lhs = as_float(&input_or_default(inputs, "lhs").value);
+    let rhs = as_float(&input_or_default(inputs, "rhs").value);
+    // Equal/NotEqual tolerance: absolute by default, or scaled by the larger magnitude when
+    // `relative` is set so large values compare sensibly.
+    let epsilon = params.epsilon.unwrap_or(1e-6).abs();
+    let tolerance = if params.relative.unwrap_or(false) {
+        epsilon * lhs.abs().max(rhs.abs())
+    } else {
+        epsilon
+    };
     let value = match kind {
-        NodeType::GreaterThan => as_float(&lhs.value) > as_float(&rhs.value),
-        NodeType::LessThan => as_float(&lhs.value) < as_float(&rhs.value),
-        NodeType::Equal => (as_float(&lhs.value) - as_float(&rhs.value)).abs() < 1e-6,
-        NodeType::NotEqual => (as_float(&lhs.value) - as_float(&rhs.value)).abs() > 1e-6,
+        NodeType::GreaterThan => lhs > rhs,
+        NodeType::LessThan => lhs < rhs,
+        NodeType::Equal => lhs == rhs || (lhs - rhs).abs() < tolerance,
+        NodeType::NotEqual => lhs != rhs && (lhs - rhs).abs() > tolerance,
         _ => unreachable!(),
     };
     single_output(outputs, vocab::bool_(value))
 }
 
+fn eval_compare(
+    params: &NodeParams,
+    inputs: &InputSlots,
+    outputs: &mut OutputSlots,
+) -> Result<(), String> {
+    let lhs = input_or_default(inputs, "lhs");
+    let rhs = input_or_default(inputs, "rhs");
+    let epsilon = params.epsilon.unwrap_or(1e-6).abs();
+    single_output(
+        outputs,
+        binary_numeric(&lhs.value, &rhs.value, |x, y| {
+            let diff = x - y;
+            if diff.is_nan() {
+                f32::NAN
+            } else if diff.abs() <= epsilon {
+                0.0
+            } else {
+                diff.signum()
+            }
+        }),
+    )
+}
+
 fn eval_if(inputs: &InputSlots, outputs: &mut OutputSlots) -> Result<(), String> {
     let cond = as_bool(&input_or_default(inputs, "cond").value);
     let branch = if cond {
@@ -973,6 +1345,42 @@ fn eval_piecewise_remap(
     single_output(outputs, remapped)
 }
 
+fn eval_curve(
+    params: &NodeParams,
+    inputs: &InputSlots,
+    outputs: &mut OutputSlots,
+) -> Result<(), String> {
+    let points = params.points.as_deref().unwrap_or(&[]);
+    if points.is_empty() {
+        return Err("Curve requires at least one point".to_string());
+    }
+    for (idx, (x, y)) in points.iter().enumerate() {
+        if !x.is_finite() || !y.is_finite() {
+            return Err(format!("Curve points[{idx}] must be finite"));
+        }
+        if idx > 0 && *x < points[idx - 1].0 {
+            return Err(format!(
+                "Curve points must be sorted by input; index {idx} violates ordering"
+            ));
+        }
+    }
+
+    let input = input_or_default(inputs, "in");
+    if let [(_, y)] = points {
+        return single_output(outputs, unary_numeric(&input.value, |_| *y));
+    }
+    let config = prepare_piecewise_breakpoints(
+        points.iter().map(|(x, _)| *x).collect(),
+        points.iter().map(|(_, y)| *y).collect(),
+    )?;
+    single_output(
+        outputs,
+        unary_numeric(&input.value, |x| {
+            remap_piecewise_scalar(x, &config.inputs, &config.outputs, true, config.constant)
+        }),
+    )
+}
+
 struct PiecewiseConfig {
     inputs: Vec<f32>,
     outputs: Vec<f32>,
@@ -1200,15 +1608,12 @@ fn eval_vector_normalize(inputs: &InputSlots, outputs: &mut OutputSlots) -> Resu
 fn eval_vector_dot(inputs: &InputSlots, outputs: &mut OutputSlots) -> Result<(), String> {
     let a = input_or_default(inputs, "a");
     let b = input_or_default(inputs, "b");
-    match (flatten_numeric(&a.value), flatten_numeric(&b.value)) {
-        (Some(lhs), Some(rhs)) => match align_flattened(&lhs, &rhs) {
-            Ok((_, da, db)) => {
-                let sum = da.iter().zip(db.iter()).map(|(x, y)| x * y).sum::<f32>();
-                single_output(outputs, vocab::float(sum))
-            }
-            Err(_) => single_output(outputs, vocab::float(f32::NAN)),
-        },
-        _ => single_output(outputs, vocab::float(f32::NAN)),
+    match align_values(&a.value, &b.value) {
+        Ok((_, da, db)) => {
+            let sum = da.iter().zip(db.iter()).map(|(x, y)| x * y).sum::<f32>();
+            single_output(outputs, vocab::float(sum))
+        }
+        Err(_) => single_output(outputs, vocab::float(f32::NAN)),
     }
 }
 
@@ -1239,6 +1644,88 @@ fn eval_vector_index(inputs: &InputSlots, outputs: &mut OutputSlots) -> Result<(
     }
 }
 
+/// Apply `map` to the first three channels of a `ColorRgba`/`Vec4` input, leaving the fourth
+/// (alpha) untouched and preserving the input's kind.
+fn eval_color_map(
+    node: &str,
+    inputs: &InputSlots,
+    outputs: &mut OutputSlots,
+    map: fn([f32; 3]) -> [f32; 3],
+) -> Result<(), String> {
+    let input = input_or_default(inputs, "in");
+    // The output keeps the input's kind.
+    let rebuild: fn([f32; 4]) -> Value;
+    let channels = if let Some(c) = vocab::as_color_rgba(&input.value) {
+        rebuild = vocab::color_rgba;
+        c
+    } else if let Some(v) = vocab::as_vec4(&input.value) {
+        rebuild = vocab::vec4;
+        v
+    } else {
+        return Err(format!(
+            "{node} expects a ColorRgba or Vec4 input, got {:?}",
+            vocab::kind(&input.value)
+        ));
+    };
+    let [a, b, c] = map([channels[0], channels[1], channels[2]]);
+    single_output(outputs, rebuild([a, b, c, channels[3]]))
+}
+
+fn rgb_to_hsv([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let hue = if delta <= f32::EPSILON {
+        0.0
+    } else if max == r {
+        ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+    let saturation = if max <= f32::EPSILON {
+        0.0
+    } else {
+        delta / max
+    };
+    [hue / 6.0, saturation, max]
+}
+
+fn hsv_to_rgb([h, s, v]: [f32; 3]) -> [f32; 3] {
+    let h6 = h.rem_euclid(1.0) * 6.0;
+    let chroma = v * s;
+    let x = chroma * (1.0 - ((h6 % 2.0) - 1.0).abs());
+    let (r, g, b) = match h6 as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = v - chroma;
+    [r + m, g + m, b + m]
+}
+
+/// IEC 61966-2-1 sRGB decoding for one channel.
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// IEC 61966-2-1 sRGB encoding for one channel.
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
 fn eval_join(inputs: &InputSlots, outputs: &mut OutputSlots) -> Result<(), String> {
     let mut out: Vec<f32> = Vec::new();
     for port in collect_operand_ports(inputs) {
@@ -1260,6 +1747,25 @@ fn eval_split(
         .unwrap_or_default();
     let sizes = params.sizes.clone().unwrap_or_default();
     let sizes_usize: Vec<usize> = sizes.iter().map(|x| x.floor().max(0.0) as usize).collect();
+    if let Some(names) = &params.output_names {
+        if names.len() != sizes_usize.len() {
+            return Err(format!(
+                "Split output_names has {} entries but sizes has {}",
+                names.len(),
+                sizes_usize.len()
+            ));
+        }
+        if let Some((i, name)) = names
+            .iter()
+            .enumerate()
+            .find(|&(i, name)| names[..i].contains(name))
+        {
+            return Err(format!(
+                "Split output_names repeats '{}' at index {}",
+                name, i
+            ));
+        }
+    }
 
     let total: usize = sizes_usize.iter().sum();
 
@@ -1275,8 +1781,8 @@ fn eval_split(
         outputs.set_variadic("parts", i, PortValue::new(vocab::vector(value_vec)))?;
     }
 
-    // If no sizes provided, emit a single empty part.
-    if sizes_usize.is_empty() {
+    // If no sizes provided, emit a single empty part (there is no slot for it once named).
+    if sizes_usize.is_empty() && params.output_names.is_none() {
         outputs.set_variadic("parts", 0, PortValue::new(vocab::vector(Vec::new())))?;
     }
 
@@ -1306,6 +1812,7 @@ fn eval_from_vector(inputs: &InputSlots, outputs: &mut OutputSlots) -> Result<()
 }
 
 fn eval_noise(
+    global_seed: u64,
     kind: &NodeType,
     params: &NodeParams,
     inputs: &InputSlots,
@@ -1314,7 +1821,7 @@ fn eval_noise(
     let x = as_float(&input_or_default(inputs, "x").value);
     let y = as_float(&input_or_default(inputs, "y").value);
 
-    let seed = params.noise_seed.unwrap_or(0.0).floor() as i32;
+    let seed = noise::mix_seed(global_seed, params.noise_seed.unwrap_or(0.0).floor() as i32);
     let frequency = params.frequency.unwrap_or(1.0);
     let octaves = params.octaves.unwrap_or(1.0).floor().clamp(1.0, 16.0) as u32;
     let lacunarity = params.lacunarity.unwrap_or(2.0);
@@ -1605,6 +2112,38 @@ fn eval_weighted_sum_vector(inputs: &InputSlots, outputs: &mut OutputSlots) -> R
     )
 }
 
+/// Normalized weighted average of the variadic `value_*` inputs via
+/// [`vizij_api_core::blend::weighted_average`].
+///
+/// `value_i` takes `weights[i - 1]`, defaulting to 1.0 past the end of the list. When the weights
+/// sum to zero (or the values mix kinds) every component of the output is NaN.
+fn eval_weighted_sum(
+    params: &NodeParams,
+    inputs: &InputSlots,
+    outputs: &mut OutputSlots,
+) -> Result<(), String> {
+    let values = inputs.variadic("value");
+    let weights = params.weights.as_deref().unwrap_or(&[]);
+    // `value_i` takes `weights[i - 1]` whichever ports are connected.
+    let weighted: Vec<(Value, f32)> = inputs
+        .variadic_indexed("value")
+        .map(|(idx, port)| {
+            let weight = idx
+                .and_then(|i| i.checked_sub(1))
+                .and_then(|i| weights.get(i).copied());
+            (port.value.clone(), weight.unwrap_or(1.0))
+        })
+        .collect();
+    let out = match vizij_api_core::blend::weighted_average(&weighted) {
+        Some(avg) => avg,
+        None => match values.first() {
+            Some(first) => unary_numeric(&first.value, |_| f32::NAN),
+            None => vocab::float(f32::NAN),
+        },
+    };
+    single_output(outputs, out)
+}
+
 /// Blend: weighted average (non-overlay)
 ///
 /// Inputs:
@@ -1832,6 +2371,31 @@ fn eval_case(
     }
 }
 
+/// Routes `case_<label>` when the text selector equals one of `case_labels`; otherwise (unknown
+/// label, non-text selector, or unconnected case) falls back to `default`, else NaN.
+fn eval_case_route(
+    params: &NodeParams,
+    inputs: &InputSlots,
+    outputs: &mut OutputSlots,
+) -> Result<(), String> {
+    let selector = inputs
+        .get("selector")
+        .and_then(|port| vocab::as_text(&port.value));
+    let routed = selector
+        .filter(|label| {
+            params
+                .case_labels
+                .as_deref()
+                .is_some_and(|labels| labels.iter().any(|l| l == label))
+        })
+        .and_then(|label| inputs.get(format!("case_{label}").as_str()));
+    let value = match routed.or_else(|| inputs.get("default")) {
+        Some(port) => port.value.clone(),
+        None => vocab::float(f32::NAN),
+    };
+    single_output(outputs, value)
+}
+
 fn eval_inverse_kinematics(inputs: &InputSlots, outputs: &mut OutputSlots) -> Result<(), String> {
     let l1 = as_float(&input_or_default(inputs, "bone1").value);
     let l2 = as_float(&input_or_default(inputs, "bone2").value);
@@ -1903,7 +2467,94 @@ fn eval_urdf_fk(
 
     outputs.set_port("position", PortValue::new(vocab::vec3(pos_arr)))?;
     outputs.set_port("rotation", PortValue::new(vocab::quat(rot_arr)))?;
-    outputs.set_port("transform", PortValue::new(transform_value))
+    outputs.set_port("transform", PortValue::new(transform_value))?;
+    outputs.set_port("links", PortValue::new(link_transforms(state)))
+}
+
+#[cfg(feature = "urdf_ik")]
+/// Resolve the IK joint seed (the `seed` input, then the `seed` param, then the chain's current
+/// pose) and validate optional joint weights against the chain DoF.
+fn ik_seed_and_weights<'p>(
+    node: &str,
+    state: &UrdfKinematicsState,
+    params: &'p NodeParams,
+    inputs: &InputSlots,
+) -> Result<(Vec<f32>, Option<&'p [f32]>), String> {
+    let dofs = state.dofs;
+    let seed_candidate: Option<Vec<f32>> = inputs
+        .get("seed")
+        .map(|port| vector_from_value(&port.value, &format!("{node} seed")))
+        .transpose()?
+        .or_else(|| params.seed.clone());
+
+    let seed_provided = seed_candidate.is_some();
+    let mut seed = seed_candidate.unwrap_or_else(|| state.chain.joint_positions());
+
+    if seed.len() != dofs {
+        if seed_provided {
+            return Err(format!(
+                "{node} seed length {} does not match chain DoF {dofs}",
+                seed.len()
+            ));
+        }
+        seed = vec![0.0; dofs];
+    }
+
+    let weights = params.weights.as_deref().filter(|w| !w.is_empty());
+    if let Some(weights) = weights {
+        if weights.len() != dofs {
+            return Err(format!(
+                "{node} weights length {} does not match chain DoF {dofs}",
+                weights.len()
+            ));
+        }
+    }
+    Ok((seed, weights))
+}
+
+#[cfg(feature = "urdf_ik")]
+/// Publish solver iteration count and residual error alongside an IK solution.
+fn set_ik_diagnostics(
+    outputs: &mut OutputSlots,
+    solution: &IkSolution,
+    with_rotation: bool,
+) -> Result<(), String> {
+    outputs.set_port(
+        "iterations",
+        PortValue::new(vocab::float(solution.iterations as f32)),
+    )?;
+    outputs.set_port(
+        "residual",
+        PortValue::new(vocab::float(solution.residual_pos)),
+    )?;
+    if with_rotation {
+        outputs.set_port(
+            "residual_rot",
+            PortValue::new(vocab::float(solution.residual_rot)),
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "urdf_ik")]
+/// Publish the `converged` flag, or fail the node when the solver missed the target and
+/// `fail_soft` is not set.
+fn set_ik_convergence(
+    node: &str,
+    params: &NodeParams,
+    outputs: &mut OutputSlots,
+    solution: &IkSolution,
+) -> Result<(), String> {
+    if !solution.converged && !params.fail_soft.unwrap_or(false) {
+        return Err(format!(
+            "{node} IK solve failed: not converged after {} iterations (residual {:.4} m, {:.4} rad)",
+            solution.iterations, solution.residual_pos, solution.residual_rot
+        ));
+    }
+    outputs.set_port(
+        "converged",
+        PortValue::new(vocab::bool_(solution.converged)),
+    )
 }
 
 #[cfg(feature = "urdf_ik")]
@@ -1938,51 +2589,38 @@ fn eval_urdf_position(
         root_link,
         tip_link,
     };
+    let solver_kind = parse_ik_solver("UrdfIkPosition", params.solver.as_deref())?;
     let state = rt.kinematics_state_mut(&spec.id, key)?;
-    let dofs = state.dofs;
-    let mut solver = k::JacobianIkSolver::default();
 
-    let seed_candidate: Option<Vec<f32>> = inputs
-        .get("seed")
-        .map(|port| vector_from_value(&port.value, "UrdfIkPosition seed"))
-        .transpose()?
-        .or_else(|| params.seed.clone());
-
-    let seed_provided = seed_candidate.is_some();
-    let mut seed = seed_candidate.unwrap_or_else(|| state.chain.joint_positions());
-
-    if seed.len() != dofs {
-        if seed_provided {
-            return Err(format!(
-                "UrdfIkPosition seed length {} does not match chain DoF {dofs}",
-                seed.len()
-            ));
-        }
-        seed = vec![0.0; dofs];
-    }
-
-    let weights_ref = params.weights.as_ref().filter(|w| !w.is_empty());
-    if let Some(weights) = weights_ref {
-        if weights.len() != dofs {
-            return Err(format!(
-                "UrdfIkPosition weights length {} does not match chain DoF {dofs}",
-                weights.len()
-            ));
+    let (seed, weights) = ik_seed_and_weights("UrdfIkPosition", state, params, inputs)?;
+
+    let max_iters = params.max_iters.unwrap_or(100);
+    let tol_pos = params.tol_pos.unwrap_or(1e-3);
+    let solution = match solver_kind {
+        IkSolverKind::Jacobian => solve_position(
+            state,
+            &mut k::JacobianIkSolver::default(),
+            target_pos,
+            seed.as_slice(),
+            weights,
+            max_iters,
+            tol_pos,
+        )?,
+        IkSolverKind::Ccd if weights.is_some() => {
+            return Err("UrdfIkPosition solver \"ccd\" does not support joint weights".to_string());
+        }
+        IkSolverKind::Ccd => {
+            solve_position_ccd(state, target_pos, seed.as_slice(), max_iters, tol_pos)?
         }
-    }
-    let weights = weights_ref.map(|w| w.as_slice());
+    };
 
-    let solution = solve_position(
-        state,
-        &mut solver,
-        target_pos,
-        seed.as_slice(),
-        weights,
-        params.max_iters.unwrap_or(100),
-        params.tol_pos.unwrap_or(1e-3),
+    set_ik_convergence("UrdfIkPosition", params, outputs, &solution)?;
+    set_ik_diagnostics(outputs, &solution, false)?;
+    outputs.set_port(
+        "at_limit",
+        PortValue::new(state.limit_record(&solution.joints)),
     )?;
-
-    single_output(outputs, state.solution_record(&solution))
+    single_output(outputs, state.solution_record(&solution.joints))
 }
 
 #[cfg(feature = "urdf_ik")]
@@ -2021,53 +2659,85 @@ fn eval_urdf_pose(
         root_link,
         tip_link,
     };
+    if parse_ik_solver("UrdfIkPose", params.solver.as_deref())? != IkSolverKind::Jacobian {
+        return Err("UrdfIkPose supports only the \"jacobian\" solver".to_string());
+    }
     let state = rt.kinematics_state_mut(&spec.id, key)?;
-    let dofs = state.dofs;
     let mut solver = k::JacobianIkSolver::default();
 
-    let seed_candidate: Option<Vec<f32>> = inputs
-        .get("seed")
-        .map(|port| vector_from_value(&port.value, "UrdfIkPose seed"))
-        .transpose()?
-        .or_else(|| params.seed.clone());
+    let (seed, weights) = ik_seed_and_weights("UrdfIkPose", state, params, inputs)?;
 
-    let seed_provided = seed_candidate.is_some();
-    let mut seed = seed_candidate.unwrap_or_else(|| state.chain.joint_positions());
+    let solution = solve_pose(
+        state,
+        &mut solver,
+        target_pos,
+        target_rot,
+        seed.as_slice(),
+        weights,
+        params.max_iters.unwrap_or(100),
+        params.tol_pos.unwrap_or(1e-3),
+        params.tol_rot.unwrap_or(1e-3),
+    )?;
 
-    if seed.len() != dofs {
-        if seed_provided {
-            return Err(format!(
-                "UrdfIkPose seed length {} does not match chain DoF {dofs}",
-                seed.len()
-            ));
-        }
-        seed = vec![0.0; dofs];
-    }
+    set_ik_convergence("UrdfIkPose", params, outputs, &solution)?;
+    set_ik_diagnostics(outputs, &solution, true)?;
+    outputs.set_port(
+        "at_limit",
+        PortValue::new(state.limit_record(&solution.joints)),
+    )?;
+    single_output(outputs, state.solution_record(&solution.joints))
+}
 
-    let weights_ref = params.weights.as_ref().filter(|w| !w.is_empty());
-    if let Some(weights) = weights_ref {
-        if weights.len() != dofs {
-            return Err(format!(
-                "UrdfIkPose weights length {} does not match chain DoF {dofs}",
-                weights.len()
-            ));
-        }
+#[cfg(feature = "urdf_ik")]
+fn eval_urdf_orientation(
+    rt: &mut GraphRuntime,
+    spec: &NodeSpec,
+    params: &NodeParams,
+    inputs: &InputSlots,
+    outputs: &mut OutputSlots,
+) -> Result<(), String> {
+    let target_rot = {
+        let port = input_or_default(inputs, "target_rot");
+        quat_from_value(&port.value, "UrdfIkOrientation target_rot")?
+    };
+
+    let urdf_xml = params
+        .urdf_xml
+        .as_deref()
+        .filter(|s| !s.trim().is_empty())
+        .ok_or_else(|| "UrdfIkOrientation requires non-empty 'urdf_xml' param".to_string())?;
+    let root_link = params.root_link.as_deref().unwrap_or("base_link");
+    let tip_link = params.tip_link.as_deref().unwrap_or("tool0");
+
+    let key = IkKey {
+        hash: hash_urdf_config(urdf_xml, root_link, tip_link),
+        urdf_xml,
+        root_link,
+        tip_link,
+    };
+    if parse_ik_solver("UrdfIkOrientation", params.solver.as_deref())? != IkSolverKind::Jacobian {
+        return Err("UrdfIkOrientation supports only the \"jacobian\" solver".to_string());
     }
-    let weights = weights_ref.map(|w| w.as_slice());
+    let state = rt.kinematics_state_mut(&spec.id, key)?;
+    let mut solver = k::JacobianIkSolver::default();
+    let (seed, weights) = ik_seed_and_weights("UrdfIkOrientation", state, params, inputs)?;
 
-    let solution = solve_pose(
+    let solution = solve_orientation(
         state,
         &mut solver,
-        target_pos,
         target_rot,
         seed.as_slice(),
         weights,
         params.max_iters.unwrap_or(100),
-        params.tol_pos.unwrap_or(1e-3),
         params.tol_rot.unwrap_or(1e-3),
     )?;
 
-    single_output(outputs, state.solution_record(&solution))
+    set_ik_convergence("UrdfIkOrientation", params, outputs, &solution)?;
+    outputs.set_port(
+        "at_limit",
+        PortValue::new(state.limit_record(&solution.joints)),
+    )?;
+    single_output(outputs, state.solution_record(&solution.joints))
 }
 
 fn eval_output(inputs: &InputSlots, outputs: &mut OutputSlots) -> Result<(), String> {
@@ -2187,6 +2857,67 @@ fn eval_split_record(
     Ok(())
 }
 
+fn eval_list_length(inputs: &InputSlots, outputs: &mut OutputSlots) -> Result<(), String> {
+    let list = input_or_default(inputs, "list");
+    let len = match vocab::as_array(&list.value) {
+        Some(items) => items.len(),
+        None => flatten_numeric(&list.value).map_or(0, |flat| flat.data.len()),
+    };
+    single_output(outputs, vocab::float(len as f32))
+}
+
+/// Reads `list[floor(index)]`, preferring the `index` input over the param. Out-of-range indices
+/// and non-list inputs yield NaN.
+fn eval_list_get(
+    params: &NodeParams,
+    inputs: &InputSlots,
+    outputs: &mut OutputSlots,
+) -> Result<(), String> {
+    let list = input_or_default(inputs, "list");
+    let index = inputs
+        .get("index")
+        .map(|pv| as_float(&pv.value))
+        .or(params.index)
+        .unwrap_or(0.0)
+        .floor();
+    let element = if index.is_finite() && index >= 0.0 {
+        let i = index as usize;
+        match vocab::as_array(&list.value) {
+            Some(items) => items.get(i).cloned(),
+            None => flatten_numeric(&list.value)
+                .and_then(|flat| flat.data.get(i).copied())
+                .map(vocab::float),
+        }
+    } else {
+        None
+    };
+    single_output(outputs, element.unwrap_or_else(|| vocab::float(f32::NAN)))
+}
+
+/// Upper bound on [`NodeParams::precision`], keeping formatted labels bounded.
+const MAX_TEXT_PRECISION: u32 = 16;
+
+fn eval_number_to_text(
+    params: &NodeParams,
+    inputs: &InputSlots,
+    outputs: &mut OutputSlots,
+) -> Result<(), String> {
+    let x = as_float(&input_or_default(inputs, "in").value);
+    let text = match params.precision {
+        Some(precision) => format!("{:.*}", precision.min(MAX_TEXT_PRECISION) as usize, x),
+        None => x.to_string(),
+    };
+    single_output(outputs, vocab::text(&text))
+}
+
+fn eval_text_to_number(inputs: &InputSlots, outputs: &mut OutputSlots) -> Result<(), String> {
+    let input = input_or_default(inputs, "in");
+    let parsed = vocab::as_text(&input.value)
+        .and_then(|text| text.trim().parse::<f32>().ok())
+        .unwrap_or(f32::NAN);
+    single_output(outputs, vocab::float(parsed))
+}
+
 fn eval_math_record(
     inputs: &InputSlots,
     outputs: &mut OutputSlots,
@@ -2340,12 +3071,7 @@ fn enforce_output_shapes_slots(
             )
         })?;
 
-        if !value_matches_shape(&declared.id, &port.value) {
-            return Err(format!(
-                "node '{}' output '{}' does not match declared shape {:?}",
-                spec.id, key, declared.id
-            ));
-        }
+        check_declared_shape(spec, key, &declared.id, &port.value)?;
 
         port.shape = declared.clone();
     }
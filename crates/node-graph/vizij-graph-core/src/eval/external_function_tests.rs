@@ -32,6 +32,7 @@ fn constant_node(id: &str, value: Value) -> NodeSpec {
         },
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     }
 }
 
@@ -63,6 +64,7 @@ fn external_function_graph(function_id: Uuid, param_id: Uuid) -> GraphSpec {
                 },
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         // The variadic "args" group is addressed as args_0, args_1, ...
@@ -128,6 +130,7 @@ fn external_function_missing_id_errors() {
             params: NodeParams::default(),
             output_shapes: HashMap::new(),
             input_defaults: HashMap::new(),
+            metadata: serde_json::Value::Null,
         }],
         edges: vec![],
         ..Default::default()
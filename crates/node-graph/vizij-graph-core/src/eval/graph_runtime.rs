@@ -2,14 +2,16 @@
 
 use crate::types::NodeId;
 use hashbrown::{hash_map::Entry, HashMap};
-use vizij_api_core::{Shape, TypedPath, Value, WriteBatch};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use vizij_api_core::{Shape, ShapeId, TypedPath, Value, WriteBatch};
 
 use super::plan::PlanCache;
-use super::urdfik::{build_chain_from_urdf, IkKey, UrdfKinematicsState};
+use super::urdfik::{IkKey, UrdfCache, UrdfKinematicsState};
 use super::value_layout::{FlatValue, PortValue, ValueLayout};
 
 /// Internal integration state for a spring node. Values remain flattened for efficiency.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SpringState {
     /// Layout used to reconstruct structured values from the flattened buffers.
     pub layout: ValueLayout,
@@ -44,7 +46,7 @@ impl SpringState {
 }
 
 /// Integration state for a damp node.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DampState {
     /// Layout used to reconstruct structured values from the flattened buffers.
     pub layout: ValueLayout,
@@ -69,7 +71,7 @@ impl DampState {
 }
 
 /// Integration state for a slew node.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SlewState {
     /// Layout used to reconstruct structured values from the flattened buffers.
     pub layout: ValueLayout,
@@ -93,16 +95,146 @@ impl SlewState {
     }
 }
 
+/// Previous-frame state for an edge-detect node.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct EdgeState {
+    /// Boolean observed on the previous evaluation, or `None` before the first frame.
+    pub previous: Option<bool>,
+}
+
+/// Latched value for a sample-and-hold node.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SampleHoldState {
+    /// Most recently sampled input, preserved with its full layout.
+    pub held: Value,
+}
+
+/// Ring buffer of recent inputs for a delay node.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DelayState {
+    /// Shape of the buffered values; a different incoming shape restarts the history.
+    pub shape: ShapeId,
+    /// Buffered inputs, oldest first.
+    pub history: VecDeque<Value>,
+}
+
+impl DelayState {
+    /// Create an empty history for values of `shape`.
+    fn new(shape: &ShapeId) -> Self {
+        DelayState {
+            shape: shape.clone(),
+            history: VecDeque::new(),
+        }
+    }
+}
+
+/// Value recorded by a feedback source, double-buffered by input epoch so taps observe the
+/// previous frame's value regardless of where they are scheduled relative to the source.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct FeedbackState {
+    /// Input epoch in which [`Self::latest`] was recorded.
+    pub epoch: u64,
+    /// Most recently recorded value.
+    pub latest: Option<Value>,
+    /// Value recorded in the frame before [`Self::epoch`].
+    pub previous: Option<Value>,
+}
+
+impl FeedbackState {
+    /// Record `value` for `epoch`, rotating the last frame's value into [`Self::previous`].
+    pub fn record(&mut self, epoch: u64, value: Value) {
+        if self.epoch != epoch {
+            self.previous = self.latest.take();
+            self.epoch = epoch;
+        }
+        self.latest = Some(value);
+    }
+
+    /// Value recorded before `epoch`, i.e. during the previous frame.
+    pub fn previous_frame(&self, epoch: u64) -> Option<&Value> {
+        if self.epoch == epoch {
+            self.previous.as_ref()
+        } else {
+            self.latest.as_ref()
+        }
+    }
+}
+
+/// Recent flattened samples for a moving-average node.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MovingAverageState {
+    /// Layout of the buffered samples; a different incoming layout restarts the window.
+    pub layout: ValueLayout,
+    /// Flattened samples, oldest first.
+    pub samples: VecDeque<Vec<f32>>,
+}
+
+impl MovingAverageState {
+    /// Create an empty window for values laid out like `flat`.
+    fn new(flat: &FlatValue) -> Self {
+        MovingAverageState {
+            layout: flat.layout.clone(),
+            samples: VecDeque::new(),
+        }
+    }
+}
+
 /// State stored for each node that requires persistence across frames.
-#[derive(Debug)]
+///
+/// Serializes externally tagged by variant (e.g. `{"spring": {...}}`) for
+/// [`GraphRuntime::export_state`]. Subgraph runtimes are exported through their own
+/// `export_state` as `{"subgraph": {...}}`; URDF solver state holds caches that are rebuilt
+/// lazily, so it is skipped.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum NodeRuntimeState {
     Spring(SpringState),
     Damp(DampState),
+    /// Frame-based EMA; tracks the same layout and smoothed value as a damp node.
+    Ema(DampState),
     Slew(SlewState),
+    Edge(EdgeState),
+    SampleHold(SampleHoldState),
+    Delay(DelayState),
+    MovingAverage(MovingAverageState),
+    Feedback(FeedbackState),
+    /// Child runtime of a subgraph node; owns the nested graph's plan and node state.
+    #[serde(skip)]
+    Subgraph(Box<GraphRuntime>),
     #[cfg(feature = "urdf_ik")]
+    #[serde(skip)]
     UrdfKinematics(UrdfKinematicsState),
 }
 
+impl NodeRuntimeState {
+    /// Snapshot form of this state for [`GraphRuntime::export_state`]; `None` for state that is
+    /// rebuilt on the next evaluation instead.
+    fn export(&self) -> Option<serde_json::Value> {
+        match self {
+            NodeRuntimeState::Subgraph(child) => {
+                Some(serde_json::json!({ "subgraph": child.export_state() }))
+            }
+            #[cfg(feature = "urdf_ik")]
+            NodeRuntimeState::UrdfKinematics(_) => None,
+            _ => serde_json::to_value(self).ok(),
+        }
+    }
+
+    /// Inverse of [`Self::export`].
+    fn import(json: serde_json::Value) -> Result<Self, String> {
+        match json {
+            serde_json::Value::Object(mut map)
+                if map.len() == 1 && map.contains_key("subgraph") =>
+            {
+                let mut child = GraphRuntime::default();
+                child.import_state(map.remove("subgraph").unwrap_or_default())?;
+                Ok(NodeRuntimeState::Subgraph(Box::new(child)))
+            }
+            json => NodeRuntimeState::deserialize(json).map_err(|e| e.to_string()),
+        }
+    }
+}
+
 /// Data staged by the host for consumption by [`NodeType::Input`](crate::types::NodeType::Input).
 #[derive(Debug, Clone)]
 pub struct StagedInput {
@@ -135,9 +267,23 @@ pub struct GraphRuntime {
     pub input_epoch: u64,
     /// Cached execution plan and node-index lookup derived from the current spec.
     pub plan: PlanCache,
+    /// URDF kinematic trees shared across kinematics nodes.
+    #[cfg(feature = "urdf_ik")]
+    pub urdf_cache: UrdfCache,
+    /// Global seed mixed into every stochastic node's own seed; `0` (the default) leaves node
+    /// seeds unchanged.
+    pub seed: u64,
 }
 
 impl GraphRuntime {
+    /// Create an empty runtime whose stochastic nodes are reproducible under `seed`.
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            ..Default::default()
+        }
+    }
+
     /// Reset runtime state for a new spec, clearing plan and per-node caches.
     ///
     /// Call this when a structural graph change invalidates cached layouts, bindings, or node
@@ -146,10 +292,59 @@ impl GraphRuntime {
         self.plan = PlanCache::default();
         self.outputs.clear();
         self.outputs_vec.clear();
-        self.writes.0.clear();
+        self.writes.clear();
         self.node_states.clear();
         self.staged_inputs.clear();
         self.input_epoch = 0;
+        #[cfg(feature = "urdf_ik")]
+        self.urdf_cache.clear();
+    }
+
+    /// Clear evaluation results (outputs and writes) while keeping time, node state, staged
+    /// inputs, and the cached plan.
+    ///
+    /// Use this between independent evaluations of the same spec, e.g. discarding a preview
+    /// before the committed frame, so stale outputs are not read back without disturbing
+    /// springs, filters, or other stateful nodes. Use [`Self::reset_for_spec`] when the graph
+    /// itself changed.
+    pub fn clear_results(&mut self) {
+        self.outputs.clear();
+        self.outputs_vec.clear();
+        self.writes.clear();
+    }
+
+    /// Snapshot per-node state (springs, filters, delays, ...) as a JSON object keyed by node id,
+    /// e.g. to save a live simulation.
+    ///
+    /// Subgraph nodes nest their child runtime's snapshot. URDF solver state is omitted; those
+    /// nodes rebuild it on their next evaluation. Time, outputs, and staged inputs are not
+    /// included.
+    pub fn export_state(&self) -> serde_json::Value {
+        let mut states = serde_json::Map::new();
+        for (id, state) in &self.node_states {
+            if let Some(json) = state.export() {
+                states.insert(id.clone(), json);
+            }
+        }
+        serde_json::Value::Object(states)
+    }
+
+    /// Replace per-node state with a snapshot produced by [`Self::export_state`].
+    ///
+    /// Existing node state is dropped first, so nodes missing from the snapshot start fresh.
+    /// The runtime is left untouched when the snapshot is malformed.
+    pub fn import_state(&mut self, value: serde_json::Value) -> Result<(), String> {
+        let serde_json::Value::Object(entries) = value else {
+            return Err("node state snapshot must be a JSON object keyed by node id".to_string());
+        };
+        let mut states = HashMap::with_capacity(entries.len());
+        for (id, json) in entries {
+            let state = NodeRuntimeState::import(json)
+                .map_err(|e| format!("node '{}' state: {}", id, e))?;
+            states.insert(id, state);
+        }
+        self.node_states = states;
+        Ok(())
     }
 
     /// Advance the staging epoch. Values staged for `epoch + 1` become visible for the
@@ -273,6 +468,41 @@ impl GraphRuntime {
         }
     }
 
+    /// Fetch the EMA state for `node_id`, creating or reinitialising it from `flat` as needed.
+    pub fn ema_state_mut<'a>(
+        &'a mut self,
+        node_id: &NodeId,
+        flat: &FlatValue,
+    ) -> &'a mut DampState {
+        match self.node_states.entry(node_id.clone()) {
+            Entry::Occupied(mut occupied) => {
+                {
+                    let state = occupied.get_mut();
+                    match state {
+                        NodeRuntimeState::Ema(inner) => {
+                            if inner.layout != flat.layout {
+                                inner.reset(flat);
+                            }
+                        }
+                        _ => {
+                            *state = NodeRuntimeState::Ema(DampState::new(flat));
+                        }
+                    }
+                }
+                match occupied.into_mut() {
+                    NodeRuntimeState::Ema(inner) => inner,
+                    _ => unreachable!(),
+                }
+            }
+            Entry::Vacant(vacant) => {
+                match vacant.insert(NodeRuntimeState::Ema(DampState::new(flat))) {
+                    NodeRuntimeState::Ema(inner) => inner,
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+
     /// Fetch the slew state for `node_id`, creating or reinitialising it from `flat` as needed.
     pub fn slew_state_mut<'a>(
         &'a mut self,
@@ -308,6 +538,165 @@ impl GraphRuntime {
         }
     }
 
+    /// Fetch the edge-detect state for `node_id`, creating an empty one as needed.
+    pub fn edge_state_mut<'a>(&'a mut self, node_id: &NodeId) -> &'a mut EdgeState {
+        match self.node_states.entry(node_id.clone()) {
+            Entry::Occupied(occupied) => {
+                let state = occupied.into_mut();
+                if !matches!(state, NodeRuntimeState::Edge(_)) {
+                    *state = NodeRuntimeState::Edge(EdgeState::default());
+                }
+                match state {
+                    NodeRuntimeState::Edge(inner) => inner,
+                    _ => unreachable!(),
+                }
+            }
+            Entry::Vacant(vacant) => {
+                match vacant.insert(NodeRuntimeState::Edge(EdgeState::default())) {
+                    NodeRuntimeState::Edge(inner) => inner,
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+
+    /// Fetch the sample-and-hold state for `node_id`, seeding it with `initial` when it is
+    /// created.
+    pub fn sample_hold_state_mut<'a>(
+        &'a mut self,
+        node_id: &NodeId,
+        initial: &Value,
+    ) -> &'a mut SampleHoldState {
+        let fresh = || {
+            NodeRuntimeState::SampleHold(SampleHoldState {
+                held: initial.clone(),
+            })
+        };
+        match self.node_states.entry(node_id.clone()) {
+            Entry::Occupied(occupied) => {
+                let state = occupied.into_mut();
+                if !matches!(state, NodeRuntimeState::SampleHold(_)) {
+                    *state = fresh();
+                }
+                match state {
+                    NodeRuntimeState::SampleHold(inner) => inner,
+                    _ => unreachable!(),
+                }
+            }
+            Entry::Vacant(vacant) => match vacant.insert(fresh()) {
+                NodeRuntimeState::SampleHold(inner) => inner,
+                _ => unreachable!(),
+            },
+        }
+    }
+
+    /// Fetch the delay state for `node_id`, restarting the history when `shape` changes.
+    pub fn delay_state_mut<'a>(
+        &'a mut self,
+        node_id: &NodeId,
+        shape: &ShapeId,
+    ) -> &'a mut DelayState {
+        match self.node_states.entry(node_id.clone()) {
+            Entry::Occupied(occupied) => {
+                let state = occupied.into_mut();
+                match state {
+                    NodeRuntimeState::Delay(inner) => {
+                        if inner.shape != *shape {
+                            *inner = DelayState::new(shape);
+                        }
+                    }
+                    _ => {
+                        *state = NodeRuntimeState::Delay(DelayState::new(shape));
+                    }
+                }
+                match state {
+                    NodeRuntimeState::Delay(inner) => inner,
+                    _ => unreachable!(),
+                }
+            }
+            Entry::Vacant(vacant) => {
+                match vacant.insert(NodeRuntimeState::Delay(DelayState::new(shape))) {
+                    NodeRuntimeState::Delay(inner) => inner,
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+
+    /// Fetch the moving-average state for `node_id`, restarting the window when the layout of
+    /// `flat` changes.
+    pub fn moving_average_state_mut<'a>(
+        &'a mut self,
+        node_id: &NodeId,
+        flat: &FlatValue,
+    ) -> &'a mut MovingAverageState {
+        match self.node_states.entry(node_id.clone()) {
+            Entry::Occupied(occupied) => {
+                let state = occupied.into_mut();
+                match state {
+                    NodeRuntimeState::MovingAverage(inner) => {
+                        if inner.layout != flat.layout {
+                            *inner = MovingAverageState::new(flat);
+                        }
+                    }
+                    _ => {
+                        *state = NodeRuntimeState::MovingAverage(MovingAverageState::new(flat));
+                    }
+                }
+                match state {
+                    NodeRuntimeState::MovingAverage(inner) => inner,
+                    _ => unreachable!(),
+                }
+            }
+            Entry::Vacant(vacant) => {
+                match vacant.insert(NodeRuntimeState::MovingAverage(MovingAverageState::new(
+                    flat,
+                ))) {
+                    NodeRuntimeState::MovingAverage(inner) => inner,
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+
+    /// Fetch the feedback state for source `node_id`, creating an empty one on first use.
+    pub fn feedback_state_mut<'a>(&'a mut self, node_id: &NodeId) -> &'a mut FeedbackState {
+        let state = self
+            .node_states
+            .entry(node_id.clone())
+            .or_insert_with(|| NodeRuntimeState::Feedback(FeedbackState::default()));
+        if !matches!(state, NodeRuntimeState::Feedback(_)) {
+            *state = NodeRuntimeState::Feedback(FeedbackState::default());
+        }
+        match state {
+            NodeRuntimeState::Feedback(inner) => inner,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Read the feedback state recorded by source `node_id`, if it has run.
+    pub fn feedback_state(&self, node_id: &NodeId) -> Option<&FeedbackState> {
+        match self.node_states.get(node_id) {
+            Some(NodeRuntimeState::Feedback(inner)) => Some(inner),
+            _ => None,
+        }
+    }
+
+    /// Fetch the child runtime for subgraph `node_id`, creating an empty one on first use.
+    pub fn subgraph_runtime_mut<'a>(&'a mut self, node_id: &NodeId) -> &'a mut GraphRuntime {
+        let state = self
+            .node_states
+            .entry(node_id.clone())
+            .or_insert_with(|| NodeRuntimeState::Subgraph(Box::default()));
+        if !matches!(state, NodeRuntimeState::Subgraph(_)) {
+            *state = NodeRuntimeState::Subgraph(Box::default());
+        }
+        match state {
+            NodeRuntimeState::Subgraph(inner) => inner,
+            _ => unreachable!(),
+        }
+    }
+
     #[cfg(feature = "urdf_ik")]
     /// Fetch the cached URDF chain for `node_id`, rebuilding it if the configuration hash
     /// changes.
@@ -316,9 +705,9 @@ impl GraphRuntime {
         node_id: &NodeId,
         key: IkKey<'_>,
     ) -> Result<&'a mut UrdfKinematicsState, String> {
-        let build_state = || -> Result<UrdfKinematicsState, String> {
-            let (chain, joint_names) =
-                build_chain_from_urdf(key.urdf_xml, key.root_link, key.tip_link)?;
+        let urdf_cache = &mut self.urdf_cache;
+        let mut build_state = || -> Result<UrdfKinematicsState, String> {
+            let (chain, joint_names) = urdf_cache.serial_chain(&key)?;
             Ok(UrdfKinematicsState::new(key.hash, chain, joint_names))
         };
 
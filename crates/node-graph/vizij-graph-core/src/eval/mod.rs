@@ -10,6 +10,7 @@
 //! - `numeric` and `variadic` provide shared math helpers.
 //! - [`mod@eval_node`] houses the dispatch logic for individual [`NodeType`](crate::types::NodeType)s.
 //! - `urdfik` is gated behind the `urdf_ik` feature and packages the IK solver helpers.
+//! - `type_check` propagates shapes statically for [`evaluate_types_only`].
 //!
 //! Integration code should primarily interact with [`GraphRuntime`] and [`evaluate_all`].
 
@@ -23,6 +24,7 @@ mod noise;
 mod numeric;
 mod plan;
 mod shape_helpers;
+mod type_check;
 mod urdfik;
 mod value_layout;
 mod variadic;
@@ -31,6 +33,8 @@ pub use eval_node::eval_node;
 pub use graph_runtime::{GraphRuntime, StagedInput};
 pub use node_function::{NodeFunction, NodeFunctionRegistry, NodeFunctions};
 pub use plan::{fingerprint_spec, PlanCache};
+pub use shape_helpers::infer_shape;
+pub use type_check::evaluate_types_only;
 pub use value_layout::PortValue;
 
 #[cfg(test)]
@@ -39,6 +43,8 @@ mod blend_tests;
 mod external_function_tests;
 #[cfg(test)]
 mod tests;
+#[cfg(all(test, feature = "urdf_ik"))]
+mod urdf_tests;
 
 /// Evaluate every node in `spec`, updating `rt` in-place.
 ///
@@ -48,7 +54,24 @@ mod tests;
 /// This path has no [`NodeFunctions`] host, so any `ExternalFunction` node in `spec` errors.
 /// Use [`evaluate_all_with_functions`] to run graphs that invoke node-functions.
 pub fn evaluate_all(rt: &mut GraphRuntime, spec: &GraphSpec) -> Result<(), String> {
-    evaluate_all_inner(rt, spec, None)
+    evaluate_all_inner(rt, spec, None, None)
+}
+
+/// Evaluate like [`evaluate_all`], but refuse graphs that schedule more than `max_nodes` nodes.
+///
+/// A safety valve for hosts that cannot afford an unbounded frame (e.g. a single-threaded wasm
+/// context): the budget is checked against the execution plan before any node runs, so an
+/// oversized graph fails with a budget-exceeded error and leaves the previous outputs intact.
+///
+/// Nodes inside `Subgraph` nodes count too. Each nested graph is checked against what is left of
+/// the budget when its `Subgraph` node runs, so a nested overrun fails that node mid-frame.
+pub fn evaluate_all_limited(
+    rt: &mut GraphRuntime,
+    spec: &GraphSpec,
+    max_nodes: usize,
+) -> Result<(), String> {
+    let mut remaining = max_nodes;
+    evaluate_all_inner(rt, spec, None, Some(&mut remaining))
 }
 
 /// Evaluate every node in `spec`, threading `functions` through to any `ExternalFunction` nodes.
@@ -60,23 +83,35 @@ pub fn evaluate_all_with_functions(
     spec: &GraphSpec,
     functions: &mut dyn NodeFunctions,
 ) -> Result<(), String> {
-    evaluate_all_inner(rt, spec, Some(functions))
+    evaluate_all_inner(rt, spec, Some(functions), None)
 }
 
+/// Evaluate `spec` on `rt`. With a `budget`, the graph's scheduled nodes are taken from the
+/// remaining node count up front, and nested subgraphs draw on what is left.
 fn evaluate_all_inner(
     rt: &mut GraphRuntime,
     spec: &GraphSpec,
     mut functions: Option<&mut dyn NodeFunctions>,
+    mut budget: Option<&mut usize>,
 ) -> Result<(), String> {
     if spec.version > 0 {
         rt.plan.ensure_versioned(spec)?;
     } else {
         rt.plan.ensure(spec)?;
     }
+    if let Some(remaining) = budget.as_deref_mut() {
+        let scheduled = rt.plan.order.len();
+        if scheduled > *remaining {
+            return Err(format!(
+                "evaluation budget exceeded: graph schedules {scheduled} nodes, limit is {remaining}"
+            ));
+        }
+        *remaining -= scheduled;
+    }
     rt.advance_epoch();
     rt.outputs.clear();
     rt.outputs.reserve(spec.nodes.len());
-    rt.writes.0.clear();
+    rt.writes.clear();
     rt.node_states
         .retain(|id, _| spec.nodes.iter().any(|node| node.id == *id));
 
@@ -111,7 +146,14 @@ fn evaluate_all_inner(
                     Some(ref mut f) => Some(&mut **f),
                     None => None,
                 };
-                eval_node::eval_node_inner(rt, node, &inputs, &mut outputs, functions_ref)?;
+                eval_node::eval_node_budgeted(
+                    rt,
+                    node,
+                    &inputs,
+                    &mut outputs,
+                    functions_ref,
+                    budget.as_deref_mut(),
+                )?;
             }
 
             let compat = eval_node::materialize_outputs(&plan.layouts[idx].outputs, &vec_out);
@@ -156,7 +198,7 @@ pub fn evaluate_all_cached(rt: &mut GraphRuntime, spec: &GraphSpec) -> Result<()
     rt.advance_epoch();
     rt.outputs.clear();
     rt.outputs.reserve(spec.nodes.len());
-    rt.writes.0.clear();
+    rt.writes.clear();
     rt.node_states
         .retain(|id, _| spec.nodes.iter().any(|node| node.id == *id));
 
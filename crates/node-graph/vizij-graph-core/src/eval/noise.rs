@@ -10,6 +10,22 @@ fn hash2d(ix: i32, iy: i32, seed: i32) -> u32 {
     h ^ (h >> 16)
 }
 
+/// Mix a runtime-wide seed into a node's own seed.
+///
+/// A global seed of `0` leaves the node seed unchanged, so graphs evaluated
+/// without a runtime seed keep their historical output.
+pub fn mix_seed(global: u64, local: i32) -> i32 {
+    if global == 0 {
+        return local;
+    }
+    // SplitMix64 finalizer: nearby global seeds land far apart.
+    let mut z = global.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    local ^ (z as i32)
+}
+
 /// Map hash to float in [0, 1).
 fn hash_to_unit(h: u32) -> f32 {
     (h & 0x00FF_FFFF) as f32 / 16777216.0
@@ -1,27 +1,21 @@
 //! Numeric helper utilities shared across node evaluators.
 
 use vizij_api_core::value as vocab;
-use vizij_api_core::value::VizijKind;
 use vizij_api_core::{coercion, Value};
 
-use super::value_layout::{align_flattened, flatten_numeric};
+use super::value_layout::{align_values, flatten_numeric};
 
 /// Apply `op` pairwise to two numeric values, broadcasting scalars when possible.
 pub fn binary_numeric<F>(lhs: &Value, rhs: &Value, op: F) -> Value
 where
     F: Fn(f32, f32) -> f32 + Copy,
 {
-    match (flatten_numeric(lhs), flatten_numeric(rhs)) {
-        (Some(a), Some(b)) => match align_flattened(&a, &b) {
-            Ok((layout, da, db)) => {
-                let data: Vec<f32> = da.iter().zip(db.iter()).map(|(x, y)| op(*x, *y)).collect();
-                layout.reconstruct(&data)
-            }
-            Err(layout) => layout.fill_with(f32::NAN),
-        },
-        (Some(a), None) => a.layout.fill_with(f32::NAN),
-        (None, Some(b)) => b.layout.fill_with(f32::NAN),
-        (None, None) => vocab::float(f32::NAN),
+    match align_values(lhs, rhs) {
+        Ok((layout, da, db)) => {
+            let data: Vec<f32> = da.iter().zip(db.iter()).map(|(x, y)| op(*x, *y)).collect();
+            layout.reconstruct(&data)
+        }
+        Err(layout) => layout.fill_with(f32::NAN),
     }
 }
 
@@ -46,9 +40,5 @@ pub fn as_float(v: &Value) -> f32 {
 
 /// Coerce a [`Value`] to a boolean, treating non-zero numeric entries as `true`.
 pub fn as_bool(v: &Value) -> bool {
-    match vocab::kind(v) {
-        VizijKind::Bool => vocab::as_bool(v).unwrap_or(false),
-        VizijKind::Text => vocab::as_text(v).is_some_and(|s| !s.is_empty()),
-        _ => coercion::to_vector(v).iter().any(|x| *x != 0.0),
-    }
+    coercion::to_bool(v)
 }
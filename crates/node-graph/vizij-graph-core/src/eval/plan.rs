@@ -241,11 +241,28 @@ fn build_output_layout(
         }
     }
 
+    // Caller-named Split parts replace the generated `partN` keys. They are inserted with the
+    // variadic group below so the range stays contiguous.
+    let custom_names = match node.kind {
+        NodeType::Split => node.params.output_names.as_ref(),
+        _ => None,
+    };
+
     // Respect any explicit output_shapes hints.
     for key in node.output_shapes.keys() {
+        if custom_names.is_some_and(|names| names.contains(key)) {
+            continue;
+        }
         layout.insert_slot(key.clone());
     }
 
+    // Subgraph ports are named by the node's output mapping rather than its signature.
+    if let Some(sub) = &node.params.subgraph {
+        for mapping in &sub.outputs {
+            layout.insert_slot(mapping.port.clone());
+        }
+    }
+
     if let Some(sig) = signature {
         if let Some(var_out) = &sig.variadic_outputs {
             let var_id = var_out.id;
@@ -298,8 +315,12 @@ fn build_output_layout(
 
             let count = std::cmp::max(1, std::cmp::max(min_from_params, max_ref));
             let start = layout.slots.len();
-
-            if var_out.keyed || has_canonical {
+            if let Some(names) = custom_names {
+                // The evaluator rejects a count that disagrees with `sizes`.
+                for name in names {
+                    layout.insert_slot(name.clone());
+                }
+            } else if var_out.keyed || has_canonical {
                 // Frontend convention: 0-indexed {id}_{N} (elements_0, elements_1, ...).
                 // Also used for keyed variadics (e.g. ReadRecord fields_0, fields_1).
                 for i in 0..count {
@@ -312,7 +333,8 @@ fn build_output_layout(
                 }
             }
 
-            let range = VariadicRange { start, len: count };
+            let len = custom_names.map_or(count, |_| layout.slots.len() - start);
+            let range = VariadicRange { start, len };
             layout.variadics.insert(var_id.to_string(), range);
             // Also register under the stem for backward eval-function compat.
             if stem != var_id {
@@ -425,7 +447,7 @@ pub fn fingerprint_spec(spec: &GraphSpec) -> u64 {
             hasher.write(kind_json.as_bytes());
         }
 
-        // Include params that alter layout (currently Split sizes / index can adjust output slots).
+        // Include params that alter layout (Split sizes / names / index and Subgraph output ports).
         if let Some(index) = node.params.index {
             hasher.write_u64(index.to_bits() as u64);
         }
@@ -435,6 +457,18 @@ pub fn fingerprint_spec(spec: &GraphSpec) -> u64 {
                 hasher.write_u64(size.to_bits() as u64);
             }
         }
+        if let Some(names) = &node.params.output_names {
+            hasher.write_usize(names.len());
+            for name in names {
+                hasher.write(name.as_bytes());
+            }
+        }
+        if let Some(sub) = &node.params.subgraph {
+            hasher.write_usize(sub.outputs.len());
+            for mapping in &sub.outputs {
+                hasher.write(mapping.port.as_bytes());
+            }
+        }
 
         hasher.write_usize(node.output_shapes.len());
         for (k, _) in &node.output_shapes {
@@ -8,6 +8,7 @@
 
 use crate::types::{NodeSpec, SelectorSegment};
 use hashbrown::HashMap;
+use vizij_api_core::coercion;
 use vizij_api_core::shape::Field;
 use vizij_api_core::value as vocab;
 use vizij_api_core::value::VizijKind;
@@ -98,12 +99,7 @@ pub fn enforce_output_shapes(
             )
         })?;
 
-        if !value_matches_shape(&declared.id, &port.value) {
-            return Err(format!(
-                "node '{}' output '{}' does not match declared shape {:?}",
-                spec.id, key, declared.id
-            ));
-        }
+        check_declared_shape(spec, key, &declared.id, &port.value)?;
 
         port.shape = declared.clone();
     }
@@ -111,66 +107,38 @@ pub fn enforce_output_shapes(
     Ok(())
 }
 
+/// Error unless output `key` of `spec` conforms to its declared shape. Enum
+/// shapes report the unknown variant or mismatched payload from
+/// [`coercion::validate_enum`].
+pub fn check_declared_shape(
+    spec: &NodeSpec,
+    key: &str,
+    declared: &ShapeId,
+    value: &Value,
+) -> Result<(), String> {
+    if let ShapeId::Enum(_) = declared {
+        return coercion::validate_enum(value, declared).map_err(|err| {
+            format!(
+                "node '{}' output '{}' does not match declared shape: {}",
+                spec.id, key, err
+            )
+        });
+    }
+    if !value_matches_shape(declared, value) {
+        return Err(format!(
+            "node '{}' output '{}' does not match declared shape {:?}",
+            spec.id, key, declared
+        ));
+    }
+    Ok(())
+}
+
 /// Check whether `value` conforms to the expected `shape`.
+///
+/// Thin wrapper over [`coercion::matches_shape`], kept for the graph's
+/// `(shape, value)` call sites.
 pub fn value_matches_shape(shape: &ShapeId, value: &Value) -> bool {
-    match shape {
-        ShapeId::Scalar => vocab::kind(value) == VizijKind::Float,
-        ShapeId::Bool => vocab::kind(value) == VizijKind::Bool,
-        ShapeId::Vec2 => vocab::kind(value) == VizijKind::Vec2,
-        ShapeId::Vec3 => vocab::kind(value) == VizijKind::Vec3,
-        ShapeId::Vec4 => vocab::kind(value) == VizijKind::Vec4,
-        ShapeId::Quat => vocab::kind(value) == VizijKind::Quat,
-        ShapeId::ColorRgba => vocab::kind(value) == VizijKind::ColorRgba,
-        ShapeId::Transform => vocab::kind(value) == VizijKind::Transform,
-        ShapeId::Text => vocab::kind(value) == VizijKind::Text,
-        ShapeId::Vector { len } => match vocab::as_vector(value) {
-            Some(items) => match len {
-                Some(expected) => items.len() == *expected,
-                None => true,
-            },
-            None => false,
-        },
-        ShapeId::Record(fields) => match vocab::as_record(value) {
-            Some(entries) => fields.iter().all(|field| {
-                entries
-                    .iter()
-                    .find(|(name, _)| *name == field.name)
-                    .map(|(_, v)| value_matches_shape(&field.shape, v))
-                    .unwrap_or(false)
-            }),
-            None => false,
-        },
-        ShapeId::Array(inner, len) => match vocab::as_array(value) {
-            Some(items) => {
-                items.len() == *len && items.iter().all(|item| value_matches_shape(inner, item))
-            }
-            None => false,
-        },
-        ShapeId::List(inner) => match vocab::as_array(value) {
-            Some(items) => items.iter().all(|item| value_matches_shape(inner, item)),
-            None => false,
-        },
-        ShapeId::Tuple(entries) => match vocab::as_array(value) {
-            Some(items) => {
-                items.len() == entries.len()
-                    && items
-                        .iter()
-                        .zip(entries.iter())
-                        .all(|(item, shape)| value_matches_shape(shape, item))
-            }
-            None => false,
-        },
-        // Enum shape tags are variant names (compared through
-        // [`vocab::variant_id`]); a tag equal to the id's string form is also
-        // accepted, covering shapes inferred from values.
-        ShapeId::Enum(variants) => match vocab::as_enumeration(value) {
-            Some((variant, payload)) => variants
-                .iter()
-                .find(|(tag, _)| vocab::variant_id(tag) == variant || variant.to_string() == *tag)
-                .is_some_and(|(_, shape)| value_matches_shape(shape, payload)),
-            None => false,
-        },
-    }
+    coercion::matches_shape(shape, value)
 }
 
 /// Determine whether a [`ShapeId`] is composed entirely of numeric leaves.
@@ -3,7 +3,7 @@
 use super::*;
 use crate::types::{
     EdgeInputEndpoint, EdgeOutputEndpoint, EdgeSpec, GraphSpec, InputDefault, NodeParams, NodeSpec,
-    NodeType, RoundMode, SelectorSegment,
+    NodeType, RoundMode, SelectorSegment, SubgraphInput, SubgraphOutput, SubgraphSpec,
 };
 use hashbrown::HashMap;
 use vizij_api_core::shape::Field;
@@ -35,9 +35,218 @@ fn constant_node(id: &str, value: Value) -> NodeSpec {
         },
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     }
 }
 
+// --- Spec handling & planning --------------------------------------------
+
+#[test]
+fn node_metadata_survives_normalize_and_serialize() {
+    let authored = r##"{
+        "nodes": [
+            {
+                "id": "c",
+                "type": "constant",
+                "params": { "value": 1.0 },
+                "metadata": { "position": { "x": 40, "y": -12 }, "note": "gain", "color": "#ff8800" }
+            },
+            { "id": "d", "type": "constant", "params": { "value": 2.0 } }
+        ],
+        "edges": []
+    }"##;
+    let normalized =
+        vizij_api_core::json::normalize_graph_spec_json(authored).expect("spec normalizes");
+    let spec: GraphSpec = serde_json::from_value(normalized).expect("spec deserializes");
+    let saved = serde_json::to_value(&spec).expect("spec serializes");
+
+    assert_eq!(
+        saved["nodes"][0]["metadata"],
+        serde_json::json!({ "position": { "x": 40, "y": -12 }, "note": "gain", "color": "#ff8800" })
+    );
+    assert!(
+        saved["nodes"][1].get("metadata").is_none(),
+        "nodes without metadata serialize without the field"
+    );
+}
+
+#[test]
+fn extracted_subgraph_evaluates_independently() {
+    let add = NodeSpec {
+        id: "add".to_string(),
+        kind: NodeType::Add,
+        params: NodeParams::default(),
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+    let mut mul = add.clone();
+    mul.id = "mul".to_string();
+    mul.kind = NodeType::Multiply;
+    let graph = GraphSpec {
+        nodes: vec![
+            constant_node("a", Value::F32(2.0)),
+            constant_node("b", Value::F32(3.0)),
+            add,
+            constant_node("k", Value::F32(10.0)),
+            mul,
+        ],
+        edges: vec![
+            link("a", "add", "operand_1"),
+            link("b", "add", "operand_2"),
+            link("add", "mul", "operand_1"),
+            link("k", "mul", "operand_2"),
+        ],
+        ..Default::default()
+    };
+
+    let extracted = graph.extract(&["a".to_string(), "add".to_string()]);
+    let ids: Vec<&str> = extracted.nodes.iter().map(|n| n.id.as_str()).collect();
+    assert_eq!(ids, ["a", "add"]);
+    assert_eq!(
+        extracted.edges.len(),
+        1,
+        "only the a -> add edge is internal"
+    );
+
+    // The dangling b -> add edge is rewritten to an inline default carrying b's value.
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &extracted.with_cache()).expect("extracted graph evaluates");
+    assert_eq!(vocab::as_float(&rt.outputs["add"]["out"].value), Some(5.0));
+}
+
+#[test]
+fn subgraph_exposes_mapped_child_output() {
+    let child = GraphSpec {
+        nodes: vec![
+            NodeSpec {
+                id: "x".to_string(),
+                kind: NodeType::Input,
+                params: NodeParams {
+                    path: Some(TypedPath::parse("sub/x").expect("typed path")),
+                    ..Default::default()
+                },
+                output_shapes: HashMap::new(),
+                input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
+            },
+            constant_node("two", Value::F32(2.0)),
+            NodeSpec {
+                id: "add".to_string(),
+                kind: NodeType::Add,
+                params: NodeParams::default(),
+                output_shapes: HashMap::new(),
+                input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
+            },
+        ],
+        edges: vec![
+            link("x", "add", "operand_1"),
+            link("two", "add", "operand_2"),
+        ],
+        ..Default::default()
+    };
+    let subgraph = NodeSpec {
+        id: "sub".to_string(),
+        kind: NodeType::Subgraph,
+        params: NodeParams {
+            subgraph: Some(Box::new(SubgraphSpec {
+                graph: child,
+                inputs: vec![SubgraphInput {
+                    port: "x".to_string(),
+                    path: TypedPath::parse("sub/x").expect("typed path"),
+                }],
+                outputs: vec![SubgraphOutput {
+                    port: "sum".to_string(),
+                    node_id: "add".to_string(),
+                    output: "out".to_string(),
+                }],
+            })),
+            ..Default::default()
+        },
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+    let mut spec = GraphSpec {
+        nodes: vec![constant_node("five", Value::F32(5.0)), subgraph],
+        edges: vec![link("five", "sub", "x")],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &spec).expect("subgraph should evaluate");
+    assert_eq!(vocab::as_float(&rt.outputs["sub"]["sum"].value), Some(7.0));
+
+    // Mapped inputs are re-staged every frame on the persistent child runtime.
+    spec.nodes[0].params.value = Some(Value::F32(-1.0));
+    evaluate_all(&mut rt, &spec).expect("subgraph should re-evaluate");
+    assert_eq!(vocab::as_float(&rt.outputs["sub"]["sum"].value), Some(1.0));
+}
+
+#[test]
+fn evaluate_all_limited_rejects_graphs_over_budget() {
+    let spec = graph_spec!({
+        nodes: (0..5)
+            .map(|i| constant_node(&format!("c{i}"), Value::F32(i as f32)))
+            .collect(),
+        edges: vec![],
+    });
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all_limited(&mut rt, &spec, 5).expect("graph fits the budget");
+    assert_eq!(rt.outputs.len(), 5);
+
+    let err = evaluate_all_limited(&mut rt, &spec, 3).expect_err("graph exceeds the budget");
+    assert!(err.contains("budget exceeded"), "{err}");
+    assert!(
+        err.contains("5 nodes") && err.contains("limit is 3"),
+        "{err}"
+    );
+    assert_eq!(rt.outputs.len(), 5, "previous outputs are kept");
+}
+
+#[test]
+fn evaluate_all_limited_counts_subgraph_nodes() {
+    let child = GraphSpec {
+        nodes: (0..3)
+            .map(|i| constant_node(&format!("k{i}"), Value::F32(i as f32)))
+            .collect(),
+        ..Default::default()
+    };
+    let subgraph = NodeSpec {
+        id: "sub".to_string(),
+        kind: NodeType::Subgraph,
+        params: NodeParams {
+            subgraph: Some(Box::new(SubgraphSpec {
+                graph: child,
+                inputs: vec![],
+                outputs: vec![],
+            })),
+            ..Default::default()
+        },
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+    let spec = graph_spec!({
+        nodes: vec![constant_node("a", Value::F32(1.0)), subgraph],
+        edges: vec![],
+    });
+
+    // Two top-level nodes plus three nested ones.
+    let mut rt = GraphRuntime::default();
+    evaluate_all_limited(&mut rt, &spec, 5).expect("nested nodes fit the budget");
+
+    let err = evaluate_all_limited(&mut rt, &spec, 4).expect_err("nested nodes exceed the budget");
+    assert!(err.contains("subgraph 'sub'"), "{err}");
+    assert!(
+        err.contains("3 nodes") && err.contains("limit is 2"),
+        "{err}"
+    );
+}
+
 #[test]
 fn plan_cache_reuses_layouts_when_spec_version_matches() {
     let spec = graph_spec!({
@@ -108,6 +317,8 @@ fn plan_cache_rebuilds_when_spec_version_changes() {
     );
 }
 
+// --- Piecewise remap & curves --------------------------------------------
+
 #[test]
 fn piecewise_remap_matches_linear_case() {
     let mut defaults = HashMap::new();
@@ -137,6 +348,7 @@ fn piecewise_remap_matches_linear_case() {
                 },
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "remap".to_string(),
@@ -144,6 +356,7 @@ fn piecewise_remap_matches_linear_case() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: defaults,
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![link("input", "remap", "in")],
@@ -171,6 +384,48 @@ fn piecewise_remap_matches_linear_case() {
     }
 }
 
+#[test]
+fn curve_node_interpolates_and_clamps() {
+    let graph = |input: Value| {
+        GraphSpec {
+            nodes: vec![
+                constant_node("src", input),
+                NodeSpec {
+                    id: "curve".to_string(),
+                    kind: NodeType::Curve,
+                    params: NodeParams {
+                        points: Some(vec![(0.0, 0.0), (1.0, 10.0), (3.0, 30.0), (4.0, 0.0)]),
+                        ..Default::default()
+                    },
+                    output_shapes: HashMap::new(),
+                    input_defaults: HashMap::new(),
+                    metadata: serde_json::Value::Null,
+                },
+            ],
+            edges: vec![link("src", "curve", "in")],
+            ..Default::default()
+        }
+        .with_cache()
+    };
+    let eval = |input: Value| {
+        let mut rt = GraphRuntime::default();
+        evaluate_all(&mut rt, &graph(input)).expect("curve should evaluate");
+        rt.outputs["curve"]["out"].value.clone()
+    };
+
+    for (input, expected) in [(0.5, 5.0), (2.0, 20.0), (3.5, 15.0), (1.0, 10.0)] {
+        let actual = vocab::as_float(&eval(vocab::float(input))).expect("float output");
+        assert!(
+            (actual - expected).abs() < 1e-5,
+            "{input}: {actual} vs {expected}"
+        );
+    }
+
+    // Outside the breakpoint range the end outputs hold, per component.
+    let clamped = expect_vec3(&eval(vocab::vec3([-2.0, 2.0, 9.0])));
+    assert_eq!(clamped, [0.0, 20.0, 0.0]);
+}
+
 #[test]
 fn piecewise_remap_handles_segments_and_extrapolation() {
     let mut defaults = HashMap::new();
@@ -200,6 +455,7 @@ fn piecewise_remap_handles_segments_and_extrapolation() {
                 },
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "remap".to_string(),
@@ -210,6 +466,7 @@ fn piecewise_remap_handles_segments_and_extrapolation() {
                 },
                 output_shapes: HashMap::new(),
                 input_defaults: defaults,
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![link("input", "remap", "in")],
@@ -271,6 +528,7 @@ fn piecewise_remap_preserves_plateaus_with_duplicate_inputs() {
                 },
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "remap".to_string(),
@@ -278,6 +536,7 @@ fn piecewise_remap_preserves_plateaus_with_duplicate_inputs() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: defaults,
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![link("input", "remap", "in")],
@@ -332,6 +591,7 @@ fn piecewise_remap_allows_duplicate_inputs_with_different_outputs() {
                 },
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "remap".to_string(),
@@ -339,6 +599,7 @@ fn piecewise_remap_allows_duplicate_inputs_with_different_outputs() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: defaults,
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![link("input", "remap", "in")],
@@ -393,6 +654,7 @@ fn piecewise_remap_validates_duplicate_breakpoints() {
                 },
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "remap".to_string(),
@@ -400,6 +662,7 @@ fn piecewise_remap_validates_duplicate_breakpoints() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: defaults,
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![link("input", "remap", "in")],
@@ -462,6 +725,7 @@ fn piecewise_remap_errors_when_inputs_decrease() {
                 },
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "remap".to_string(),
@@ -469,6 +733,7 @@ fn piecewise_remap_errors_when_inputs_decrease() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: defaults,
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![link("input", "remap", "in")],
@@ -565,6 +830,7 @@ fn abs_node_handles_negative_values() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![link("src", "abs", "in")],
@@ -591,6 +857,7 @@ fn modulo_node_handles_division() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![link("lhs", "mod", "lhs"), link("rhs", "mod", "rhs")],
@@ -618,6 +885,7 @@ fn sqrt_node_handles_vectors() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![link("src", "sqrt", "in")],
@@ -645,6 +913,7 @@ fn sign_node_outputs_signum() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![link("src", "sign", "in")],
@@ -661,6 +930,66 @@ fn sign_node_outputs_signum() {
     }
 }
 
+#[test]
+fn saturate_node_clamps_vec3_into_unit_range() {
+    let graph = GraphSpec {
+        nodes: vec![
+            constant_node("src", vocab::vec3([-0.5, 0.25, 3.0])),
+            NodeSpec {
+                id: "sat".to_string(),
+                kind: NodeType::Saturate,
+                params: NodeParams::default(),
+                output_shapes: HashMap::new(),
+                input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
+            },
+        ],
+        edges: vec![link("src", "sat", "in")],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &graph).expect("saturate should evaluate");
+    assert_eq!(
+        expect_vec3(&rt.outputs["sat"]["out"].value),
+        [0.0, 0.25, 1.0]
+    );
+}
+
+#[test]
+fn negate_and_reciprocal_nodes_map_vec3_components() {
+    let unary = |id: &str, kind: NodeType| NodeSpec {
+        id: id.to_string(),
+        kind,
+        params: NodeParams::default(),
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+    let graph = GraphSpec {
+        nodes: vec![
+            constant_node("src", vocab::vec3([2.0, 0.0, -4.0])),
+            unary("neg", NodeType::Negate),
+            unary("inv", NodeType::Reciprocal),
+        ],
+        edges: vec![link("src", "neg", "in"), link("src", "inv", "in")],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &graph).expect("unary nodes should evaluate");
+    assert_eq!(
+        expect_vec3(&rt.outputs["neg"]["out"].value),
+        [-2.0, -0.0, 4.0]
+    );
+
+    let [x, y, z] = expect_vec3(&rt.outputs["inv"]["out"].value);
+    assert_eq!((x, z), (0.5, -0.25));
+    assert!(y.is_nan(), "reciprocal of zero should be NaN, got {y}");
+}
+
 #[test]
 fn min_max_nodes_select_expected_values() {
     let mut rt = GraphRuntime::default();
@@ -675,6 +1004,7 @@ fn min_max_nodes_select_expected_values() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "maxx".to_string(),
@@ -682,6 +1012,7 @@ fn min_max_nodes_select_expected_values() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![
@@ -732,6 +1063,7 @@ fn round_node_respects_modes() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "ceil".to_string(),
@@ -742,6 +1074,7 @@ fn round_node_respects_modes() {
                 },
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "trunc".to_string(),
@@ -752,6 +1085,7 @@ fn round_node_respects_modes() {
                 },
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![
@@ -800,86 +1134,689 @@ fn round_node_respects_modes() {
     assert_eq!(trunc_vals, vec![-1.0, 0.0, 2.0]);
 }
 
-// --- Runtime outputs -----------------------------------------------------
+#[test]
+fn boolean_inputs_contribute_one_or_zero_to_math_nodes() {
+    let eval = |kind: NodeType, lhs: f32, flag: bool| {
+        let node = NodeSpec {
+            id: "math".to_string(),
+            kind,
+            params: NodeParams::default(),
+            output_shapes: HashMap::new(),
+            input_defaults: HashMap::new(),
+            metadata: serde_json::Value::Null,
+        };
+        let graph = GraphSpec {
+            nodes: vec![
+                constant_node("lhs", Value::F32(lhs)),
+                constant_node("flag", vocab::bool_(flag)),
+                node,
+            ],
+            edges: vec![
+                link("lhs", "math", "operand_1"),
+                link("flag", "math", "operand_2"),
+            ],
+            ..Default::default()
+        }
+        .with_cache();
+        let mut rt = GraphRuntime::default();
+        evaluate_all(&mut rt, &graph).expect("graph evaluates");
+        vocab::as_float(&rt.outputs["math"]["out"].value).expect("float output")
+    };
+
+    assert_eq!(eval(NodeType::Add, 2.0, true), 3.0);
+    assert_eq!(eval(NodeType::Add, 2.0, false), 2.0);
+    // A boolean gate passes or zeroes the other operand.
+    assert_eq!(eval(NodeType::Multiply, 5.0, true), 5.0);
+    assert_eq!(eval(NodeType::Multiply, 5.0, false), 0.0);
+}
 
 #[test]
-fn it_should_emit_write_for_output_nodes() {
+fn number_to_text_formats_with_precision_and_text_to_number_parses() {
+    let mut format = NodeSpec {
+        id: "format".to_string(),
+        kind: NodeType::NumberToText,
+        params: NodeParams {
+            precision: Some(2),
+            ..Default::default()
+        },
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+    let mut parse = format.clone();
+    parse.id = "parse".to_string();
+    parse.kind = NodeType::TextToNumber;
+    parse.params = NodeParams::default();
+    let mut bad = parse.clone();
+    bad.id = "bad".to_string();
+    format.input_defaults.insert(
+        "in".to_string(),
+        InputDefault {
+            value: Value::F32(std::f32::consts::PI),
+            shape: None,
+        },
+    );
+    parse.input_defaults.insert(
+        "in".to_string(),
+        InputDefault {
+            value: vocab::text("2.75"),
+            shape: None,
+        },
+    );
+    bad.input_defaults.insert(
+        "in".to_string(),
+        InputDefault {
+            value: vocab::text("pi"),
+            shape: None,
+        },
+    );
     let graph = GraphSpec {
-        nodes: vec![
-            constant_node("src", Value::F32(2.0)),
-            NodeSpec {
-                id: "out".to_string(),
-                kind: NodeType::Output,
-                params: NodeParams {
-                    path: Some(TypedPath::parse("robot1/Arm/Joint.angle").expect("valid path")),
-                    ..Default::default()
-                },
-                output_shapes: HashMap::new(),
-                input_defaults: HashMap::new(),
-            },
-        ],
-        edges: vec![link("src", "out", "in")],
+        nodes: vec![format, parse, bad],
         ..Default::default()
     }
     .with_cache();
 
     let mut rt = GraphRuntime::default();
-    evaluate_all(&mut rt, &graph).expect("graph should evaluate");
-    assert_eq!(rt.writes.iter().count(), 1);
-    let op = rt.writes.iter().next().expect("write present");
-    assert_eq!(op.path.to_string(), "robot1/Arm/Joint.angle");
-    assert!(matches!(
-        op.shape.as_ref().map(|s| &s.id),
-        Some(ShapeId::Scalar)
-    ));
-    match op.value {
-        Value::F32(f) => assert_eq!(f, 2.0),
-        _ => panic!("expected float write"),
-    }
+    evaluate_all(&mut rt, &graph).expect("graph evaluates");
+    assert_eq!(
+        vocab::as_text(&rt.outputs["format"]["out"].value),
+        Some("3.14")
+    );
+    assert_eq!(
+        vocab::as_float(&rt.outputs["parse"]["out"].value),
+        Some(2.75)
+    );
+    assert!(vocab::as_float(&rt.outputs["bad"]["out"].value).is_some_and(f32::is_nan));
 }
 
 #[test]
-fn writes_batch_json_roundtrip_from_graph() {
-    // Build a trivial graph that emits a write.
-    let graph = GraphSpec {
+fn quantize_node_snaps_to_grid() {
+    let quantize = |step: f32| NodeSpec {
+        id: "quantize".to_string(),
+        kind: NodeType::Quantize,
+        params: NodeParams {
+            step: Some(step),
+            ..Default::default()
+        },
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+
+    let mut spec = GraphSpec {
         nodes: vec![
-            constant_node("src", vocab::vec3([1.0, 2.0, 3.0])),
-            NodeSpec {
-                id: "out".to_string(),
-                kind: NodeType::Output,
-                params: NodeParams {
-                    path: Some(TypedPath::parse("robot/pose.translation").expect("valid path")),
-                    ..Default::default()
-                },
-                output_shapes: HashMap::new(),
-                input_defaults: HashMap::new(),
-            },
+            constant_node("src", vocab::vec3([0.1, 0.38, -0.62])),
+            quantize(0.25),
         ],
-        edges: vec![link("src", "out", "in")],
+        edges: vec![link("src", "quantize", "in")],
         ..Default::default()
     }
     .with_cache();
 
     let mut rt = GraphRuntime::default();
-    evaluate_all(&mut rt, &graph).expect("graph should evaluate");
-    let original = rt.writes.clone();
-
-    let json = serde_json::to_string(&original).expect("serialize writes");
-    let parsed: WriteBatch = serde_json::from_str(&json).expect("parse writes");
-    assert_eq!(original, parsed, "writes batch should roundtrip via JSON");
+    evaluate_all(&mut rt, &spec).expect("quantize should evaluate");
+    let outputs = rt
+        .outputs
+        .get("quantize")
+        .expect("quantize outputs present");
+    let snapped = expect_vec3(&outputs.get("out").expect("out port present").value);
+    assert_eq!(snapped, [0.0, 0.5, -0.5]);
+
+    spec.nodes[1] = quantize(0.0);
+    evaluate_all(&mut rt, &spec).expect("zero step should evaluate");
+    let outputs = rt
+        .outputs
+        .get("quantize")
+        .expect("quantize outputs present");
+    let passthrough = expect_vec3(&outputs.get("out").expect("out port present").value);
+    assert_eq!(passthrough, [0.1, 0.38, -0.62]);
 }
 
 #[test]
-fn input_defaults_supply_missing_connections() {
-    let mut defaults = HashMap::new();
-    defaults.insert(
-        "rhs".to_string(),
-        InputDefault {
-            value: Value::F32(2.0),
-            shape: None,
+fn compare_node_reports_sign_of_difference() {
+    let compare = NodeSpec {
+        id: "cmp".to_string(),
+        kind: NodeType::Compare,
+        params: NodeParams {
+            epsilon: Some(0.01),
+            ..Default::default()
         },
-    );
-
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+    let eval = |lhs: Value, rhs: Value| {
+        let spec = GraphSpec {
+            nodes: vec![
+                constant_node("lhs", lhs),
+                constant_node("rhs", rhs),
+                compare.clone(),
+            ],
+            edges: vec![link("lhs", "cmp", "lhs"), link("rhs", "cmp", "rhs")],
+            ..Default::default()
+        }
+        .with_cache();
+        let mut rt = GraphRuntime::default();
+        evaluate_all(&mut rt, &spec).expect("compare should evaluate");
+        rt.outputs["cmp"]["out"].value.clone()
+    };
+
+    let scalar = |lhs: f32, rhs: f32| vocab::as_float(&eval(vocab::float(lhs), vocab::float(rhs)));
+    assert_eq!(scalar(1.0, 2.0), Some(-1.0));
+    assert_eq!(scalar(2.0, 2.005), Some(0.0), "within epsilon");
+    assert_eq!(scalar(3.0, 2.0), Some(1.0));
+
+    let elementwise = eval(vocab::vec3([0.0, 5.0, 9.0]), vocab::vec3([1.0, 5.0, 2.0]));
+    assert_eq!(expect_vec3(&elementwise), [-1.0, 0.0, 1.0]);
+}
+
+#[test]
+fn equality_nodes_support_relative_epsilon_for_large_values() {
+    let eval = |kind: NodeType, relative: Option<bool>| {
+        let node = NodeSpec {
+            id: "eq".to_string(),
+            kind,
+            params: NodeParams {
+                relative,
+                ..Default::default()
+            },
+            output_shapes: HashMap::new(),
+            input_defaults: HashMap::new(),
+            metadata: serde_json::Value::Null,
+        };
+        let spec = GraphSpec {
+            nodes: vec![
+                constant_node("lhs", Value::F32(1.0e8)),
+                constant_node("rhs", Value::F32(1.0e8 + 8.0)),
+                node,
+            ],
+            edges: vec![link("lhs", "eq", "lhs"), link("rhs", "eq", "rhs")],
+            ..Default::default()
+        }
+        .with_cache();
+        let mut rt = GraphRuntime::default();
+        evaluate_all(&mut rt, &spec).expect("equality node should evaluate");
+        vocab::as_bool(&rt.outputs["eq"]["out"].value)
+    };
+
+    // Adjacent f32 values near 1e8 are 8 apart, far outside the default absolute tolerance.
+    assert_eq!(eval(NodeType::Equal, None), Some(false));
+    assert_eq!(eval(NodeType::NotEqual, None), Some(true));
+    assert_eq!(eval(NodeType::Equal, Some(true)), Some(true));
+    assert_eq!(eval(NodeType::NotEqual, Some(true)), Some(false));
+}
+
+// --- Records, lists & colors ---------------------------------------------
+
+#[test]
+fn read_and_build_record_round_trip_joint_angles() {
+    // Same layout the URDF IK nodes emit: one float field per joint name.
+    let solution = vocab::record([
+        ("shoulder", vocab::float(0.5)),
+        ("elbow", vocab::float(-1.25)),
+    ]);
+    let record_node = |id: &str, kind: NodeType, keys: &[&str]| NodeSpec {
+        id: id.to_string(),
+        kind,
+        params: NodeParams {
+            record_keys: Some(keys.iter().map(|k| k.to_string()).collect()),
+            ..Default::default()
+        },
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+
+    let spec = GraphSpec {
+        nodes: vec![
+            constant_node("ik", solution),
+            record_node("read", NodeType::ReadRecord, &["elbow", "wrist"]),
+            constant_node("wrist_angle", vocab::float(0.75)),
+            record_node("build", NodeType::BuildRecord, &["elbow", "wrist"]),
+            record_node("reread", NodeType::ReadRecord, &["wrist"]),
+        ],
+        edges: vec![
+            link("ik", "read", "in"),
+            link_with_output("read", "field_0", "build", "field_0"),
+            link("wrist_angle", "build", "field_1"),
+            link("build", "reread", "in"),
+        ],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &spec).expect("record nodes should evaluate");
+    let read = |id: &str, port: &str| {
+        rt.outputs
+            .get(id)
+            .and_then(|ports| ports.get(port))
+            .map(|pv| pv.value.clone())
+            .unwrap_or_else(|| panic!("{id}.{port} output present"))
+    };
+
+    assert_eq!(vocab::as_float(&read("read", "field_0")), Some(-1.25));
+    // Keys absent from the record read as 0.0.
+    assert_eq!(vocab::as_float(&read("read", "field_1")), Some(0.0));
+
+    let rebuilt = read("build", "out");
+    let mut names: Vec<&str> = vocab::as_record(&rebuilt)
+        .expect("build emits a record")
+        .iter()
+        .map(|(name, _)| *name)
+        .collect();
+    names.sort_unstable();
+    assert_eq!(names, vec!["elbow", "wrist"]);
+    assert_eq!(vocab::as_float(&read("reread", "field_0")), Some(0.75));
+}
+
+#[test]
+fn list_nodes_report_length_and_extract_elements() {
+    let list = vocab::array(vec![
+        vocab::vec3([1.0, 0.0, 0.0]),
+        vocab::vec3([0.0, 2.0, 0.0]),
+        vocab::vec3([0.0, 0.0, 3.0]),
+    ]);
+    let list_get = |id: &str, index: f32| NodeSpec {
+        id: id.to_string(),
+        kind: NodeType::ListGet,
+        params: NodeParams {
+            index: Some(index),
+            ..Default::default()
+        },
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+
+    let spec = GraphSpec {
+        nodes: vec![
+            constant_node("list", list),
+            NodeSpec {
+                id: "len".to_string(),
+                kind: NodeType::ListLength,
+                params: NodeParams::default(),
+                output_shapes: HashMap::new(),
+                input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
+            },
+            list_get("second", 1.0),
+            list_get("past_end", 3.0),
+            constant_node("wired_index", vocab::float(2.0)),
+            list_get("third", 0.0),
+        ],
+        edges: vec![
+            link("list", "len", "list"),
+            link("list", "second", "list"),
+            link("list", "past_end", "list"),
+            link("list", "third", "list"),
+            link("wired_index", "third", "index"),
+        ],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &spec).expect("list nodes should evaluate");
+    let read = |id: &str| {
+        rt.outputs
+            .get(id)
+            .and_then(|ports| ports.get("out"))
+            .map(|port| port.value.clone())
+            .unwrap_or_else(|| panic!("{id} output present"))
+    };
+
+    assert_eq!(vocab::as_float(&read("len")), Some(3.0));
+    assert_eq!(expect_vec3(&read("second")), [0.0, 2.0, 0.0]);
+    assert_eq!(expect_vec3(&read("third")), [0.0, 0.0, 3.0]);
+    let past_end = vocab::as_float(&read("past_end")).expect("out-of-range yields a float");
+    assert!(past_end.is_nan(), "out-of-range index should be NaN");
+}
+
+#[test]
+fn color_nodes_round_trip_rgb_through_hsv() {
+    let colors = [
+        [1.0, 0.0, 0.0, 1.0],
+        [0.2, 0.6, 0.4, 0.5],
+        [0.9, 0.1, 0.7, 0.0],
+        [0.3, 0.3, 0.3, 0.25],
+        [0.0, 0.0, 0.0, 1.0],
+    ];
+    let color_node = |id: &str, kind: NodeType| NodeSpec {
+        id: id.to_string(),
+        kind,
+        params: NodeParams::default(),
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+
+    for rgba in colors {
+        let spec = GraphSpec {
+            nodes: vec![
+                constant_node("src", vocab::color_rgba(rgba)),
+                color_node("hsv", NodeType::RgbToHsv),
+                color_node("rgb", NodeType::HsvToRgb),
+            ],
+            edges: vec![link("src", "hsv", "in"), link("hsv", "rgb", "in")],
+            ..Default::default()
+        }
+        .with_cache();
+
+        let mut rt = GraphRuntime::default();
+        evaluate_all(&mut rt, &spec).expect("color conversion should evaluate");
+        let read = |id: &str| {
+            let value = &rt.outputs[id]["out"].value;
+            vocab::as_color_rgba(value).unwrap_or_else(|| panic!("{id} emits a color"))
+        };
+
+        assert_eq!(read("hsv")[3], rgba[3], "alpha untouched by RgbToHsv");
+        let round_trip = read("rgb");
+        for (actual, expected) in round_trip.iter().zip(rgba.iter()) {
+            assert!(
+                (actual - expected).abs() < 1e-5,
+                "expected {rgba:?}, got {round_trip:?}"
+            );
+        }
+    }
+
+    let spec = GraphSpec {
+        nodes: vec![
+            constant_node("src", vocab::vec3([1.0, 0.0, 0.0])),
+            color_node("hsv", NodeType::RgbToHsv),
+        ],
+        edges: vec![link("src", "hsv", "in")],
+        ..Default::default()
+    }
+    .with_cache();
+    let mut rt = GraphRuntime::default();
+    let err = evaluate_all(&mut rt, &spec).expect_err("vec3 input should be rejected");
+    assert!(err.contains("ColorRgba"), "unexpected error: {err}");
+}
+
+#[test]
+fn srgb_nodes_apply_transfer_function() {
+    let spec = GraphSpec {
+        nodes: vec![
+            constant_node("src", vocab::color_rgba([0.0, 0.5, 1.0, 0.5])),
+            NodeSpec {
+                id: "linear".to_string(),
+                kind: NodeType::SrgbToLinear,
+                params: NodeParams::default(),
+                output_shapes: HashMap::new(),
+                input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
+            },
+            NodeSpec {
+                id: "srgb".to_string(),
+                kind: NodeType::LinearToSrgb,
+                params: NodeParams::default(),
+                output_shapes: HashMap::new(),
+                input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
+            },
+        ],
+        edges: vec![link("src", "linear", "in"), link("linear", "srgb", "in")],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &spec).expect("srgb conversion should evaluate");
+    let linear = vocab::as_color_rgba(&rt.outputs["linear"]["out"].value).expect("linear color");
+    assert_eq!(linear[0], 0.0);
+    assert!((linear[1] - 0.214_041).abs() < 1e-5, "mid-gray: {linear:?}");
+    assert!((linear[2] - 1.0).abs() < 1e-6);
+    assert_eq!(linear[3], 0.5, "alpha stays linear");
+
+    let srgb = vocab::as_color_rgba(&rt.outputs["srgb"]["out"].value).expect("srgb color");
+    for (actual, expected) in srgb.iter().zip([0.0, 0.5, 1.0, 0.5]) {
+        assert!((actual - expected).abs() < 1e-5, "round trip: {srgb:?}");
+    }
+}
+
+// --- Runtime outputs -----------------------------------------------------
+
+#[test]
+fn it_should_emit_write_for_output_nodes() {
+    let graph = GraphSpec {
+        nodes: vec![
+            constant_node("src", Value::F32(2.0)),
+            NodeSpec {
+                id: "out".to_string(),
+                kind: NodeType::Output,
+                params: NodeParams {
+                    path: Some(TypedPath::parse("robot1/Arm/Joint.angle").expect("valid path")),
+                    ..Default::default()
+                },
+                output_shapes: HashMap::new(),
+                input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
+            },
+        ],
+        edges: vec![link("src", "out", "in")],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &graph).expect("graph should evaluate");
+    assert_eq!(rt.writes.iter().count(), 1);
+    let op = rt.writes.iter().next().expect("write present");
+    assert_eq!(op.path.to_string(), "robot1/Arm/Joint.angle");
+    assert!(matches!(
+        op.shape.as_ref().map(|s| &s.id),
+        Some(ShapeId::Scalar)
+    ));
+    match op.value {
+        Value::F32(f) => assert_eq!(f, 2.0),
+        _ => panic!("expected float write"),
+    }
+}
+
+#[test]
+fn recorded_run_replays_against_a_fresh_runtime() {
+    let node = |id: &str, kind: NodeType, path: Option<&str>| NodeSpec {
+        id: id.to_string(),
+        kind,
+        params: NodeParams {
+            path: path.map(|p| TypedPath::parse(p).expect("valid path")),
+            ..Default::default()
+        },
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+    let spec = GraphSpec {
+        nodes: vec![
+            node("clock", NodeType::Time, None),
+            constant_node("gain", Value::F32(2.0)),
+            node("scaled", NodeType::Multiply, None),
+            node("out", NodeType::Output, Some("demo/scaled.value")),
+        ],
+        edges: vec![
+            link("clock", "scaled", "operand_1"),
+            link("gain", "scaled", "operand_2"),
+            link("scaled", "out", "in"),
+        ],
+        ..Default::default()
+    }
+    .with_cache();
+    let step = |rt: &mut GraphRuntime, delta: f32| {
+        rt.dt = delta;
+        rt.t += delta;
+        evaluate_all(rt, &spec).expect("graph should evaluate");
+    };
+
+    let mut recording = GraphRuntime::default();
+    let frames: Vec<(f32, WriteBatch)> = [0.0, 0.25, 0.5]
+        .into_iter()
+        .map(|delta| {
+            step(&mut recording, delta);
+            (delta, recording.writes.clone())
+        })
+        .collect();
+    let fixture =
+        vizij_test_fixtures::record_steps(serde_json::json!({}), frames).expect("record steps");
+    let steps = fixture["steps"].as_array().expect("steps array");
+    assert_eq!(steps.len(), 3);
+
+    let mut replay = GraphRuntime::default();
+    for recorded in steps {
+        step(
+            &mut replay,
+            recorded["delta"].as_f64().expect("delta") as f32,
+        );
+        vizij_test_fixtures::assert_outputs_match(&recorded["expect"], &replay.writes, 1e-6)
+            .expect("fresh run matches the recording");
+    }
+    vizij_test_fixtures::assert_graph_outputs_match(
+        &serde_json::json!({ "clock": { "out": 0.75 }, "scaled": { "out": 1.5 } }),
+        &replay.outputs,
+        1e-6,
+    )
+    .expect("node outputs match");
+    assert!(
+        vizij_test_fixtures::assert_outputs_match(&steps[1]["expect"], &replay.writes, 1e-6)
+            .is_err(),
+        "a different frame does not match"
+    );
+}
+
+/// Two `Output` nodes writing the same path, the second fed by `relay` from the first's
+/// passthrough so the writes land in a fixed order.
+fn accumulated_output_writes(
+    modes: [&str; 2],
+    first: Value,
+    relay: NodeType,
+    relay_input: &str,
+) -> WriteBatch {
+    let output = |id: &str, mode: &str| NodeSpec {
+        id: id.to_string(),
+        kind: NodeType::Output,
+        params: NodeParams {
+            path: Some(TypedPath::parse("robot1/Arm/Joint.angle").expect("valid path")),
+            accumulate: Some(mode.to_string()),
+            ..Default::default()
+        },
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+    let graph = GraphSpec {
+        nodes: vec![
+            constant_node("a", first),
+            output("out_a", modes[0]),
+            NodeSpec {
+                id: "relay".to_string(),
+                kind: relay,
+                params: NodeParams::default(),
+                output_shapes: HashMap::new(),
+                input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
+            },
+            output("out_b", modes[1]),
+        ],
+        edges: vec![
+            link("a", "out_a", "in"),
+            link("out_a", "relay", relay_input),
+            link("relay", "out_b", "in"),
+        ],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &graph).expect("graph should evaluate");
+    rt.writes
+}
+
+/// Writes 2.0 then its reciprocal 0.5 to one path under `mode`.
+fn accumulated_float_writes(mode: &str) -> WriteBatch {
+    accumulated_output_writes([mode, mode], Value::F32(2.0), NodeType::Reciprocal, "in")
+}
+
+#[test]
+fn output_nodes_sum_writes_to_shared_path() {
+    let writes = accumulated_float_writes("sum");
+    assert_eq!(writes.len(), 1);
+    let op = writes.iter().next().expect("write present");
+    assert_eq!(vocab::as_float(&op.value), Some(2.5));
+}
+
+#[test]
+fn output_nodes_collect_writes_to_shared_path_as_list() {
+    let writes = accumulated_float_writes("list");
+    assert_eq!(writes.len(), 1);
+    let op = writes.iter().next().expect("write present");
+    let items: Vec<f32> = match &op.value {
+        Value::ArrayValue(items) => items.iter().filter_map(vocab::as_float).collect(),
+        other => panic!("expected collected array, got {other:?}"),
+    };
+    assert_eq!(items, vec![2.0, 0.5]);
+}
+
+#[test]
+fn output_nodes_default_to_last_write_wins() {
+    let writes = accumulated_float_writes("last");
+    assert_eq!(writes.len(), 1);
+    let value = vocab::as_float(&writes.iter().next().expect("write present").value);
+    assert_eq!(value, Some(0.5));
+}
+
+#[test]
+fn output_list_write_wraps_an_earlier_last_write_holding_an_array() {
+    let list = vocab::array(vec![vocab::float(1.0), vocab::float(2.0)]);
+    let writes =
+        accumulated_output_writes(["last", "list"], list.clone(), NodeType::ListLength, "list");
+    assert_eq!(writes.len(), 1);
+    let op = writes.iter().next().expect("write present");
+    assert_eq!(op.value, vocab::array(vec![list, vocab::float(2.0)]));
+}
+
+#[test]
+fn writes_batch_json_roundtrip_from_graph() {
+    // Build a trivial graph that emits a write.
+    let graph = GraphSpec {
+        nodes: vec![
+            constant_node("src", vocab::vec3([1.0, 2.0, 3.0])),
+            NodeSpec {
+                id: "out".to_string(),
+                kind: NodeType::Output,
+                params: NodeParams {
+                    path: Some(TypedPath::parse("robot/pose.translation").expect("valid path")),
+                    ..Default::default()
+                },
+                output_shapes: HashMap::new(),
+                input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
+            },
+        ],
+        edges: vec![link("src", "out", "in")],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &graph).expect("graph should evaluate");
+    let original = rt.writes.clone();
+
+    let json = serde_json::to_string(&original).expect("serialize writes");
+    let parsed: WriteBatch = serde_json::from_str(&json).expect("parse writes");
+    assert_eq!(original, parsed, "writes batch should roundtrip via JSON");
+}
+
+#[test]
+fn input_defaults_supply_missing_connections() {
+    let mut defaults = HashMap::new();
+    defaults.insert(
+        "rhs".to_string(),
+        InputDefault {
+            value: Value::F32(2.0),
+            shape: None,
+        },
+    );
+
     let graph = GraphSpec {
         nodes: vec![
             constant_node("numerator", Value::F32(10.0)),
@@ -889,6 +1826,7 @@ fn input_defaults_supply_missing_connections() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: defaults,
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![link("numerator", "div", "lhs")],
@@ -927,6 +1865,7 @@ fn linked_inputs_override_defaults() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: defaults,
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![
@@ -967,6 +1906,7 @@ fn defaults_apply_when_output_key_missing() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: defaults,
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![
@@ -1001,6 +1941,7 @@ fn join_respects_operand_order() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![
@@ -1034,6 +1975,7 @@ fn oscillator_broadcasts_vector_inputs() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![
@@ -1071,6 +2013,94 @@ fn oscillator_broadcasts_vector_inputs() {
     }
 }
 
+#[test]
+fn split_output_names_replace_part_keys() {
+    let split_graph = |names: Vec<&str>| {
+        let split = NodeSpec {
+            id: "split".to_string(),
+            kind: NodeType::Split,
+            params: NodeParams {
+                sizes: Some(vec![1.0, 2.0]),
+                output_names: Some(names.into_iter().map(String::from).collect()),
+                ..Default::default()
+            },
+            output_shapes: HashMap::new(),
+            input_defaults: HashMap::new(),
+            metadata: serde_json::Value::Null,
+        };
+        GraphSpec {
+            nodes: vec![
+                constant_node("vec", vocab::vector(vec![1.0, 2.0, 3.0])),
+                split,
+            ],
+            edges: vec![link("vec", "split", "in")],
+            ..Default::default()
+        }
+        .with_cache()
+    };
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &split_graph(vec!["head", "tail"])).expect("named split evaluates");
+    let outputs = &rt.outputs["split"];
+    let mut keys: Vec<&str> = outputs.keys().map(String::as_str).collect();
+    keys.sort();
+    assert_eq!(keys, ["head", "tail"]);
+    assert_eq!(vocab::as_vector(&outputs["head"].value), Some(&[1.0][..]));
+    assert_eq!(
+        vocab::as_vector(&outputs["tail"].value),
+        Some(&[2.0, 3.0][..])
+    );
+
+    let mut rt = GraphRuntime::default();
+    let err =
+        evaluate_all(&mut rt, &split_graph(vec!["only"])).expect_err("name count must match sizes");
+    assert!(
+        err.contains("output_names has 1 entries but sizes has 2"),
+        "{err}"
+    );
+}
+
+#[test]
+fn case_route_picks_labelled_input_or_default() {
+    let eval = |selector: &str| {
+        let route = NodeSpec {
+            id: "route".to_string(),
+            kind: NodeType::CaseRoute,
+            params: NodeParams {
+                case_labels: Some(vec!["walk".to_string(), "run".to_string()]),
+                ..Default::default()
+            },
+            output_shapes: HashMap::new(),
+            input_defaults: HashMap::new(),
+            metadata: serde_json::Value::Null,
+        };
+        let graph = GraphSpec {
+            nodes: vec![
+                constant_node("sel", vocab::text(selector)),
+                constant_node("walk", Value::F32(1.0)),
+                constant_node("run", Value::F32(2.0)),
+                constant_node("idle", Value::F32(-1.0)),
+                route,
+            ],
+            edges: vec![
+                link("sel", "route", "selector"),
+                link("walk", "route", "case_walk"),
+                link("run", "route", "case_run"),
+                link("idle", "route", "default"),
+            ],
+            ..Default::default()
+        }
+        .with_cache();
+        let mut rt = GraphRuntime::default();
+        evaluate_all(&mut rt, &graph).expect("graph evaluates");
+        vocab::as_float(&rt.outputs["route"]["out"].value).expect("float output")
+    };
+
+    assert_eq!(eval("run"), 2.0);
+    assert_eq!(eval("walk"), 1.0);
+    assert_eq!(eval("swim"), -1.0, "unknown labels fall back to default");
+}
+
 // --- Shape inference -----------------------------------------------------
 
 #[test]
@@ -1091,6 +2121,118 @@ fn it_should_infer_vector_length_hints() {
     }
 }
 
+#[test]
+fn evaluate_types_only_reports_conflicting_vector_hints() {
+    let hinted = |id: &str, values: Vec<f32>, len: usize| {
+        let mut node = constant_node(id, Value::ArrayF32(values));
+        node.output_shapes.insert(
+            "out".to_string(),
+            Shape::new(ShapeId::Vector { len: Some(len) }),
+        );
+        node
+    };
+    let add = NodeSpec {
+        id: "sum".to_string(),
+        kind: NodeType::Add,
+        params: NodeParams::default(),
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+    let spring = NodeSpec {
+        id: "spring".to_string(),
+        kind: NodeType::Spring,
+        params: NodeParams::default(),
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+
+    let valid = GraphSpec {
+        nodes: vec![
+            hinted("a", vec![1.0, 2.0, 3.0], 3),
+            constant_node("gain", Value::F32(2.0)),
+            add.clone(),
+            spring.clone(),
+        ],
+        edges: vec![
+            link("a", "sum", "operand_1"),
+            link("gain", "sum", "operand_2"),
+            link("sum", "spring", "in"),
+        ],
+        ..Default::default()
+    };
+    let shapes = evaluate_types_only(&valid).expect("shapes line up");
+    assert_eq!(shapes["sum"], ShapeId::Vector { len: Some(3) });
+    assert_eq!(shapes["spring"], ShapeId::Vector { len: Some(3) });
+
+    let conflicting = GraphSpec {
+        nodes: vec![
+            hinted("a", vec![1.0, 2.0, 3.0], 3),
+            hinted("b", vec![1.0, 2.0, 3.0, 4.0], 4),
+            add,
+            spring,
+        ],
+        edges: vec![
+            link("a", "sum", "operand_1"),
+            link("b", "sum", "operand_2"),
+            link("sum", "spring", "in"),
+        ],
+        ..Default::default()
+    };
+    let err = evaluate_types_only(&conflicting).expect_err("vector lengths conflict");
+    assert!(
+        err.starts_with("node 'sum': input 'operand_2'"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn evaluate_types_only_matches_fixed_shapes_against_sized_vectors() {
+    let hinted = |id: &str, len: usize| {
+        let mut node = constant_node(id, Value::ArrayF32(vec![0.0; len]));
+        node.output_shapes.insert(
+            "out".to_string(),
+            Shape::new(ShapeId::Vector { len: Some(len) }),
+        );
+        node
+    };
+    let add = NodeSpec {
+        id: "sum".to_string(),
+        kind: NodeType::Add,
+        params: NodeParams::default(),
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+    let sum_of = |fixed: Value, len: usize| GraphSpec {
+        nodes: vec![
+            constant_node("fixed", fixed),
+            hinted("hinted", len),
+            add.clone(),
+        ],
+        edges: vec![
+            link("hinted", "sum", "operand_1"),
+            link("fixed", "sum", "operand_2"),
+        ],
+        ..Default::default()
+    };
+
+    let shapes =
+        evaluate_types_only(&sum_of(vocab::vec3([1.0, 2.0, 3.0]), 3)).expect("lengths agree");
+    assert_eq!(shapes["sum"], ShapeId::Vec3);
+    let shapes =
+        evaluate_types_only(&sum_of(vocab::quat([0.0, 0.0, 0.0, 1.0]), 4)).expect("lengths agree");
+    assert_eq!(shapes["sum"], ShapeId::Quat);
+
+    let err =
+        evaluate_types_only(&sum_of(vocab::vec4([0.0; 4]), 3)).expect_err("Vec4 against Vector(3)");
+    assert!(
+        err.starts_with("node 'sum': input 'operand_2'"),
+        "unexpected error: {err}"
+    );
+}
+
 // --- Declared shape error handling --------------------------------------
 
 #[test]
@@ -1160,6 +2302,7 @@ fn input_node_emits_staged_value_with_declared_shape() {
         params,
         output_shapes,
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     };
 
     let graph = GraphSpec {
@@ -1208,6 +2351,7 @@ fn input_node_coerces_vector_to_declared_vec3() {
             params,
             output_shapes,
             input_defaults: HashMap::new(),
+            metadata: serde_json::Value::Null,
         }],
         ..Default::default()
     }
@@ -1252,6 +2396,7 @@ fn input_node_missing_numeric_returns_null() {
             params,
             output_shapes,
             input_defaults: HashMap::new(),
+            metadata: serde_json::Value::Null,
         }],
         ..Default::default()
     }
@@ -1289,6 +2434,7 @@ fn input_node_missing_non_numeric_errors() {
             params,
             output_shapes,
             input_defaults: HashMap::new(),
+            metadata: serde_json::Value::Null,
         }],
         ..Default::default()
     };
@@ -1317,6 +2463,7 @@ fn input_node_requires_restaging_each_epoch() {
             params,
             output_shapes,
             input_defaults: HashMap::new(),
+            metadata: serde_json::Value::Null,
         }],
         ..Default::default()
     };
@@ -1361,6 +2508,7 @@ fn selector_projects_record_field() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![link_with_selector(
@@ -1405,72 +2553,290 @@ fn selector_projects_transform_field_and_nested_index() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
+            },
+        ],
+        edges: vec![link_with_selector(
+            "src",
+            "out",
+            "out",
+            "in",
+            vec![
+                SelectorSegment::Field("translation".to_string()),
+                SelectorSegment::Index(1),
+            ],
+        )],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &graph).expect("selector projection should succeed");
+
+    let port = rt
+        .outputs
+        .get("out")
+        .and_then(|outputs| outputs.get("out"))
+        .expect("output node present");
+
+    match &port.value {
+        Value::F32(f) => assert_eq!(*f, 42.0),
+        other => panic!("expected scalar, got {:?}", other),
+    }
+    assert!(matches!(port.shape.id, ShapeId::Scalar));
+}
+
+#[test]
+fn selector_index_out_of_bounds_errors() {
+    // Select index 5 from a vec3; should error.
+    let graph = GraphSpec {
+        nodes: vec![
+            constant_node("src", vocab::vec3([1.0, 2.0, 3.0])),
+            NodeSpec {
+                id: "out".to_string(),
+                kind: NodeType::Output,
+                params: NodeParams::default(),
+                output_shapes: HashMap::new(),
+                input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
+            },
+        ],
+        edges: vec![link_with_selector(
+            "src",
+            "out",
+            "out",
+            "in",
+            vec![SelectorSegment::Index(5)],
+        )],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    let err = evaluate_all(&mut rt, &graph).expect_err("oob selector should error");
+    assert!(
+        err.contains("out of bounds"),
+        "unexpected error content: {err}"
+    );
+}
+
+// --- Stateful nodes ------------------------------------------------------
+
+#[test]
+fn clear_results_keeps_spring_state() {
+    let spring = NodeSpec {
+        id: "spring".to_string(),
+        kind: NodeType::Spring,
+        params: NodeParams {
+            stiffness: Some(30.0),
+            damping: Some(6.0),
+            mass: Some(1.0),
+            ..Default::default()
+        },
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+    let mut spec = GraphSpec {
+        nodes: vec![
+            constant_node("target", Value::F32(0.0)),
+            spring,
+            NodeSpec {
+                id: "out".to_string(),
+                kind: NodeType::Output,
+                params: NodeParams {
+                    path: Some(TypedPath::parse("demo/spring").expect("valid path")),
+                    ..Default::default()
+                },
+                output_shapes: HashMap::new(),
+                input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
-        edges: vec![link_with_selector(
-            "src",
-            "out",
-            "out",
-            "in",
-            vec![
-                SelectorSegment::Field("translation".to_string()),
-                SelectorSegment::Index(1),
-            ],
-        )],
+        edges: vec![link("target", "spring", "in"), link("spring", "out", "in")],
+        ..Default::default()
+    }
+    .with_cache();
+
+    // Drive two identical runtimes toward a new target; only one is cleared between steps.
+    let mut cleared = GraphRuntime::default();
+    let mut reference = GraphRuntime::default();
+    for rt in [&mut cleared, &mut reference] {
+        evaluate_all(rt, &spec).expect("initial evaluate");
+    }
+    spec.nodes[0].params.value = Some(Value::F32(10.0));
+    let step = |rt: &mut GraphRuntime| {
+        rt.dt = 1.0 / 60.0;
+        rt.t += rt.dt;
+        evaluate_all(rt, &spec).expect("step");
+        vocab::as_float(&rt.outputs["spring"]["out"].value).expect("spring output")
+    };
+    step(&mut cleared);
+    step(&mut reference);
+
+    assert!(!cleared.writes.is_empty());
+    let t_before = cleared.t;
+    cleared.clear_results();
+    assert!(cleared.outputs.is_empty());
+    assert!(cleared.writes.is_empty());
+    assert_eq!(cleared.t, t_before, "time is untouched");
+    assert!(cleared.node_states.contains_key("spring"));
+
+    // The spring continues from its velocity rather than restarting from rest.
+    assert_eq!(step(&mut cleared), step(&mut reference));
+}
+
+#[test]
+fn exported_node_state_restores_spring_in_fresh_runtime() {
+    let spring = NodeSpec {
+        id: "spring".to_string(),
+        kind: NodeType::Spring,
+        params: NodeParams {
+            stiffness: Some(30.0),
+            damping: Some(6.0),
+            mass: Some(1.0),
+            ..Default::default()
+        },
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+    let mut spec = GraphSpec {
+        nodes: vec![
+            constant_node("target", vocab::vec3([0.0, 0.0, 0.0])),
+            spring,
+        ],
+        edges: vec![link("target", "spring", "in")],
         ..Default::default()
     }
     .with_cache();
 
-    let mut rt = GraphRuntime::default();
-    evaluate_all(&mut rt, &graph).expect("selector projection should succeed");
+    let step = |rt: &mut GraphRuntime, spec: &GraphSpec| {
+        rt.dt = 1.0 / 60.0;
+        rt.t += rt.dt;
+        evaluate_all(rt, spec).expect("step");
+        vocab::as_vec3(&rt.outputs["spring"]["out"].value).expect("vec3 output")
+    };
+    let mut original = GraphRuntime::default();
+    evaluate_all(&mut original, &spec).expect("initial evaluate");
+    spec.nodes[0].params.value = Some(vocab::vec3([10.0, -4.0, 2.0]));
+    for _ in 0..3 {
+        step(&mut original, &spec);
+    }
 
-    let port = rt
-        .outputs
-        .get("out")
-        .and_then(|outputs| outputs.get("out"))
-        .expect("output node present");
+    let snapshot = original.export_state();
+    assert!(snapshot["spring"].get("spring").is_some(), "{snapshot}");
+    let mut restored = GraphRuntime {
+        t: original.t,
+        ..Default::default()
+    };
+    restored.import_state(snapshot).expect("snapshot imports");
 
-    match &port.value {
-        Value::F32(f) => assert_eq!(*f, 42.0),
-        other => panic!("expected scalar, got {:?}", other),
-    }
-    assert!(matches!(port.shape.id, ShapeId::Scalar));
+    // Mid-flight velocity survives, so both runtimes take the same next step.
+    assert_eq!(step(&mut restored, &spec), step(&mut original, &spec));
+
+    let err = restored
+        .import_state(serde_json::json!({ "spring": { "spring": 1 } }))
+        .expect_err("malformed state is rejected");
+    assert!(err.starts_with("node 'spring' state:"), "{err}");
 }
 
 #[test]
-fn selector_index_out_of_bounds_errors() {
-    // Select index 5 from a vec3; should error.
-    let graph = GraphSpec {
+fn exported_node_state_recurses_into_subgraphs() {
+    let child = GraphSpec {
         nodes: vec![
-            constant_node("src", vocab::vec3([1.0, 2.0, 3.0])),
             NodeSpec {
-                id: "out".to_string(),
-                kind: NodeType::Output,
-                params: NodeParams::default(),
+                id: "x".to_string(),
+                kind: NodeType::Input,
+                params: NodeParams {
+                    path: Some(TypedPath::parse("sub/x").expect("typed path")),
+                    ..Default::default()
+                },
+                output_shapes: HashMap::new(),
+                input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
+            },
+            NodeSpec {
+                id: "spring".to_string(),
+                kind: NodeType::Spring,
+                params: NodeParams {
+                    stiffness: Some(30.0),
+                    damping: Some(6.0),
+                    mass: Some(1.0),
+                    ..Default::default()
+                },
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
-        edges: vec![link_with_selector(
-            "src",
-            "out",
-            "out",
-            "in",
-            vec![SelectorSegment::Index(5)],
-        )],
+        edges: vec![link("x", "spring", "in")],
+        ..Default::default()
+    };
+    let subgraph = NodeSpec {
+        id: "sub".to_string(),
+        kind: NodeType::Subgraph,
+        params: NodeParams {
+            subgraph: Some(Box::new(SubgraphSpec {
+                graph: child,
+                inputs: vec![SubgraphInput {
+                    port: "x".to_string(),
+                    path: TypedPath::parse("sub/x").expect("typed path"),
+                }],
+                outputs: vec![SubgraphOutput {
+                    port: "out".to_string(),
+                    node_id: "spring".to_string(),
+                    output: "out".to_string(),
+                }],
+            })),
+            ..Default::default()
+        },
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+    let mut spec = GraphSpec {
+        nodes: vec![constant_node("target", Value::F32(0.0)), subgraph],
+        edges: vec![link("target", "sub", "x")],
         ..Default::default()
     }
     .with_cache();
 
-    let mut rt = GraphRuntime::default();
-    let err = evaluate_all(&mut rt, &graph).expect_err("oob selector should error");
+    let step = |rt: &mut GraphRuntime, spec: &GraphSpec| {
+        rt.dt = 1.0 / 60.0;
+        rt.t += rt.dt;
+        evaluate_all(rt, spec).expect("step");
+        vocab::as_float(&rt.outputs["sub"]["out"].value).expect("float output")
+    };
+    let mut original = GraphRuntime::default();
+    evaluate_all(&mut original, &spec).expect("initial evaluate");
+    spec.nodes[0].params.value = Some(Value::F32(10.0));
+    for _ in 0..3 {
+        step(&mut original, &spec);
+    }
+
+    let snapshot = original.export_state();
     assert!(
-        err.contains("out of bounds"),
-        "unexpected error content: {err}"
+        snapshot["sub"]["subgraph"]["spring"]
+            .get("spring")
+            .is_some(),
+        "{snapshot}"
     );
-}
+    let mut restored = GraphRuntime {
+        t: original.t,
+        ..Default::default()
+    };
+    restored.import_state(snapshot).expect("snapshot imports");
+    assert_eq!(step(&mut restored, &spec), step(&mut original, &spec));
 
-// --- Stateful nodes ------------------------------------------------------
+    let err = restored
+        .import_state(serde_json::json!({ "sub": { "subgraph": { "spring": { "spring": 1 } } } }))
+        .expect_err("malformed child state is rejected");
+    assert!(
+        err.starts_with("node 'sub' state: node 'spring' state:"),
+        "{err}"
+    );
+}
 
 #[test]
 fn spring_node_transitions_toward_new_target() {
@@ -1485,6 +2851,7 @@ fn spring_node_transitions_toward_new_target() {
         },
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     };
 
     let mut spec = GraphSpec {
@@ -1550,6 +2917,7 @@ fn damp_node_smooths_toward_target() {
         },
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     };
 
     let mut spec = GraphSpec {
@@ -1612,6 +2980,7 @@ fn slew_node_limits_rate_of_change() {
         },
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     };
 
     let mut spec = GraphSpec {
@@ -1667,6 +3036,290 @@ fn slew_node_limits_rate_of_change() {
     );
 }
 
+#[test]
+fn edge_node_reports_transitions_across_frames() {
+    let edge = NodeSpec {
+        id: "edge".to_string(),
+        kind: NodeType::Edge,
+        params: NodeParams::default(),
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+
+    let mut spec = GraphSpec {
+        nodes: vec![constant_node("signal", Value::Boolean(true)), edge],
+        edges: vec![link("signal", "edge", "in")],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let read_edges = |rt: &GraphRuntime| -> (bool, bool, bool) {
+        let outputs = rt.outputs.get("edge").expect("edge outputs present");
+        let read = |key: &str| match outputs.get(key).map(|pv| &pv.value) {
+            Some(Value::Boolean(b)) => *b,
+            other => panic!("expected bool for '{key}', got {:?}", other),
+        };
+        (read("rising"), read("falling"), read("changed"))
+    };
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &spec).expect("first frame");
+    assert_eq!(
+        read_edges(&rt),
+        (false, false, false),
+        "first frame has no previous state"
+    );
+
+    evaluate_all(&mut rt, &spec).expect("steady frame");
+    assert_eq!(read_edges(&rt), (false, false, false));
+
+    spec.nodes[0].params.value = Some(Value::Boolean(false));
+    evaluate_all(&mut rt, &spec).expect("falling frame");
+    assert_eq!(read_edges(&rt), (false, true, true));
+
+    spec.nodes[0].params.value = Some(Value::F32(1.0));
+    evaluate_all(&mut rt, &spec).expect("rising frame");
+    assert_eq!(read_edges(&rt), (true, false, true));
+
+    evaluate_all(&mut rt, &spec).expect("held frame");
+    assert_eq!(read_edges(&rt), (false, false, false));
+}
+
+#[test]
+fn sample_hold_only_updates_on_triggered_frames() {
+    let hold = NodeSpec {
+        id: "hold".to_string(),
+        kind: NodeType::SampleHold,
+        params: NodeParams::default(),
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+
+    let mut spec = GraphSpec {
+        nodes: vec![
+            constant_node("signal", vocab::vec3([1.0, 2.0, 3.0])),
+            constant_node("trigger", Value::Boolean(false)),
+            hold,
+        ],
+        edges: vec![
+            link("signal", "hold", "in"),
+            link("trigger", "hold", "trigger"),
+        ],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let held = |rt: &GraphRuntime| -> [f32; 3] {
+        let outputs = rt.outputs.get("hold").expect("hold outputs present");
+        expect_vec3(&outputs.get("out").expect("out port present").value)
+    };
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &spec).expect("first frame");
+    assert_eq!(held(&rt), [1.0, 2.0, 3.0], "first frame samples the input");
+
+    spec.nodes[0].params.value = Some(vocab::vec3([4.0, 5.0, 6.0]));
+    evaluate_all(&mut rt, &spec).expect("untriggered frame");
+    assert_eq!(held(&rt), [1.0, 2.0, 3.0], "untriggered frame holds");
+
+    spec.nodes[1].params.value = Some(Value::Boolean(true));
+    evaluate_all(&mut rt, &spec).expect("triggered frame");
+    assert_eq!(held(&rt), [4.0, 5.0, 6.0], "triggered frame samples");
+
+    spec.nodes[0].params.value = Some(vocab::vec3([7.0, 8.0, 9.0]));
+    spec.nodes[1].params.value = Some(Value::Boolean(false));
+    evaluate_all(&mut rt, &spec).expect("released frame");
+    assert_eq!(held(&rt), [4.0, 5.0, 6.0], "released trigger holds");
+}
+
+#[test]
+fn delay_node_lags_input_by_configured_frames() {
+    let delay = NodeSpec {
+        id: "delay".to_string(),
+        kind: NodeType::Delay,
+        params: NodeParams {
+            frames: Some(2),
+            default: Some(Value::F32(-1.0)),
+            ..Default::default()
+        },
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+
+    let mut spec = GraphSpec {
+        nodes: vec![constant_node("counter", Value::F32(0.0)), delay],
+        edges: vec![link("counter", "delay", "in")],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    for frame in 0..6 {
+        spec.nodes[0].params.value = Some(Value::F32(frame as f32));
+        evaluate_all(&mut rt, &spec).expect("delay should evaluate");
+        let delayed = match rt
+            .outputs
+            .get("delay")
+            .and_then(|map| map.get("out"))
+            .map(|pv| pv.value.clone())
+            .expect("delay output")
+        {
+            Value::F32(f) => f,
+            other => panic!("expected float, got {:?}", other),
+        };
+        let expected = if frame < 2 { -1.0 } else { (frame - 2) as f32 };
+        assert_eq!(delayed, expected, "frame {frame} should lag by two");
+    }
+
+    // A new input shape restarts the history, so the default shows again.
+    spec.nodes[0].params.value = Some(vocab::vec3([1.0, 2.0, 3.0]));
+    evaluate_all(&mut rt, &spec).expect("reshaped input");
+    let outputs = rt.outputs.get("delay").expect("delay outputs present");
+    assert_eq!(
+        outputs.get("out").map(|pv| pv.value.clone()),
+        Some(Value::F32(-1.0))
+    );
+}
+
+#[test]
+fn ema_step_response_matches_closed_form() {
+    let alpha = 0.3f32;
+    let ema = NodeSpec {
+        id: "ema".to_string(),
+        kind: NodeType::Ema,
+        params: NodeParams {
+            alpha: Some(alpha),
+            ..Default::default()
+        },
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+    let mut spec = GraphSpec {
+        nodes: vec![constant_node("signal", Value::F32(0.0)), ema],
+        edges: vec![link("signal", "ema", "in")],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &spec).expect("ema seeds from the first input");
+    spec.nodes[0].params.value = Some(Value::F32(1.0));
+
+    // After n frames of a unit step: 1 - (1 - alpha)^n, regardless of dt.
+    for n in 1..=5 {
+        rt.dt = 0.25 * n as f32;
+        evaluate_all(&mut rt, &spec).expect("ema should evaluate");
+        let actual = vocab::as_float(&rt.outputs["ema"]["out"].value).expect("float output");
+        let expected = 1.0 - (1.0 - alpha).powi(n);
+        assert!(
+            (actual - expected).abs() < 1e-6,
+            "frame {n}: {actual} vs {expected}"
+        );
+    }
+}
+
+#[test]
+fn feedback_loop_accumulates_without_cycle() {
+    // prev (tap of acc) + 1 -> add -> acc (source): an integrator stepping by one per frame.
+    let tap = NodeSpec {
+        id: "prev".to_string(),
+        kind: NodeType::FeedbackTap,
+        params: NodeParams {
+            source: Some("acc".to_string()),
+            ..Default::default()
+        },
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+    let add = NodeSpec {
+        id: "add".to_string(),
+        kind: NodeType::Add,
+        params: NodeParams::default(),
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+    let source = NodeSpec {
+        id: "acc".to_string(),
+        kind: NodeType::FeedbackSource,
+        params: NodeParams::default(),
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+    let spec = GraphSpec {
+        nodes: vec![source, tap, constant_node("one", Value::F32(1.0)), add],
+        edges: vec![
+            link("prev", "add", "operand_1"),
+            link("one", "add", "operand_2"),
+            link("add", "acc", "in"),
+        ],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    for frame in 1..=4 {
+        evaluate_all(&mut rt, &spec).expect("feedback loop must not be rejected as a cycle");
+        assert_eq!(
+            vocab::as_float(&rt.outputs["acc"]["out"].value),
+            Some(frame as f32)
+        );
+        assert_eq!(
+            vocab::as_float(&rt.outputs["prev"]["out"].value),
+            Some((frame - 1) as f32)
+        );
+    }
+}
+
+#[test]
+fn moving_average_ramps_over_window_after_step() {
+    let average = NodeSpec {
+        id: "avg".to_string(),
+        kind: NodeType::MovingAverage,
+        params: NodeParams {
+            window: Some(4),
+            ..Default::default()
+        },
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    };
+    let mut spec = GraphSpec {
+        nodes: vec![constant_node("signal", Value::F32(0.0)), average],
+        edges: vec![link("signal", "avg", "in")],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    let mut eval = |spec: &GraphSpec| {
+        evaluate_all(&mut rt, spec).expect("moving average should evaluate");
+        vocab::as_float(&rt.outputs["avg"]["out"].value).expect("float output")
+    };
+
+    // Fill the window with zeros, then step to 1.0.
+    for _ in 0..4 {
+        assert_eq!(eval(&spec), 0.0);
+    }
+    spec.nodes[0].params.value = Some(Value::F32(1.0));
+    let ramp: Vec<f32> = (0..5).map(|_| eval(&spec)).collect();
+    assert_eq!(ramp, vec![0.25, 0.5, 0.75, 1.0, 1.0]);
+
+    // A new layout restarts the window.
+    spec.nodes[0].params.value = Some(vocab::vec3([2.0, 4.0, 6.0]));
+    evaluate_all(&mut rt, &spec).expect("reshaped input");
+    assert_eq!(
+        expect_vec3(&rt.outputs["avg"]["out"].value),
+        [2.0, 4.0, 6.0]
+    );
+}
+
 // --- End-to-end: Input → selector → math → Output ------------------------
 
 #[test]
@@ -1700,6 +3353,7 @@ fn end_to_end_input_selector_scalar_math_output() {
         params: input_params,
         output_shapes: input_output_shapes,
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     };
 
     let add_node = NodeSpec {
@@ -1708,6 +3362,7 @@ fn end_to_end_input_selector_scalar_math_output() {
         params: NodeParams::default(),
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     };
 
     let output_node = NodeSpec {
@@ -1719,6 +3374,7 @@ fn end_to_end_input_selector_scalar_math_output() {
         },
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     };
 
     let graph = GraphSpec {
@@ -1836,6 +3492,7 @@ fn centered_remap_handles_anchor_segments() {
                 },
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "remap".to_string(),
@@ -1843,6 +3500,7 @@ fn centered_remap_handles_anchor_segments() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: defaults,
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![link("input", "remap", "in")],
@@ -1941,6 +3599,7 @@ fn centered_remap_supports_asymmetric_ranges_and_vectors() {
                 },
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "remap".to_string(),
@@ -1948,6 +3607,7 @@ fn centered_remap_supports_asymmetric_ranges_and_vectors() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: defaults,
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![link("input", "remap", "in")],
@@ -2011,6 +3671,7 @@ fn to_vector_packs_floats() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![
@@ -2043,6 +3704,7 @@ fn from_vector_unpacks_with_nan_padding() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "s1".to_string(),
@@ -2050,6 +3712,7 @@ fn from_vector_unpacks_with_nan_padding() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "s2".to_string(),
@@ -2057,6 +3720,7 @@ fn from_vector_unpacks_with_nan_padding() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "s3".to_string(),
@@ -2064,6 +3728,7 @@ fn from_vector_unpacks_with_nan_padding() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "s4".to_string(),
@@ -2071,6 +3736,7 @@ fn from_vector_unpacks_with_nan_padding() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "s5".to_string(),
@@ -2078,6 +3744,7 @@ fn from_vector_unpacks_with_nan_padding() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![
@@ -2125,6 +3792,7 @@ fn from_vector_underscore_naming_convention() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "s1".to_string(),
@@ -2132,6 +3800,7 @@ fn from_vector_underscore_naming_convention() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "s2".to_string(),
@@ -2139,6 +3808,7 @@ fn from_vector_underscore_naming_convention() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
             NodeSpec {
                 id: "s3".to_string(),
@@ -2146,6 +3816,7 @@ fn from_vector_underscore_naming_convention() {
                 params: NodeParams::default(),
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![
@@ -2216,6 +3887,7 @@ fn noise_nodes_are_deterministic_and_in_range() {
                 },
                 output_shapes: HashMap::new(),
                 input_defaults: defaults.clone(),
+                metadata: serde_json::Value::Null,
             }],
             edges: vec![],
         });
@@ -2253,6 +3925,62 @@ fn noise_nodes_are_deterministic_and_in_range() {
     }
 }
 
+#[test]
+fn runtime_seed_mixes_into_noise_nodes() {
+    let spec = graph_spec!({
+        nodes: vec![NodeSpec {
+            id: "n".to_string(),
+            kind: NodeType::PerlinNoise,
+            params: NodeParams {
+                noise_seed: Some(7.0),
+                octaves: Some(3.0),
+                ..Default::default()
+            },
+            output_shapes: HashMap::new(),
+            input_defaults: HashMap::from([
+                (
+                    "x".to_string(),
+                    InputDefault {
+                        value: Value::F32(1.37),
+                        shape: None,
+                    },
+                ),
+                (
+                    "y".to_string(),
+                    InputDefault {
+                        value: Value::F32(0.61),
+                        shape: None,
+                    },
+                ),
+            ]),
+            metadata: serde_json::Value::Null,
+        }],
+        edges: vec![],
+    });
+    let sample = |rt: &mut GraphRuntime| -> f32 {
+        evaluate_all(rt, &spec).expect("noise should evaluate");
+        match rt
+            .outputs
+            .get("n")
+            .and_then(|o| o.get("out"))
+            .map(|p| &p.value)
+        {
+            Some(Value::F32(f)) => *f,
+            other => panic!("expected Float, got {:?}", other),
+        }
+    };
+
+    let unseeded = sample(&mut GraphRuntime::default());
+    assert_eq!(sample(&mut GraphRuntime::with_seed(0)), unseeded);
+
+    let a = sample(&mut GraphRuntime::with_seed(1234));
+    assert_eq!(sample(&mut GraphRuntime::with_seed(1234)), a);
+    let b = sample(&mut GraphRuntime::with_seed(98765));
+    assert_eq!(sample(&mut GraphRuntime::with_seed(98765)), b);
+    assert_ne!(a, b, "different runtime seeds should change noise output");
+    assert_ne!(a, unseeded);
+}
+
 // --- path-less Output: keyed record batches ----------------------------------
 
 use uuid::Uuid;
@@ -2295,6 +4023,7 @@ fn pathless_output_node(id: &str) -> NodeSpec {
         },
         output_shapes: HashMap::new(),
         input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
     }
 }
 
@@ -2344,9 +4073,9 @@ fn pathless_output_writes_nothing_for_an_empty_batch() {
 }
 
 #[test]
-fn pathless_output_keeps_batch_order_for_a_repeated_key() {
-    // Batch order is preserved in the write set; the per-tick flush (a map)
-    // keeps the last write. Explicit combination is VIZ-76's ground.
+fn pathless_output_keeps_the_last_write_for_a_repeated_key() {
+    // The write set holds one write per path, so the later record overwrites
+    // the earlier one. Explicit combination is VIZ-76's ground.
     let graph = GraphSpec {
         nodes: vec![
             constant_node(
@@ -2366,9 +4095,8 @@ fn pathless_output_keeps_batch_order_for_a_repeated_key() {
     let mut rt = GraphRuntime::default();
     evaluate_all(&mut rt, &graph).expect("graph should evaluate");
     let writes: Vec<_> = rt.writes.iter().collect();
-    assert_eq!(writes.len(), 2);
-    assert!(matches!(writes[0].value, Value::F32(v) if v == 1.0));
-    assert!(matches!(writes[1].value, Value::F32(v) if v == 2.0));
+    assert_eq!(writes.len(), 1);
+    assert!(matches!(writes[0].value, Value::F32(v) if v == 2.0));
 }
 
 #[test]
@@ -2432,6 +4160,7 @@ fn output_path_takes_precedence_over_key_fields() {
                 },
                 output_shapes: HashMap::new(),
                 input_defaults: HashMap::new(),
+                metadata: serde_json::Value::Null,
             },
         ],
         edges: vec![link("src", "sink", "in")],
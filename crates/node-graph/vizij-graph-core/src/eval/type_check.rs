@@ -0,0 +1,214 @@
+//! Static shape propagation over a [`GraphSpec`] without evaluating any node.
+
+use hashbrown::HashMap;
+use vizij_api_core::ShapeId;
+
+use crate::schema::{registry, NodeSignature, PortType};
+use crate::topo::topo_order;
+use crate::types::{GraphSpec, NodeId, NodeSpec, NodeType};
+
+use super::shape_helpers::infer_shape_id;
+
+/// Check that every node type in `spec` is available and that output shapes line up, without
+/// running the graph.
+///
+/// Shapes come from declared `output_shapes`, literal values of constant nodes, and concrete
+/// registry port types, and flow through elementwise numeric nodes from their connected inputs
+/// (scalars broadcast). The first conflict — between two operands of an elementwise node, or
+/// between a declared and an inferred output shape — is returned as an error.
+///
+/// Nothing is evaluated: stateful nodes are not advanced, no IK is solved, and no writes are
+/// produced. Nodes whose `out` shape cannot be determined statically (e.g. behind a selector or
+/// an `Input` without a declared shape) are omitted from the result.
+pub fn evaluate_types_only(spec: &GraphSpec) -> Result<HashMap<NodeId, ShapeId>, String> {
+    let order = topo_order(&spec.nodes, &spec.edges)?;
+    let connections = spec.input_connections()?;
+    let signatures: HashMap<NodeType, NodeSignature> = registry()
+        .nodes
+        .into_iter()
+        .map(|sig| (sig.type_id.clone(), sig))
+        .collect();
+    let nodes: HashMap<&str, &NodeSpec> = spec.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+    let mut ports: HashMap<NodeId, HashMap<String, ShapeId>> = HashMap::new();
+
+    for id in &order {
+        let node = nodes
+            .get(id.as_str())
+            .ok_or_else(|| format!("type check referenced missing node '{}'", id))?;
+        let sig = signatures
+            .get(&node.kind)
+            .ok_or_else(|| format!("node '{}': type {:?} is not registered", id, node.kind))?;
+        if !sig.available {
+            return Err(format!(
+                "node '{}': {:?} requires the '{}' feature, which this build lacks",
+                id,
+                node.kind,
+                sig.requires_feature.unwrap_or("unknown")
+            ));
+        }
+
+        // Known shapes of connected inputs, in port-name order so errors are deterministic.
+        let mut inputs: Vec<(&str, ShapeId)> = Vec::new();
+        if let Some(conns) = connections.get(id) {
+            let mut names: Vec<&String> = conns.keys().collect();
+            names.sort();
+            for name in names {
+                let conn = &conns[name];
+                let shape = match (&conn.node_id, &conn.selector) {
+                    // Selectors project into the source value; the sub-shape is not tracked.
+                    (Some(_), Some(_)) => None,
+                    (Some(source), None) => ports
+                        .get(source)
+                        .and_then(|outputs| outputs.get(&conn.output_key))
+                        .cloned(),
+                    (None, _) => conn
+                        .default_shape
+                        .as_ref()
+                        .map(|shape| shape.id.clone())
+                        .or_else(|| conn.default_value.as_ref().map(infer_shape_id)),
+                };
+                if let Some(shape) = shape {
+                    inputs.push((name.as_str(), shape));
+                }
+            }
+        }
+
+        let inferred = infer_out_shape(node, sig, &inputs)?;
+        let mut outputs: HashMap<String, ShapeId> = node
+            .output_shapes
+            .iter()
+            .map(|(key, shape)| (key.clone(), shape.id.clone()))
+            .collect();
+        if let Some(inferred) = inferred {
+            match outputs.get("out") {
+                Some(declared) if !compatible(declared, &inferred) => {
+                    return Err(format!(
+                        "node '{}': declared output shape {:?} conflicts with inferred {:?}",
+                        id, declared, inferred
+                    ));
+                }
+                Some(_) => {}
+                None => {
+                    outputs.insert("out".to_string(), inferred);
+                }
+            }
+        }
+        ports.insert(id.clone(), outputs);
+    }
+
+    Ok(ports
+        .into_iter()
+        .filter_map(|(id, mut outputs)| outputs.remove("out").map(|shape| (id, shape)))
+        .collect())
+}
+
+/// Statically inferred `out` shape of `node`, or `None` when it depends on runtime values.
+fn infer_out_shape(
+    node: &NodeSpec,
+    sig: &NodeSignature,
+    inputs: &[(&str, ShapeId)],
+) -> Result<Option<ShapeId>, String> {
+    if matches!(node.kind, NodeType::Constant | NodeType::VectorConstant) {
+        return Ok(node.params.value.as_ref().map(infer_shape_id));
+    }
+    if is_elementwise(&node.kind) {
+        return unify_operands(&node.id, inputs);
+    }
+    let out = sig.outputs.iter().find(|port| port.id == "out");
+    Ok(out.and_then(|port| match port.ty {
+        PortType::Bool => Some(ShapeId::Bool),
+        PortType::Vec3 => Some(ShapeId::Vec3),
+        PortType::Quat => Some(ShapeId::Quat),
+        PortType::Transform => Some(ShapeId::Transform),
+        // Float/Vector/Any ports accept broadcast numeric values, so their shape is dynamic.
+        PortType::Float | PortType::Vector | PortType::Any => None,
+    }))
+}
+
+/// Nodes that apply a numeric op per component, broadcasting scalar operands.
+fn is_elementwise(kind: &NodeType) -> bool {
+    matches!(
+        kind,
+        NodeType::Add
+            | NodeType::Subtract
+            | NodeType::Multiply
+            | NodeType::Divide
+            | NodeType::Power
+            | NodeType::Modulo
+            | NodeType::Min
+            | NodeType::Max
+            | NodeType::Abs
+            | NodeType::Sqrt
+            | NodeType::Sign
+            | NodeType::Saturate
+            | NodeType::Negate
+            | NodeType::Reciprocal
+            | NodeType::Round
+            | NodeType::Quantize
+            | NodeType::Sin
+            | NodeType::Cos
+            | NodeType::Tan
+            | NodeType::Clamp
+            | NodeType::Spring
+            | NodeType::Damp
+            | NodeType::Slew
+            | NodeType::Ema
+            | NodeType::MovingAverage
+    )
+}
+
+/// Combine operand shapes of an elementwise node: scalars broadcast, everything else must agree.
+fn unify_operands(node_id: &str, inputs: &[(&str, ShapeId)]) -> Result<Option<ShapeId>, String> {
+    let mut result: Option<(&str, ShapeId)> = None;
+    for &(port, ref shape) in inputs {
+        result = match result {
+            None => Some((port, shape.clone())),
+            Some((_, ShapeId::Scalar)) => Some((port, shape.clone())),
+            Some(current) if *shape == ShapeId::Scalar => Some(current),
+            Some((from, current)) if compatible(&current, shape) => {
+                // Keep the more specific shape: a fixed shape over a vector, a sized vector over
+                // an unsized one.
+                if matches!(current, ShapeId::Vector { .. })
+                    && *shape != (ShapeId::Vector { len: None })
+                {
+                    Some((port, shape.clone()))
+                } else {
+                    Some((from, current))
+                }
+            }
+            Some((from, current)) => {
+                return Err(format!(
+                    "node '{}': input '{}' has shape {:?}, incompatible with {:?} from input '{}'",
+                    node_id, port, shape, current, from
+                ));
+            }
+        };
+    }
+    Ok(result.map(|(_, shape)| shape))
+}
+
+/// Shapes match exactly, or one side is a vector whose length agrees with the other: an unsized
+/// vector matches any vector-like shape, `Vector(n)` matches shapes with `n` components (`Vec3`,
+/// `Quat`, `Vector(n)`, ...).
+fn compatible(a: &ShapeId, b: &ShapeId) -> bool {
+    match (a, b) {
+        (ShapeId::Vector { len: None }, other) | (other, ShapeId::Vector { len: None }) => {
+            a == b || vector_len(other).is_some()
+        }
+        (ShapeId::Vector { len: Some(n) }, other) | (other, ShapeId::Vector { len: Some(n) }) => {
+            vector_len(other) == Some(*n)
+        }
+        _ => a == b,
+    }
+}
+
+/// Component count of a vector-like shape, or `None` for other shapes and unsized vectors.
+fn vector_len(shape: &ShapeId) -> Option<usize> {
+    match shape {
+        ShapeId::Vec2 => Some(2),
+        ShapeId::Vec3 => Some(3),
+        ShapeId::Vec4 | ShapeId::Quat | ShapeId::ColorRgba => Some(4),
+        ShapeId::Vector { len } => *len,
+        _ => None,
+    }
+}
@@ -0,0 +1,504 @@
+// URDF kinematics node tests (FK link poses and IK solver behaviour).
+
+use super::urdfik::{hash_urdf_config, IkKey, UrdfCache};
+use super::*;
+use crate::types::{
+    EdgeInputEndpoint, EdgeOutputEndpoint, EdgeSpec, GraphSpec, NodeParams, NodeSpec, NodeType,
+};
+use hashbrown::HashMap;
+use vizij_api_core::value as vocab;
+use vizij_api_core::Value;
+
+/// Six-joint sample arm from `fixtures/node_graphs/urdf-ik-position.json`.
+const POSE_URDF: &str = r#"<robot name="sample_arm">
+  <link name="base_link" />
+  <link name="link1" />
+  <link name="link2" />
+  <link name="link3" />
+  <link name="link4" />
+  <link name="link5" />
+  <link name="link6" />
+  <link name="tool" />
+
+  <joint name="joint1" type="revolute">
+    <parent link="base_link" />
+    <child link="link1" />
+    <origin xyz="0 0 0.1" rpy="0 0 0" />
+    <axis xyz="0 0 1" />
+    <limit lower="-3.1416" upper="3.1416" effort="1" velocity="1" />
+  </joint>
+
+  <joint name="joint2" type="revolute">
+    <parent link="link1" />
+    <child link="link2" />
+    <origin xyz="0.2 0 0" rpy="0 0 0" />
+    <axis xyz="0 1 0" />
+    <limit lower="-3.1416" upper="3.1416" effort="1" velocity="1" />
+  </joint>
+
+  <joint name="joint3" type="revolute">
+    <parent link="link2" />
+    <child link="link3" />
+    <origin xyz="0.2 0 0" rpy="0 0 0" />
+    <axis xyz="1 0 0" />
+    <limit lower="-3.1416" upper="3.1416" effort="1" velocity="1" />
+  </joint>
+
+  <joint name="joint4" type="revolute">
+    <parent link="link3" />
+    <child link="link4" />
+    <origin xyz="0.2 0 0" rpy="0 0 0" />
+    <axis xyz="0 0 1" />
+    <limit lower="-3.1416" upper="3.1416" effort="1" velocity="1" />
+  </joint>
+
+  <joint name="joint5" type="revolute">
+    <parent link="link4" />
+    <child link="link5" />
+    <origin xyz="0.15 0 0" rpy="0 0 0" />
+    <axis xyz="0 1 0" />
+    <limit lower="-3.1416" upper="3.1416" effort="1" velocity="1" />
+  </joint>
+
+  <joint name="joint6" type="revolute">
+    <parent link="link5" />
+    <child link="link6" />
+    <origin xyz="0.1 0 0" rpy="0 0 0" />
+    <axis xyz="1 0 0" />
+    <limit lower="-3.1416" upper="3.1416" effort="1" velocity="1" />
+  </joint>
+
+  <joint name="tool_joint" type="fixed">
+    <parent link="link6" />
+    <child link="tool" />
+    <origin xyz="0.1 0 0" rpy="0 0 0" />
+  </joint>
+</robot>"#;
+
+const JOINTS: [f32; 6] = [0.3, -0.4, 0.2, 0.5, -0.1, 0.25];
+
+fn constant_node(id: &str, value: Value) -> NodeSpec {
+    NodeSpec {
+        id: id.to_string(),
+        kind: NodeType::Constant,
+        params: NodeParams {
+            value: Some(value),
+            ..Default::default()
+        },
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    }
+}
+
+fn link(from: &str, to: &str, input: &str) -> EdgeSpec {
+    EdgeSpec {
+        from: EdgeOutputEndpoint {
+            node_id: from.to_string(),
+            output: "out".to_string(),
+        },
+        to: EdgeInputEndpoint {
+            node_id: to.to_string(),
+            input: input.to_string(),
+        },
+        selector: None,
+    }
+}
+
+fn urdf_node(id: &str, kind: NodeType, params: NodeParams) -> NodeSpec {
+    NodeSpec {
+        id: id.to_string(),
+        kind,
+        params: NodeParams {
+            urdf_xml: Some(POSE_URDF.to_string()),
+            root_link: Some("base_link".to_string()),
+            tip_link: Some("tool".to_string()),
+            ..params
+        },
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    }
+}
+
+fn assert_close(actual: &[f32], expected: &[f32], tol: f32) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < tol, "expected {expected:?}, got {actual:?}");
+    }
+}
+
+#[test]
+fn urdf_fk_reports_world_transform_per_link() {
+    let spec = GraphSpec {
+        nodes: vec![
+            constant_node("joints", vocab::vector(JOINTS.to_vec())),
+            urdf_node("fk", NodeType::UrdfFk, NodeParams::default()),
+        ],
+        edges: vec![link("joints", "fk", "joints")],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &spec).expect("fk should evaluate");
+    let fk = &rt.outputs["fk"];
+    let end = vocab::as_transform(&fk["transform"].value).expect("tip transform");
+
+    let links = vocab::as_record(&fk["links"].value).expect("links record");
+    let by_name: HashMap<&str, &Value> = links.into_iter().collect();
+    for name in ["link1", "link3", "link6", "tool"] {
+        assert!(by_name.contains_key(name), "missing link '{name}'");
+    }
+    let tool = vocab::as_transform(by_name["tool"]).expect("tool link transform");
+    assert_close(&tool.translation, &end.translation, 1e-5);
+    assert_close(&tool.rotation, &end.rotation, 1e-5);
+    assert_eq!(tool.scale, [1.0, 1.0, 1.0]);
+
+    // The first moving joint sits 0.1 m above the base and only rotates about Z.
+    let link1 = vocab::as_transform(by_name["link1"]).expect("link1 transform");
+    assert_close(&link1.translation, &[0.0, 0.0, 0.1], 1e-5);
+}
+
+#[test]
+fn urdf_ik_flags_joints_resting_on_limits() {
+    // Narrow the base yaw joint so a target off to the side drives it into a limit. The clamp may
+    // keep the solver from converging, so run fail-soft and only check the flags.
+    let urdf = POSE_URDF.replacen(
+        r#"<axis xyz="0 0 1" />
+    <limit lower="-3.1416" upper="3.1416" effort="1" velocity="1" />"#,
+        r#"<axis xyz="0 0 1" />
+    <limit lower="-0.1" upper="0.1" effort="1" velocity="1" />"#,
+        1,
+    );
+    assert_ne!(urdf, POSE_URDF, "joint1 limit should be rewritten");
+
+    let mut ik = urdf_node(
+        "ik",
+        NodeType::UrdfIkPosition,
+        NodeParams {
+            max_iters: Some(512),
+            tol_pos: Some(0.02),
+            fail_soft: Some(true),
+            ..Default::default()
+        },
+    );
+    ik.params.urdf_xml = Some(urdf);
+    let spec = GraphSpec {
+        nodes: vec![constant_node("target", vocab::vec3([0.8, 0.2, 0.1])), ik],
+        edges: vec![link("target", "ik", "target_pos")],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &spec).expect("ik should evaluate");
+    let ik = &rt.outputs["ik"];
+    let angles = vocab::as_record(&ik["out"].value).expect("joint record");
+    let joint1 = angles
+        .iter()
+        .find(|(name, _)| *name == "joint1")
+        .and_then(|(_, v)| vocab::as_float(v))
+        .expect("joint1 angle");
+    assert!(
+        (joint1.abs() - 0.1).abs() < 1e-4,
+        "joint1 should rest on a limit, got {joint1}"
+    );
+
+    let flags = vocab::as_record(&ik["at_limit"].value).expect("at_limit record");
+    assert_eq!(flags.len(), 6);
+    for (name, flag) in flags {
+        let expected = name == "joint1";
+        assert_eq!(vocab::as_bool(flag), Some(expected), "{name} limit flag");
+    }
+}
+
+#[test]
+fn urdf_ik_position_solves_with_jacobian_and_ccd() {
+    let target = [0.6, 0.25, 0.3];
+    for solver in ["jacobian", "ccd"] {
+        let spec = GraphSpec {
+            nodes: vec![
+                constant_node("target", vocab::vec3(target)),
+                urdf_node(
+                    "ik",
+                    NodeType::UrdfIkPosition,
+                    NodeParams {
+                        solver: Some(solver.to_string()),
+                        max_iters: Some(512),
+                        tol_pos: Some(0.005),
+                        ..Default::default()
+                    },
+                ),
+                urdf_node("fk", NodeType::UrdfFk, NodeParams::default()),
+            ],
+            edges: vec![
+                link("target", "ik", "target_pos"),
+                link("ik", "fk", "joints"),
+            ],
+            ..Default::default()
+        }
+        .with_cache();
+
+        let mut rt = GraphRuntime::default();
+        evaluate_all(&mut rt, &spec).unwrap_or_else(|err| panic!("{solver} failed: {err}"));
+        let reached = vocab::as_vec3(&rt.outputs["fk"]["position"].value).expect("fk position");
+        assert_close(&reached, &target, 0.005);
+    }
+}
+
+#[test]
+fn urdf_ik_rejects_unknown_solver() {
+    let spec = GraphSpec {
+        nodes: vec![
+            constant_node("target", vocab::vec3([0.6, 0.0, 0.3])),
+            urdf_node(
+                "ik",
+                NodeType::UrdfIkPosition,
+                NodeParams {
+                    solver: Some("fabrik".to_string()),
+                    ..Default::default()
+                },
+            ),
+        ],
+        edges: vec![link("target", "ik", "target_pos")],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    let err = evaluate_all(&mut rt, &spec).expect_err("unknown solver should fail");
+    assert!(err.contains("solver 'fabrik'"), "unexpected error: {err}");
+}
+
+#[test]
+fn urdf_ik_ccd_rejects_joint_weights() {
+    let spec = GraphSpec {
+        nodes: vec![
+            constant_node("target", vocab::vec3([0.6, 0.0, 0.3])),
+            urdf_node(
+                "ik",
+                NodeType::UrdfIkPosition,
+                NodeParams {
+                    solver: Some("ccd".to_string()),
+                    weights: Some(vec![1.0; JOINTS.len()]),
+                    ..Default::default()
+                },
+            ),
+        ],
+        edges: vec![link("target", "ik", "target_pos")],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    let err = evaluate_all(&mut rt, &spec).expect_err("weighted ccd should fail");
+    assert!(err.contains("joint weights"), "unexpected error: {err}");
+}
+
+#[test]
+fn urdf_nodes_sharing_xml_parse_it_once() {
+    let ik_params = || NodeParams {
+        max_iters: Some(512),
+        tol_pos: Some(0.005),
+        ..Default::default()
+    };
+    let spec = GraphSpec {
+        nodes: vec![
+            constant_node("target_a", vocab::vec3([0.6, 0.25, 0.3])),
+            constant_node("target_b", vocab::vec3([0.5, -0.3, 0.2])),
+            urdf_node("ik_a", NodeType::UrdfIkPosition, ik_params()),
+            urdf_node("ik_b", NodeType::UrdfIkPosition, ik_params()),
+            urdf_node("fk_a", NodeType::UrdfFk, NodeParams::default()),
+            urdf_node("fk_b", NodeType::UrdfFk, NodeParams::default()),
+        ],
+        edges: vec![
+            link("target_a", "ik_a", "target_pos"),
+            link("target_b", "ik_b", "target_pos"),
+            link("ik_a", "fk_a", "joints"),
+            link("ik_b", "fk_b", "joints"),
+        ],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &spec).expect("both IK chains should evaluate");
+    assert_eq!(rt.urdf_cache.parse_count(), 1);
+
+    for (fk, target) in [("fk_a", [0.6, 0.25, 0.3]), ("fk_b", [0.5, -0.3, 0.2])] {
+        let reached = vocab::as_vec3(&rt.outputs[fk]["position"].value).expect("fk position");
+        assert_close(&reached, &target, 0.005);
+    }
+
+    evaluate_all(&mut rt, &spec).expect("second frame should evaluate");
+    assert_eq!(
+        rt.urdf_cache.parse_count(),
+        1,
+        "later frames reuse cached chains"
+    );
+}
+
+#[test]
+fn urdf_cache_evicts_least_recently_used_robot() {
+    let variants: Vec<String> = (0..9)
+        .map(|i| POSE_URDF.replacen("sample_arm", &format!("sample_arm_{i}"), 1))
+        .collect();
+    fn key(xml: &str) -> IkKey<'_> {
+        IkKey {
+            hash: hash_urdf_config(xml, "base_link", "tool"),
+            urdf_xml: xml,
+            root_link: "base_link",
+            tip_link: "tool",
+        }
+    }
+
+    let mut cache = UrdfCache::default();
+    for xml in &variants[..8] {
+        cache.serial_chain(&key(xml)).expect("chain builds");
+    }
+    // Touch the oldest robot so the second one becomes the eviction candidate.
+    cache
+        .serial_chain(&key(&variants[0]))
+        .expect("cached chain");
+    assert_eq!(cache.parse_count(), 8);
+
+    cache
+        .serial_chain(&key(&variants[8]))
+        .expect("chain builds");
+    cache
+        .serial_chain(&key(&variants[0]))
+        .expect("still cached");
+    assert_eq!(cache.parse_count(), 9);
+    cache
+        .serial_chain(&key(&variants[1]))
+        .expect("reparsed after eviction");
+    assert_eq!(cache.parse_count(), 10);
+}
+
+#[test]
+fn urdf_ik_orientation_matches_target_rotation() {
+    // A quarter turn about Z, reachable by the yaw joints alone.
+    let half = std::f32::consts::FRAC_PI_4;
+    let target_rot = [0.0, 0.0, half.sin(), half.cos()];
+    let spec = GraphSpec {
+        nodes: vec![
+            constant_node("target", vocab::quat(target_rot)),
+            urdf_node(
+                "ik",
+                NodeType::UrdfIkOrientation,
+                NodeParams {
+                    max_iters: Some(512),
+                    tol_rot: Some(1e-3),
+                    ..Default::default()
+                },
+            ),
+            urdf_node("fk", NodeType::UrdfFk, NodeParams::default()),
+        ],
+        edges: vec![
+            link("target", "ik", "target_rot"),
+            link("ik", "fk", "joints"),
+        ],
+        ..Default::default()
+    }
+    .with_cache();
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &spec).expect("orientation IK should evaluate");
+    let reached = vocab::as_quat(&rt.outputs["fk"]["rotation"].value).expect("fk rotation");
+    // q and -q describe the same rotation.
+    let dot: f32 = reached.iter().zip(target_rot).map(|(a, b)| a * b).sum();
+    assert!(
+        dot.abs() > 1.0 - 1e-3,
+        "expected {target_rot:?}, got {reached:?}"
+    );
+}
+
+#[test]
+fn urdf_ik_reports_iterations_and_residual() {
+    let max_iters = 256;
+    let tol_pos = 0.005;
+    for solver in ["jacobian", "ccd"] {
+        let spec = GraphSpec {
+            nodes: vec![
+                constant_node("target", vocab::vec3([0.5, -0.3, 0.2])),
+                urdf_node(
+                    "ik",
+                    NodeType::UrdfIkPosition,
+                    NodeParams {
+                        solver: Some(solver.to_string()),
+                        max_iters: Some(max_iters),
+                        tol_pos: Some(tol_pos),
+                        ..Default::default()
+                    },
+                ),
+            ],
+            edges: vec![link("target", "ik", "target_pos")],
+            ..Default::default()
+        }
+        .with_cache();
+
+        let mut rt = GraphRuntime::default();
+        evaluate_all(&mut rt, &spec).unwrap_or_else(|err| panic!("{solver} failed: {err}"));
+        let ik = &rt.outputs["ik"];
+        let residual = vocab::as_float(&ik["residual"].value).expect("residual output");
+        let iterations = vocab::as_float(&ik["iterations"].value).expect("iterations output");
+        assert!(
+            (0.0..=tol_pos).contains(&residual),
+            "{solver} residual {residual} exceeds tolerance"
+        );
+        assert!(
+            (0.0..=max_iters as f32).contains(&iterations),
+            "{solver} used {iterations} iterations"
+        );
+    }
+}
+
+#[test]
+fn urdf_ik_fail_soft_returns_best_effort_for_unreachable_target() {
+    let spec_for = |fail_soft: Option<bool>| {
+        GraphSpec {
+            nodes: vec![
+                constant_node("target", vocab::vec3([0.0, 5.0, 0.0])),
+                urdf_node(
+                    "ik",
+                    NodeType::UrdfIkPosition,
+                    NodeParams {
+                        max_iters: Some(32),
+                        fail_soft,
+                        ..Default::default()
+                    },
+                ),
+            ],
+            edges: vec![link("target", "ik", "target_pos")],
+            ..Default::default()
+        }
+        .with_cache()
+    };
+
+    let mut rt = GraphRuntime::default();
+    let err = evaluate_all(&mut rt, &spec_for(None)).expect_err("unreachable target should fail");
+    assert!(err.contains("not converged"), "unexpected error: {err}");
+
+    let mut rt = GraphRuntime::default();
+    evaluate_all(&mut rt, &spec_for(Some(true))).expect("fail_soft graph evaluates");
+    let ik = &rt.outputs["ik"];
+    assert_eq!(vocab::as_bool(&ik["converged"].value), Some(false));
+    let angles = vocab::as_record(&ik["out"].value).expect("joint record");
+    assert_eq!(angles.len(), JOINTS.len());
+    // The zero seed points the arm along +X; the last iterate should have swung it toward +Y.
+    let joint1 = angles
+        .iter()
+        .find(|(name, _)| *name == "joint1")
+        .and_then(|(_, angle)| vocab::as_float(angle))
+        .expect("joint1 angle");
+    assert!(
+        joint1 > 1.0,
+        "joint1 {joint1} should turn toward the target"
+    );
+    let residual = vocab::as_float(&ik["residual"].value).expect("residual output");
+    assert!(
+        (4.0..5.0).contains(&residual),
+        "residual {residual} should improve on the seed (~5.09 m) without reaching the target"
+    );
+}
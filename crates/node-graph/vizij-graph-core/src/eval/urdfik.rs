@@ -9,6 +9,10 @@ use std::collections::hash_map::DefaultHasher;
 use std::fmt;
 #[cfg(feature = "urdf_ik")]
 use std::hash::{Hash, Hasher};
+#[cfg(feature = "urdf_ik")]
+use std::sync::atomic::{AtomicU32, Ordering};
+#[cfg(feature = "urdf_ik")]
+use std::sync::{Arc, Mutex};
 use vizij_api_core::value as vocab;
 use vizij_api_core::value::VizijKind;
 use vizij_api_core::{coercion, Value};
@@ -59,8 +63,34 @@ impl UrdfKinematicsState {
                 .map(|(name, angle)| (name.as_str(), vocab::float(*angle))),
         )
     }
+
+    /// Produce a record value mapping joint names to whether the solved angle sits on one of the
+    /// joint's limits. Joints without limits are never flagged.
+    pub fn limit_record(&self, joints: &[f32]) -> Value {
+        let flags: Vec<bool> = self
+            .chain
+            .iter_joints()
+            .zip(joints.iter())
+            .map(|(joint, angle)| {
+                joint.limits.as_ref().is_some_and(|range| {
+                    (angle - range.min).abs() <= LIMIT_EPSILON
+                        || (angle - range.max).abs() <= LIMIT_EPSILON
+                })
+            })
+            .collect();
+        vocab::record(
+            self.joint_names
+                .iter()
+                .zip(flags)
+                .map(|(name, at_limit)| (name.as_str(), vocab::bool_(at_limit))),
+        )
+    }
 }
 
+#[cfg(feature = "urdf_ik")]
+/// Distance (radians or meters) within which a joint counts as resting on its limit.
+const LIMIT_EPSILON: f32 = 1e-4;
+
 #[cfg(feature = "urdf_ik")]
 /// Key that uniquely identifies a URDF chain configuration for caching.
 pub struct IkKey<'a> {
@@ -81,20 +111,107 @@ pub fn hash_urdf_config(urdf_xml: &str, root_link: &str, tip_link: &str) -> u64
 }
 
 #[cfg(feature = "urdf_ik")]
-/// Build a serial chain between `root` and `tip` from the provided URDF XML.
-pub fn build_chain_from_urdf(
-    urdf_xml: &str,
-    root: &str,
-    tip: &str,
-) -> Result<(k::SerialChain<f32>, Vec<String>), String> {
-    if urdf_xml.trim().is_empty() {
-        return Err("URDF XML is empty".to_string());
+/// Most robots a [`UrdfCache`] keeps before evicting the least recently used one.
+const URDF_CACHE_CAPACITY: usize = 8;
+
+#[cfg(feature = "urdf_ik")]
+/// Kinematic tree built from a parsed URDF, plus the link-to-joint lookup used to cut serial
+/// chains out of it.
+struct CachedRobot {
+    chain: k::Chain<f32>,
+    link_to_joint: std::collections::HashMap<String, String>,
+}
+
+#[cfg(feature = "urdf_ik")]
+/// Kinematic trees shared by every kinematics node in a runtime.
+///
+/// Trees are keyed by a hash of their URDF XML so several IK/FK nodes referencing the same robot
+/// parse and build it once; each node still gets its own copy of the serial chain so joint state
+/// stays per-node. At most [`URDF_CACHE_CAPACITY`] robots are kept, evicting the least recently
+/// used.
+#[derive(Default)]
+pub struct UrdfCache {
+    /// Cached robots ordered from least to most recently used.
+    robots: Vec<(u64, Arc<CachedRobot>)>,
+    #[cfg(test)]
+    parses: usize,
+}
+
+#[cfg(feature = "urdf_ik")]
+impl fmt::Debug for UrdfCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UrdfCache")
+            .field("robots", &self.robots.len())
+            .finish()
     }
+}
 
-    let robot = urdf_rs::read_from_string(urdf_xml)
-        .map_err(|err| format!("failed to parse URDF: {err}"))?;
+#[cfg(feature = "urdf_ik")]
+impl UrdfCache {
+    /// Build the serial chain selected by `key`, parsing its URDF on first use.
+    pub fn serial_chain(
+        &mut self,
+        key: &IkKey<'_>,
+    ) -> Result<(k::SerialChain<f32>, Vec<String>), String> {
+        let robot = self.robot(key.urdf_xml)?;
+        build_serial_chain(&robot, key.root_link, key.tip_link)
+    }
 
-    let link_to_joint = k::urdf::link_to_joint_map(&robot);
+    fn robot(&mut self, urdf_xml: &str) -> Result<Arc<CachedRobot>, String> {
+        if urdf_xml.trim().is_empty() {
+            return Err("URDF XML is empty".to_string());
+        }
+        let mut hasher = DefaultHasher::new();
+        urdf_xml.hash(&mut hasher);
+        let hash = hasher.finish();
+        if let Some(index) = self.robots.iter().position(|(key, _)| *key == hash) {
+            let entry = self.robots.remove(index);
+            let robot = Arc::clone(&entry.1);
+            self.robots.push(entry);
+            return Ok(robot);
+        }
+
+        let robot = urdf_rs::read_from_string(urdf_xml)
+            .map_err(|err| format!("failed to parse URDF: {err}"))?;
+        #[cfg(test)]
+        {
+            self.parses += 1;
+        }
+        let cached = Arc::new(CachedRobot {
+            chain: k::Chain::<f32>::from(&robot),
+            link_to_joint: k::urdf::link_to_joint_map(&robot),
+        });
+        if self.robots.len() >= URDF_CACHE_CAPACITY {
+            self.robots.remove(0);
+        }
+        self.robots.push((hash, Arc::clone(&cached)));
+        Ok(cached)
+    }
+
+    /// Number of URDF documents parsed since the cache was created or cleared.
+    #[cfg(test)]
+    pub(crate) fn parse_count(&self) -> usize {
+        self.parses
+    }
+
+    /// Drop every cached robot.
+    pub fn clear(&mut self) {
+        self.robots.clear();
+        #[cfg(test)]
+        {
+            self.parses = 0;
+        }
+    }
+}
+
+#[cfg(feature = "urdf_ik")]
+/// Cut the serial chain between `root` and `tip` out of a private copy of the robot's tree.
+fn build_serial_chain(
+    robot: &CachedRobot,
+    root: &str,
+    tip: &str,
+) -> Result<(k::SerialChain<f32>, Vec<String>), String> {
+    let link_to_joint = &robot.link_to_joint;
     let tip_joint = link_to_joint
         .get(tip)
         .ok_or_else(|| format!("tip link '{tip}' not found in URDF"))?;
@@ -102,7 +219,7 @@ pub fn build_chain_from_urdf(
         .get(root)
         .ok_or_else(|| format!("root link '{root}' not found in URDF"))?;
 
-    let chain = k::Chain::<f32>::from(robot);
+    let chain = robot.chain.clone();
 
     let tip_node = chain
         .find(tip_joint)
@@ -158,21 +275,138 @@ pub fn build_chain_from_urdf(
 }
 
 #[cfg(feature = "urdf_ik")]
-/// Apply optional joint-space weights to the solver.
+/// IK solver selected by the `solver` param of URDF IK nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IkSolverKind {
+    /// Damped Jacobian solver from `k`; supports weights and rotation targets.
+    Jacobian,
+    /// Cyclic coordinate descent over the chain joints; position targets only and unweighted.
+    Ccd,
+}
+
+#[cfg(feature = "urdf_ik")]
+/// Parse the `solver` param, defaulting to [`IkSolverKind::Jacobian`].
+pub fn parse_ik_solver(node: &str, name: Option<&str>) -> Result<IkSolverKind, String> {
+    match name.map(str::trim).filter(|s| !s.is_empty()) {
+        None => Ok(IkSolverKind::Jacobian),
+        Some(s) if s.eq_ignore_ascii_case("jacobian") => Ok(IkSolverKind::Jacobian),
+        Some(s) if s.eq_ignore_ascii_case("ccd") => Ok(IkSolverKind::Ccd),
+        Some(other) => Err(format!(
+            "{node} solver '{other}' is not supported (expected \"jacobian\" or \"ccd\")"
+        )),
+    }
+}
+
+#[cfg(feature = "urdf_ik")]
+/// Joint solution returned by the IK solvers together with convergence diagnostics.
+#[derive(Debug, Clone)]
+pub struct IkSolution {
+    /// Solved joint positions in chain order.
+    pub joints: Vec<f32>,
+    /// Solver iterations spent reaching the solution.
+    pub iterations: u32,
+    /// Remaining distance between the tip and the target position (meters); zero when position
+    /// is unconstrained.
+    pub residual_pos: f32,
+    /// Remaining angle between the tip and the target rotation (radians); zero when rotation is
+    /// unconstrained.
+    pub residual_rot: f32,
+    /// Whether the solver reached the target within tolerance. When `false`, `joints` hold the
+    /// solver's last iterate.
+    pub converged: bool,
+}
+
+#[cfg(feature = "urdf_ik")]
+impl IkSolution {
+    /// Capture the chain's current joints and measure how far the tip is from the target.
+    fn measure(
+        state: &UrdfKinematicsState,
+        iterations: u32,
+        converged: bool,
+        target_pos: Option<[f32; 3]>,
+        target_rot: Option<k::UnitQuaternion<f32>>,
+    ) -> Self {
+        state.chain.update_transforms();
+        let end = state.chain.end_transform();
+        let residual_pos = target_pos.map_or(0.0, |[x, y, z]| {
+            (end.translation.vector - k::nalgebra::Vector3::new(x, y, z)).norm()
+        });
+        let residual_rot = target_rot.map_or(0.0, |rot| end.rotation.angle_to(&rot));
+        IkSolution {
+            joints: state.chain.joint_positions(),
+            iterations,
+            residual_pos,
+            residual_rot,
+            converged,
+        }
+    }
+}
+
+#[cfg(feature = "urdf_ik")]
+/// Progress observed through the Jacobian solver's per-iteration nullspace callback.
+#[derive(Debug, Default)]
+struct IkProgress {
+    iterations: AtomicU32,
+    last_positions: Mutex<Vec<f32>>,
+}
+
+#[cfg(feature = "urdf_ik")]
+/// Turn the Jacobian solver's outcome into an [`IkSolution`], keeping the last iterate when it
+/// fails to converge.
+fn finish_jacobian(
+    state: &UrdfKinematicsState,
+    outcome: Result<(), k::Error>,
+    progress: &IkProgress,
+    target_pos: Option<[f32; 3]>,
+    target_rot: Option<k::UnitQuaternion<f32>>,
+) -> Result<IkSolution, String> {
+    let iterations = progress.iterations.load(Ordering::Relaxed);
+    match outcome {
+        Ok(()) => Ok(IkSolution::measure(
+            state, iterations, true, target_pos, target_rot,
+        )),
+        Err(k::Error::NotConvergedError { .. }) => {
+            // `k` may roll the chain back to the seed when it gives up; reapply the last iterate.
+            let last = progress
+                .last_positions
+                .lock()
+                .map(|positions| positions.clone())
+                .unwrap_or_default();
+            if last.len() == state.dofs {
+                state.chain.set_joint_positions_clamped(&last);
+            }
+            Ok(IkSolution::measure(
+                state, iterations, false, target_pos, target_rot,
+            ))
+        }
+        Err(err) => Err(format!("IK solve failed: {err}")),
+    }
+}
+
+#[cfg(feature = "urdf_ik")]
+/// Install the solver's nullspace function: the weighted reference-pose pull when `weights` are
+/// provided, otherwise a no-op. `k` calls it once per iteration, so it also records progress.
 fn apply_weights(
     solver: &mut k::JacobianIkSolver<f32>,
     reference: &[f32],
     weights: Option<&[f32]>,
-) -> Result<(), String> {
-    if let Some(w) = weights {
-        solver.set_nullspace_function(Box::new(k::create_reference_positions_nullspace_function(
-            reference.to_vec(),
-            w.to_vec(),
-        )));
-    } else {
-        solver.clear_nullspace_function();
-    }
-    Ok(())
+) -> Result<Arc<IkProgress>, String> {
+    let progress = Arc::new(IkProgress::default());
+    let observer = Arc::clone(&progress);
+    let pull = weights
+        .map(|w| k::create_reference_positions_nullspace_function(reference.to_vec(), w.to_vec()));
+    solver.set_nullspace_function(Box::new(move |positions: &[f32]| {
+        observer.iterations.fetch_add(1, Ordering::Relaxed);
+        if let Ok(mut last) = observer.last_positions.lock() {
+            last.clear();
+            last.extend_from_slice(positions);
+        }
+        match &pull {
+            Some(pull) => pull(positions),
+            None => vec![0.0; positions.len()],
+        }
+    }));
+    Ok(progress)
 }
 
 #[cfg(feature = "urdf_ik")]
@@ -185,13 +419,13 @@ pub fn solve_position(
     weights: Option<&[f32]>,
     max_iters: u32,
     tol_pos: f32,
-) -> Result<Vec<f32>, String> {
+) -> Result<IkSolution, String> {
     state
         .chain
         .set_joint_positions(seed)
         .map_err(|err| format!("failed to apply joint seed: {err}"))?;
 
-    apply_weights(solver, seed, weights)?;
+    let progress = apply_weights(solver, seed, weights)?;
 
     solver.num_max_try = max_iters.max(1) as usize;
     solver.allowable_target_distance = tol_pos;
@@ -209,11 +443,83 @@ pub fn solve_position(
         ..Default::default()
     };
 
-    solver
-        .solve_with_constraints(&state.chain, &target_pose, &constraints)
-        .map_err(|err| format!("IK solve failed: {err}"))?;
+    let outcome = solver.solve_with_constraints(&state.chain, &target_pose, &constraints);
+    finish_jacobian(state, outcome, &progress, Some(target_pos), None)
+}
 
-    Ok(state.chain.joint_positions())
+#[cfg(feature = "urdf_ik")]
+/// Solve for joint positions that reach `target_pos` with cyclic coordinate descent.
+///
+/// Each sweep walks the movable joints from tip to root, turning revolute joints to swing the
+/// tip toward the target and sliding prismatic joints along their axis. Joint limits are
+/// respected by clamping after every step.
+pub fn solve_position_ccd(
+    state: &mut UrdfKinematicsState,
+    target_pos: [f32; 3],
+    seed: &[f32],
+    max_iters: u32,
+    tol_pos: f32,
+) -> Result<IkSolution, String> {
+    state
+        .chain
+        .set_joint_positions(seed)
+        .map_err(|err| format!("failed to apply joint seed: {err}"))?;
+
+    let target = k::nalgebra::Vector3::new(target_pos[0], target_pos[1], target_pos[2]);
+    let mut positions = state.chain.joint_positions();
+    let tip = |chain: &k::SerialChain<f32>| {
+        chain.update_transforms();
+        chain.end_transform().translation.vector
+    };
+
+    for sweep in 0..max_iters.max(1) {
+        if (tip(&state.chain) - target).norm() <= tol_pos {
+            return Ok(IkSolution::measure(
+                state,
+                sweep,
+                true,
+                Some(target_pos),
+                None,
+            ));
+        }
+        let joints: Vec<_> = state
+            .chain
+            .iter()
+            .filter(|node| node.joint().is_movable())
+            .collect();
+        for (index, node) in joints.iter().enumerate().rev() {
+            let current_tip = tip(&state.chain);
+            let Some(frame) = node.world_transform() else {
+                continue;
+            };
+            let pivot = frame.translation.vector;
+            let step = match node.joint().joint_type {
+                k::JointType::Rotational { axis } => {
+                    let axis = frame.rotation * axis.into_inner();
+                    let to_tip = current_tip - pivot;
+                    let to_target = target - pivot;
+                    let a = to_tip - axis * axis.dot(&to_tip);
+                    let b = to_target - axis * axis.dot(&to_target);
+                    if a.norm() <= f32::EPSILON || b.norm() <= f32::EPSILON {
+                        continue;
+                    }
+                    axis.dot(&a.cross(&b)).atan2(a.dot(&b))
+                }
+                k::JointType::Linear { axis } => {
+                    let axis = frame.rotation * axis.into_inner();
+                    axis.dot(&(target - current_tip))
+                }
+                k::JointType::Fixed => continue,
+            };
+            positions[index] += step;
+            state.chain.set_joint_positions_clamped(&positions);
+            positions = state.chain.joint_positions();
+        }
+    }
+
+    let mut solution = IkSolution::measure(state, max_iters.max(1), true, Some(target_pos), None);
+    solution.converged = solution.residual_pos <= tol_pos;
+    Ok(solution)
 }
 
 #[cfg(feature = "urdf_ik")]
@@ -354,6 +660,35 @@ pub fn tip_pose(state: &UrdfKinematicsState) -> ([f32; 3], [f32; 4]) {
     ([pos.x, pos.y, pos.z], [rot.i, rot.j, rot.k, rot.w])
 }
 
+#[cfg(feature = "urdf_ik")]
+/// Produce a record value mapping each link in the chain to its world transform.
+///
+/// Reflects the joint positions most recently applied to the chain.
+pub fn link_transforms(state: &UrdfKinematicsState) -> Value {
+    let transforms = state.chain.update_transforms();
+    let entries: Vec<(String, Value)> = state
+        .chain
+        .iter()
+        .zip(transforms.iter())
+        .filter_map(|(node, iso)| {
+            let name = node.link().as_ref().map(|link| link.name.clone())?;
+            let pos = iso.translation.vector;
+            let rot = iso.rotation;
+            let transform = vocab::transform(vocab::Transform {
+                translation: [pos.x, pos.y, pos.z],
+                rotation: [rot.i, rot.j, rot.k, rot.w],
+                scale: [1.0, 1.0, 1.0],
+            });
+            Some((name, transform))
+        })
+        .collect();
+    vocab::record(
+        entries
+            .iter()
+            .map(|(name, transform)| (name.as_str(), transform.clone())),
+    )
+}
+
 #[allow(clippy::too_many_arguments)]
 #[cfg(feature = "urdf_ik")]
 /// Solve for joint positions that reach both `target_pos` and `target_rot`.
@@ -367,13 +702,13 @@ pub fn solve_pose(
     max_iters: u32,
     tol_pos: f32,
     tol_rot: f32,
-) -> Result<Vec<f32>, String> {
+) -> Result<IkSolution, String> {
     state
         .chain
         .set_joint_positions(seed)
         .map_err(|err| format!("failed to apply joint seed: {err}"))?;
 
-    apply_weights(solver, seed, weights)?;
+    let progress = apply_weights(solver, seed, weights)?;
 
     solver.num_max_try = max_iters.max(1) as usize;
     solver.allowable_target_distance = tol_pos;
@@ -390,11 +725,51 @@ pub fn solve_pose(
         rotation,
     );
 
-    solver
-        .solve(&state.chain, &target_pose)
-        .map_err(|err| format!("IK solve failed: {err}"))?;
+    let outcome = solver.solve(&state.chain, &target_pose);
+    finish_jacobian(state, outcome, &progress, Some(target_pos), Some(rotation))
+}
+
+#[cfg(feature = "urdf_ik")]
+/// Solve for joint positions that match `target_rot` while leaving the tip position free.
+pub fn solve_orientation(
+    state: &mut UrdfKinematicsState,
+    solver: &mut k::JacobianIkSolver<f32>,
+    target_rot: [f32; 4],
+    seed: &[f32],
+    weights: Option<&[f32]>,
+    max_iters: u32,
+    tol_rot: f32,
+) -> Result<IkSolution, String> {
+    state
+        .chain
+        .set_joint_positions(seed)
+        .map_err(|err| format!("failed to apply joint seed: {err}"))?;
+
+    let progress = apply_weights(solver, seed, weights)?;
+
+    solver.num_max_try = max_iters.max(1) as usize;
+    solver.allowable_target_distance = f32::INFINITY;
+    solver.allowable_target_angle = tol_rot;
+
+    let rotation = k::UnitQuaternion::new_normalize(k::nalgebra::Quaternion::new(
+        target_rot[3],
+        target_rot[0],
+        target_rot[1],
+        target_rot[2],
+    ));
+    // Position constraints are disabled, so the current tip translation only anchors the pose.
+    let current = state.chain.end_transform();
+    let target_pose = k::Isometry3::from_parts(current.translation, rotation);
+
+    let constraints = k::Constraints {
+        position_x: false,
+        position_y: false,
+        position_z: false,
+        ..Default::default()
+    };
 
-    Ok(state.chain.joint_positions())
+    let outcome = solver.solve_with_constraints(&state.chain, &target_pose, &constraints);
+    finish_jacobian(state, outcome, &progress, None, Some(rotation))
 }
 
 #[cfg(feature = "urdf_ik")]
@@ -6,9 +6,10 @@
 //! data, and [`ValueLayout::reconstruct`] re-encodes the result through the
 //! vocabulary constructors.
 
+use serde::{Deserialize, Serialize};
 use vizij_api_core::value as vocab;
 use vizij_api_core::value::VizijKind;
-use vizij_api_core::{Shape, Value};
+use vizij_api_core::{coercion, Shape, Value};
 
 use super::shape_helpers::infer_shape;
 
@@ -37,6 +38,12 @@ impl PortValue {
     }
 }
 
+impl AsRef<Value> for PortValue {
+    fn as_ref(&self) -> &Value {
+        &self.value
+    }
+}
+
 impl Default for PortValue {
     fn default() -> Self {
         PortValue::new(vocab::float(0.0))
@@ -48,7 +55,8 @@ impl Default for PortValue {
 /// Sequences flatten to a single `Array` layout: the wire value is one
 /// sequence kind (`ArrayValue`), so any declared array/list/tuple distinction
 /// lives in the path's [`Shape`], not here.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ValueLayout {
     Scalar,
     Vec2,
@@ -92,6 +100,10 @@ impl ValueLayout {
         matches!(self, ValueLayout::Scalar)
     }
 
+    fn is_composite(&self) -> bool {
+        matches!(self, ValueLayout::Record(_) | ValueLayout::Array(_))
+    }
+
     /// Reconstruct a structured [`Value`] from flattened scalar data.
     pub fn reconstruct(&self, data: &[f32]) -> Value {
         match self {
@@ -152,8 +164,14 @@ fn read_array<const N: usize>(data: &[f32], offset: usize) -> [f32; N] {
 }
 
 /// Attempt to flatten a [`Value`] that contains only numeric content.
+///
+/// Booleans flatten as scalar `1.0`/`0.0` so logic outputs can feed math nodes.
 pub fn flatten_numeric(value: &Value) -> Option<FlatValue> {
     match vocab::kind(value) {
+        VizijKind::Bool => Some(FlatValue {
+            layout: ValueLayout::Scalar,
+            data: vec![if vocab::as_bool(value)? { 1.0 } else { 0.0 }],
+        }),
         VizijKind::Float => Some(FlatValue {
             layout: ValueLayout::Scalar,
             data: vec![vocab::as_float(value)?],
@@ -227,32 +245,52 @@ pub fn flatten_numeric(value: &Value) -> Option<FlatValue> {
     }
 }
 
-/// Align two flattened values for point-wise operations, broadcasting scalars when possible.
-pub fn align_flattened(
-    a: &FlatValue,
-    b: &FlatValue,
+/// Align two values for point-wise operations and flatten them onto one layout.
+///
+/// These are the [`coercion::broadcast`] rules applied to flattened data: matching layouts pass
+/// through and a scalar repeats into the other side's layout, without rebuilding either value.
+/// Records and sequences whose layouts differ are handed to `broadcast` itself. When the values
+/// cannot be aligned (or one has no numeric layout), the error carries the larger numeric
+/// operand's layout so callers can emit a NaN-filled result of that shape.
+pub fn align_values(
+    a: &Value,
+    b: &Value,
+) -> Result<(ValueLayout, Vec<f32>, Vec<f32>), ValueLayout> {
+    match (flatten_numeric(a), flatten_numeric(b)) {
+        (Some(fa), Some(fb)) => align_flattened(a, b, fa, fb),
+        (Some(flat), None) | (None, Some(flat)) => Err(flat.layout),
+        (None, None) => Err(ValueLayout::Scalar),
+    }
+}
+
+fn align_flattened(
+    a: &Value,
+    b: &Value,
+    fa: FlatValue,
+    fb: FlatValue,
 ) -> Result<(ValueLayout, Vec<f32>, Vec<f32>), ValueLayout> {
-    if a.layout == b.layout {
-        return Ok((a.layout.clone(), a.data.clone(), b.data.clone()));
+    if fa.layout == fb.layout {
+        return Ok((fa.layout, fa.data, fb.data));
     }
-    if a.layout.is_scalar() {
-        let layout = b.layout.clone();
-        let len = layout.scalar_len();
-        let repeated = vec![a.data.first().copied().unwrap_or(f32::NAN); len];
-        return Ok((layout, repeated, b.data.clone()));
+    if fa.layout.is_scalar() {
+        let repeated = vec![fa.data.first().copied().unwrap_or(f32::NAN); fb.data.len()];
+        return Ok((fb.layout, repeated, fb.data));
     }
-    if b.layout.is_scalar() {
-        let layout = a.layout.clone();
-        let len = layout.scalar_len();
-        let repeated = vec![b.data.first().copied().unwrap_or(f32::NAN); len];
-        return Ok((layout, a.data.clone(), repeated));
+    if fb.layout.is_scalar() {
+        let repeated = vec![fb.data.first().copied().unwrap_or(f32::NAN); fa.data.len()];
+        return Ok((fa.layout, fa.data, repeated));
     }
-
-    let len_a = a.layout.scalar_len();
-    let len_b = b.layout.scalar_len();
-    if len_a >= len_b {
-        Err(a.layout.clone())
+    if fa.layout.is_composite() || fb.layout.is_composite() {
+        let aligned = coercion::broadcast(a, b)
+            .ok()
+            .and_then(|(a, b)| Some((flatten_numeric(&a)?, flatten_numeric(&b)?)));
+        if let Some((a, b)) = aligned.filter(|(a, b)| a.layout == b.layout) {
+            return Ok((a.layout, a.data, b.data));
+        }
+    }
+    if fa.layout.scalar_len() >= fb.layout.scalar_len() {
+        Err(fa.layout)
     } else {
-        Err(b.layout.clone())
+        Err(fb.layout)
     }
 }
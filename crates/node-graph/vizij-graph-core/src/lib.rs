@@ -5,12 +5,15 @@
 
 pub mod eval;
 pub mod schema;
+#[cfg(feature = "testutil")]
+pub mod testutil;
 pub mod topo;
 pub mod types;
 
 pub use eval::{
-    eval_node, evaluate_all, evaluate_all_cached, evaluate_all_with_functions, GraphRuntime,
-    NodeFunction, NodeFunctionRegistry, NodeFunctions, PortValue, StagedInput,
+    eval_node, evaluate_all, evaluate_all_cached, evaluate_all_limited,
+    evaluate_all_with_functions, evaluate_types_only, infer_shape, GraphRuntime, NodeFunction,
+    NodeFunctionRegistry, NodeFunctions, PortValue, StagedInput,
 };
 pub use schema::registry;
 pub use topo::topo_order;
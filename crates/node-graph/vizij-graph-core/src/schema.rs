@@ -52,6 +52,10 @@ pub struct VariadicSpec {
     pub max: Option<usize>,
     #[serde(default)]
     pub keyed: bool,
+    /// Param whose entry count sets the number of ports, when the count is driven by a param
+    /// rather than by wiring (e.g. `sizes` for Split).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count_param: Option<&'static str>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -74,6 +78,12 @@ pub struct NodeSignature {
     pub type_id: NodeType,
     pub name: &'static str,
     pub category: &'static str,
+    /// False when the node needs a cargo feature this build lacks. Such nodes stay listed so
+    /// tooling can show them as unavailable instead of unknown.
+    pub available: bool,
+    /// Cargo feature that gates the node, if any (e.g. `"urdf_ik"`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requires_feature: Option<&'static str>,
     #[serde(default)]
     pub doc: &'static str,
     pub inputs: Vec<PortSpec>,
@@ -85,6 +95,28 @@ pub struct NodeSignature {
     pub params: Vec<ParamSpec>,
 }
 
+impl NodeSignature {
+    /// An always-available node with no docs, ports, or params.
+    ///
+    /// Registry literals fill in the rest with struct-update syntax; only feature-gated nodes
+    /// override `available` and `requires_feature`.
+    pub fn new(type_id: NodeType, name: &'static str, category: &'static str) -> Self {
+        NodeSignature {
+            type_id,
+            name,
+            category,
+            available: true,
+            requires_feature: None,
+            doc: "",
+            inputs: vec![],
+            variadic_inputs: None,
+            outputs: vec![],
+            variadic_outputs: None,
+            params: vec![],
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Registry {
     pub version: &'static str,
@@ -162,9 +194,6 @@ pub fn registry() -> Registry {
 
     // Scalars / arithmetic (float-based legacy kept for convenience)
     nodes.push(NodeSignature {
-        type_id: Constant,
-        name: "Constant",
-        category: "Math",
         doc: "Outputs the configured value every frame; defaults to 0.0 when unspecified.",
         inputs: vec![],
         variadic_inputs: None,
@@ -179,12 +208,10 @@ pub fn registry() -> Registry {
             min: None,
             max: None,
         }],
+        ..NodeSignature::new(Constant, "Constant", "Math")
     });
 
     nodes.push(NodeSignature {
-        type_id: Slider,
-        name: "Slider",
-        category: "Math",
         doc: "Provides a tunable scalar value constrained to the configured min/max range.",
         inputs: vec![],
         variadic_inputs: None,
@@ -219,12 +246,10 @@ pub fn registry() -> Registry {
                 max: None,
             },
         ],
+        ..NodeSignature::new(Slider, "Slider", "Math")
     });
 
     nodes.push(NodeSignature {
-        type_id: MultiSlider,
-        name: "Multi Slider",
-        category: "Math",
         doc: "Provides three independent slider-controlled scalar outputs for X, Y, and Z.",
         inputs: vec![],
         variadic_inputs: None,
@@ -281,12 +306,10 @@ pub fn registry() -> Registry {
                 max: None,
             },
         ],
+        ..NodeSignature::new(MultiSlider, "Multi Slider", "Math")
     });
 
     nodes.push(NodeSignature {
-        type_id: Add,
-        name: "Add",
-        category: "Math",
         doc: "Sums all incoming operands, treating missing inputs as 0.",
         inputs: vec![],
         variadic_inputs: Some(VariadicSpec {
@@ -297,16 +320,15 @@ pub fn registry() -> Registry {
             min: 2,
             max: None,
             keyed: false,
+            count_param: None,
         }),
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Add, "Add", "Math")
     });
 
     nodes.push(NodeSignature {
-        type_id: Subtract,
-        name: "Subtract",
-        category: "Math",
         doc: "Subtracts RHS from LHS; missing inputs default to 0.",
         inputs: vec![
             PortSpec {
@@ -328,12 +350,10 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Subtract, "Subtract", "Math")
     });
 
     nodes.push(NodeSignature {
-        type_id: Multiply,
-        name: "Multiply",
-        category: "Math",
         doc: "Multiplies all incoming operands; missing inputs act as 1.",
         inputs: vec![],
         variadic_inputs: Some(VariadicSpec {
@@ -344,16 +364,15 @@ pub fn registry() -> Registry {
             min: 2,
             max: None,
             keyed: false,
+            count_param: None,
         }),
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Multiply, "Multiply", "Math")
     });
 
     nodes.push(NodeSignature {
-        type_id: Divide,
-        name: "Divide",
-        category: "Math",
         doc: "Divides LHS by RHS; division by zero yields NaN.",
         inputs: vec![
             PortSpec {
@@ -375,12 +394,10 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Divide, "Divide", "Math")
     });
 
     nodes.push(NodeSignature {
-        type_id: Modulo,
-        name: "Modulo",
-        category: "Math",
         doc: "Computes the remainder of LHS ÷ RHS; divisor 0 yields NaN.",
         inputs: vec![
             PortSpec {
@@ -402,12 +419,10 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Modulo, "Modulo", "Math")
     });
 
     nodes.push(NodeSignature {
-        type_id: Power,
-        name: "Power",
-        category: "Math",
         doc: "Raises Base to the given Exponent using f32 powf semantics.",
         inputs: vec![
             PortSpec {
@@ -429,12 +444,10 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Power, "Power", "Math")
     });
 
     nodes.push(NodeSignature {
-        type_id: Log,
-        name: "Log",
-        category: "Math",
         doc: "Computes logarithm of Value in the provided Base; invalid bases yield NaN.",
         inputs: vec![
             PortSpec {
@@ -456,48 +469,70 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Log, "Log", "Math")
     });
 
     nodes.push(NodeSignature {
-        type_id: Abs,
-        name: "Abs",
-        category: "Math",
         doc: "Outputs the absolute value of In component-wise.",
         inputs: vec![p_in()],
         variadic_inputs: None,
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Abs, "Abs", "Math")
     });
 
     nodes.push(NodeSignature {
-        type_id: Sqrt,
-        name: "Sqrt",
-        category: "Math",
         doc: "Outputs the square root of In; negative inputs yield NaN components.",
         inputs: vec![p_in()],
         variadic_inputs: None,
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Sqrt, "Sqrt", "Math")
     });
 
     nodes.push(NodeSignature {
-        type_id: Sign,
-        name: "Sign",
-        category: "Math",
         doc: "Outputs -1 for negative inputs, 0 for zero, and +1 for positive values.",
         inputs: vec![p_in()],
         variadic_inputs: None,
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Sign, "Sign", "Math")
+    });
+
+    nodes.push(NodeSignature {
+        doc: "Clamps each component of In into [0, 1].",
+        inputs: vec![p_in()],
+        variadic_inputs: None,
+        outputs: vec![p_out_float()],
+        variadic_outputs: None,
+        params: vec![],
+        ..NodeSignature::new(Saturate, "Saturate", "Math")
+    });
+
+    nodes.push(NodeSignature {
+        doc: "Outputs -In componentwise.",
+        inputs: vec![p_in()],
+        variadic_inputs: None,
+        outputs: vec![p_out_float()],
+        variadic_outputs: None,
+        params: vec![],
+        ..NodeSignature::new(Negate, "Negate", "Math")
+    });
+
+    nodes.push(NodeSignature {
+        doc: "Outputs 1 / In componentwise; zero components yield NaN.",
+        inputs: vec![p_in()],
+        variadic_inputs: None,
+        outputs: vec![p_out_float()],
+        variadic_outputs: None,
+        params: vec![],
+        ..NodeSignature::new(Reciprocal, "Reciprocal", "Math")
     });
 
     nodes.push(NodeSignature {
-        type_id: Min,
-        name: "Min",
-        category: "Math",
         doc: "Returns the smallest scalar among all operands; missing inputs yield NaN.",
         inputs: vec![],
         variadic_inputs: Some(VariadicSpec {
@@ -508,16 +543,15 @@ pub fn registry() -> Registry {
             min: 2,
             max: None,
             keyed: false,
+            count_param: None,
         }),
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Min, "Min", "Math")
     });
 
     nodes.push(NodeSignature {
-        type_id: Max,
-        name: "Max",
-        category: "Math",
         doc: "Returns the largest scalar among all operands; missing inputs yield NaN.",
         inputs: vec![],
         variadic_inputs: Some(VariadicSpec {
@@ -528,16 +562,15 @@ pub fn registry() -> Registry {
             min: 2,
             max: None,
             keyed: false,
+            count_param: None,
         }),
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Max, "Max", "Math")
     });
 
     nodes.push(NodeSignature {
-        type_id: Round,
-        name: "Round",
-        category: "Math",
         doc: "Rounds In according to the selected Mode (floor, ceil, or trunc).",
         inputs: vec![p_in()],
         variadic_inputs: None,
@@ -552,6 +585,36 @@ pub fn registry() -> Registry {
             min: None,
             max: None,
         }],
+        ..NodeSignature::new(Round, "Round", "Math")
+    });
+
+    nodes.push(NodeSignature {
+        doc: "Snaps each component of In to the nearest Step multiple offset by Offset; a zero Step passes In through.",
+        inputs: vec![p_vector_in()],
+        variadic_inputs: None,
+        outputs: vec![p_out_vector()],
+        variadic_outputs: None,
+        params: vec![
+            ParamSpec {
+                id: "step",
+                ty: ParamType::Float,
+                label: "Step",
+                doc: "Grid spacing; zero or non-finite values disable snapping.",
+                default_json: Some(serde_json::json!({ "f32": 1.0 })),
+                min: Some(0.0),
+                max: None,
+            },
+            ParamSpec {
+                id: "offset",
+                ty: ParamType::Float,
+                label: "Offset",
+                doc: "Grid origin added after snapping.",
+                default_json: Some(serde_json::json!({ "f32": 0.0 })),
+                min: None,
+                max: None,
+            },
+        ],
+        ..NodeSignature::new(Quantize, "Quantize", "Math")
     });
 
     for (nt, name, doc) in [
@@ -572,35 +635,28 @@ pub fn registry() -> Registry {
         ),
     ] {
         nodes.push(NodeSignature {
-            type_id: nt,
-            name,
-            category: "Math",
             doc,
             inputs: vec![p_in()],
             variadic_inputs: None,
             outputs: vec![p_out_float()],
             variadic_outputs: None,
             params: vec![],
+            ..NodeSignature::new(nt, name, "Math")
         });
     }
 
     // Time & generators
     nodes.push(NodeSignature {
-        type_id: Time,
-        name: "Time",
-        category: "Time",
         doc: "Outputs the graph runtime's elapsed seconds.",
         inputs: vec![],
         variadic_inputs: None,
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Time, "Time", "Time")
     });
 
     nodes.push(NodeSignature {
-        type_id: Oscillator,
-        name: "Oscillator",
-        category: "Time",
         doc: "Generates a sine wave using the provided frequency and phase inputs.",
         inputs: vec![
             PortSpec {
@@ -622,13 +678,11 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Oscillator, "Oscillator", "Time")
     });
 
     // Transitions & smoothing
     nodes.push(NodeSignature {
-        type_id: Spring,
-        name: "Spring",
-        category: "Transitions",
         doc: "Integrates a critically damped spring toward the Target; zero or non-finite dt snaps to Target.",
         inputs: vec![PortSpec {
             id: "in",
@@ -675,12 +729,10 @@ pub fn registry() -> Registry {
                 max: None,
             },
         ],
+        ..NodeSignature::new(Spring, "Spring", "Transitions")
     });
 
     nodes.push(NodeSignature {
-        type_id: Damp,
-        name: "Damp",
-        category: "Transitions",
         doc: "Exponentially decays toward the Target using a configurable half-life; zero dt or half-life snaps to Target.",
         inputs: vec![PortSpec {
             id: "in",
@@ -707,12 +759,40 @@ pub fn registry() -> Registry {
             min: Some(0.0),
             max: None,
         }],
+        ..NodeSignature::new(Damp, "Damp", "Transitions")
+    });
+
+    nodes.push(NodeSignature {
+        doc: "Exponential moving average per evaluation: Alpha × In + (1 − Alpha) × previous output, independent of dt; seeded with the first input.",
+        inputs: vec![PortSpec {
+            id: "in",
+            ty: PortType::Vector,
+            label: "In",
+            doc: "Signal to smooth.",
+            optional: false,
+        }],
+        variadic_inputs: None,
+        outputs: vec![PortSpec {
+            id: "out",
+            ty: PortType::Vector,
+            label: "Value",
+            doc: "Smoothed output.",
+            optional: false,
+        }],
+        variadic_outputs: None,
+        params: vec![ParamSpec {
+            id: "alpha",
+            ty: ParamType::Float,
+            label: "Alpha",
+            doc: "Weight of the newest input; 1 passes the input through, 0 holds the first value.",
+            default_json: Some(serde_json::json!({ "f32": 0.5 })),
+            min: Some(0.0),
+            max: Some(1.0),
+        }],
+        ..NodeSignature::new(Ema, "EMA", "Transitions")
     });
 
     nodes.push(NodeSignature {
-        type_id: Slew,
-        name: "Slew",
-        category: "Transitions",
         doc: "Limits the rate of change toward Target using max_rate units per second; zero dt or max_rate snaps to Target.",
         inputs: vec![PortSpec {
             id: "in",
@@ -739,13 +819,159 @@ pub fn registry() -> Registry {
             min: Some(0.0),
             max: None,
         }],
+        ..NodeSignature::new(Slew, "Slew", "Transitions")
+    });
+
+    nodes.push(NodeSignature {
+        doc: "Samples In whenever Trigger is true and holds the last sample otherwise; the first evaluation always samples.",
+        inputs: vec![
+            PortSpec {
+                id: "in",
+                ty: PortType::Any,
+                label: "In",
+                doc: "Value to sample; any shape is preserved.",
+                optional: false,
+            },
+            PortSpec {
+                id: "trigger",
+                ty: PortType::Bool,
+                label: "Trigger",
+                doc: "Samples In on every frame this is true.",
+                optional: false,
+            },
+        ],
+        variadic_inputs: None,
+        outputs: vec![PortSpec {
+            id: "out",
+            ty: PortType::Any,
+            label: "Held",
+            doc: "Most recently sampled value.",
+            optional: false,
+        }],
+        variadic_outputs: None,
+        params: vec![],
+        ..NodeSignature::new(SampleHold, "Sample & Hold", "Transitions")
+    });
+
+    nodes.push(NodeSignature {
+        doc: "Outputs the input from Frames evaluations ago, emitting Default until enough history exists; a change in input shape restarts the history.",
+        inputs: vec![PortSpec {
+            id: "in",
+            ty: PortType::Any,
+            label: "In",
+            doc: "Value to delay; any shape is preserved.",
+            optional: false,
+        }],
+        variadic_inputs: None,
+        outputs: vec![PortSpec {
+            id: "out",
+            ty: PortType::Any,
+            label: "Delayed",
+            doc: "Input value from Frames evaluations ago.",
+            optional: false,
+        }],
+        variadic_outputs: None,
+        params: vec![
+            ParamSpec {
+                id: "frames",
+                ty: ParamType::Float,
+                label: "Frames",
+                doc: "Number of evaluations to delay by; 0 passes the input through.",
+                default_json: Some(serde_json::json!({ "f32": 1.0 })),
+                min: Some(0.0),
+                max: None,
+            },
+            ParamSpec {
+                id: "default",
+                ty: ParamType::Any,
+                label: "Default",
+                doc: "Value emitted before the history fills; numeric inputs default to zeros of the same layout.",
+                default_json: None,
+                min: None,
+                max: None,
+            },
+        ],
+        ..NodeSignature::new(Delay, "Delay", "Transitions")
+    });
+
+    nodes.push(NodeSignature {
+        doc: "Outputs the componentwise average of the last Window inputs (fewer until the window fills); a change in input layout restarts the window.",
+        inputs: vec![p_vector_in()],
+        variadic_inputs: None,
+        outputs: vec![p_out_vector()],
+        variadic_outputs: None,
+        params: vec![ParamSpec {
+            id: "window",
+            ty: ParamType::Float,
+            label: "Window",
+            doc: "Number of recent samples to average (at least 1).",
+            default_json: Some(serde_json::json!({ "f32": 4.0 })),
+            min: Some(1.0),
+            max: None,
+        }],
+        ..NodeSignature::new(MovingAverage, "Moving Average", "Transitions")
+    });
+
+    // Feedback (the tap reads its source by id, so the loop carries no edge and stays acyclic)
+    nodes.push(NodeSignature {
+        doc: "Records the input for Feedback Tap nodes to read on the next frame and passes it through unchanged.",
+        inputs: vec![PortSpec {
+            id: "in",
+            ty: PortType::Any,
+            label: "In",
+            doc: "Value fed back to taps on the next frame.",
+            optional: false,
+        }],
+        variadic_inputs: None,
+        outputs: vec![PortSpec {
+            id: "out",
+            ty: PortType::Any,
+            label: "Out",
+            doc: "Input value for the current frame.",
+            optional: false,
+        }],
+        variadic_outputs: None,
+        params: vec![],
+        ..NodeSignature::new(FeedbackSource, "Feedback Source", "Transitions")
+    });
+
+    nodes.push(NodeSignature {
+        doc: "Outputs the value its Source feedback node recorded on the previous frame, emitting Default until the source has run.",
+        inputs: vec![],
+        variadic_inputs: None,
+        outputs: vec![PortSpec {
+            id: "out",
+            ty: PortType::Any,
+            label: "Previous",
+            doc: "Source value from the previous frame.",
+            optional: false,
+        }],
+        variadic_outputs: None,
+        params: vec![
+            ParamSpec {
+                id: "source",
+                ty: ParamType::Text,
+                label: "Source",
+                doc: "Id of the Feedback Source node to read.",
+                default_json: None,
+                min: None,
+                max: None,
+            },
+            ParamSpec {
+                id: "default",
+                ty: ParamType::Any,
+                label: "Default",
+                doc: "Value emitted before the source has recorded anything; defaults to 0.",
+                default_json: None,
+                min: None,
+                max: None,
+            },
+        ],
+        ..NodeSignature::new(FeedbackTap, "Feedback Tap", "Transitions")
     });
 
     // Logic (Bool semantics)
     nodes.push(NodeSignature {
-        type_id: And,
-        name: "And",
-        category: "Logic",
         doc: "Outputs true when both inputs are true; missing inputs default to false.",
         inputs: vec![
             PortSpec {
@@ -767,11 +993,9 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_bool()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(And, "And", "Logic")
     });
     nodes.push(NodeSignature {
-        type_id: Or,
-        name: "Or",
-        category: "Logic",
         doc: "Outputs true when either input is true; missing inputs default to false.",
         inputs: vec![
             PortSpec {
@@ -793,11 +1017,9 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_bool()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Or, "Or", "Logic")
     });
     nodes.push(NodeSignature {
-        type_id: Xor,
-        name: "Xor",
-        category: "Logic",
         doc: "Outputs true when exactly one input is true; missing inputs default to false.",
         inputs: vec![
             PortSpec {
@@ -819,24 +1041,52 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_bool()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Xor, "Xor", "Logic")
     });
     nodes.push(NodeSignature {
-        type_id: Not,
-        name: "Not",
-        category: "Logic",
         doc: "Outputs the logical negation of the input; missing input defaults to false.",
         inputs: vec![p_bool_in()],
         variadic_inputs: None,
         outputs: vec![p_out_bool()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Not, "Not", "Logic")
+    });
+
+    nodes.push(NodeSignature {
+        doc: "Detects transitions of a boolean input between frames; all edges are false on the first evaluation.",
+        inputs: vec![p_bool_in()],
+        variadic_inputs: None,
+        outputs: vec![
+            PortSpec {
+                id: "rising",
+                ty: PortType::Bool,
+                label: "Rising",
+                doc: "True on the frame the input switches from false to true.",
+                optional: false,
+            },
+            PortSpec {
+                id: "falling",
+                ty: PortType::Bool,
+                label: "Falling",
+                doc: "True on the frame the input switches from true to false.",
+                optional: false,
+            },
+            PortSpec {
+                id: "changed",
+                ty: PortType::Bool,
+                label: "Changed",
+                doc: "True on any frame the input differs from the previous frame.",
+                optional: false,
+            },
+        ],
+        variadic_outputs: None,
+        params: vec![],
+        ..NodeSignature::new(Edge, "Edge", "Logic")
     });
 
     // Conditional (float comparisons)
     nodes.push(NodeSignature {
-        type_id: GreaterThan,
-        name: "Greater Than",
-        category: "Logic",
         doc: "Outputs true when LHS is strictly greater than RHS.",
         inputs: vec![
             PortSpec {
@@ -858,11 +1108,9 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_bool()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(GreaterThan, "Greater Than", "Logic")
     });
     nodes.push(NodeSignature {
-        type_id: LessThan,
-        name: "Less Than",
-        category: "Logic",
         doc: "Outputs true when LHS is strictly less than RHS.",
         inputs: vec![
             PortSpec {
@@ -884,12 +1132,10 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_bool()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(LessThan, "Less Than", "Logic")
     });
     nodes.push(NodeSignature {
-        type_id: Equal,
-        name: "Equal",
-        category: "Logic",
-        doc: "Outputs true when LHS and RHS differ by less than 1e-6.",
+        doc: "Outputs true when LHS and RHS differ by less than Epsilon.",
         inputs: vec![
             PortSpec {
                 id: "lhs",
@@ -909,13 +1155,30 @@ pub fn registry() -> Registry {
         variadic_inputs: None,
         outputs: vec![p_out_bool()],
         variadic_outputs: None,
-        params: vec![],
+        params: vec![
+            ParamSpec {
+                id: "epsilon",
+                ty: ParamType::Float,
+                label: "Epsilon",
+                doc: "Equality tolerance; absolute unless Relative is set.",
+                default_json: Some(serde_json::json!({ "f32": 1e-6 })),
+                min: Some(0.0),
+                max: None,
+            },
+            ParamSpec {
+                id: "relative",
+                ty: ParamType::Bool,
+                label: "Relative",
+                doc: "Scale Epsilon by the larger operand magnitude.",
+                default_json: Some(serde_json::json!({ "bool": false })),
+                min: None,
+                max: None,
+            },
+        ],
+        ..NodeSignature::new(Equal, "Equal", "Logic")
     });
     nodes.push(NodeSignature {
-        type_id: NotEqual,
-        name: "Not Equal",
-        category: "Logic",
-        doc: "Outputs true when LHS and RHS differ by more than 1e-6.",
+        doc: "Outputs true when LHS and RHS differ by more than Epsilon.",
         inputs: vec![
             PortSpec {
                 id: "lhs",
@@ -935,14 +1198,63 @@ pub fn registry() -> Registry {
         variadic_inputs: None,
         outputs: vec![p_out_bool()],
         variadic_outputs: None,
-        params: vec![],
+        params: vec![
+            ParamSpec {
+                id: "epsilon",
+                ty: ParamType::Float,
+                label: "Epsilon",
+                doc: "Equality tolerance; absolute unless Relative is set.",
+                default_json: Some(serde_json::json!({ "f32": 1e-6 })),
+                min: Some(0.0),
+                max: None,
+            },
+            ParamSpec {
+                id: "relative",
+                ty: ParamType::Bool,
+                label: "Relative",
+                doc: "Scale Epsilon by the larger operand magnitude.",
+                default_json: Some(serde_json::json!({ "bool": false })),
+                min: None,
+                max: None,
+            },
+        ],
+        ..NodeSignature::new(NotEqual, "Not Equal", "Logic")
+    });
+    nodes.push(NodeSignature {
+        doc: "Outputs the sign of LHS - RHS per component: -1 when less, 1 when greater, 0 when within Epsilon.",
+        inputs: vec![
+            PortSpec {
+                id: "lhs",
+                ty: PortType::Vector,
+                label: "LHS",
+                doc: "Left-hand operand; scalars broadcast against vectors.",
+                optional: false,
+            },
+            PortSpec {
+                id: "rhs",
+                ty: PortType::Vector,
+                label: "RHS",
+                doc: "Right-hand operand; scalars broadcast against vectors.",
+                optional: false,
+            },
+        ],
+        variadic_inputs: None,
+        outputs: vec![p_out_vector()],
+        variadic_outputs: None,
+        params: vec![ParamSpec {
+            id: "epsilon",
+            ty: ParamType::Float,
+            label: "Epsilon",
+            doc: "Largest difference still reported as equal (0).",
+            default_json: Some(serde_json::json!({ "f32": 1e-6 })),
+            min: Some(0.0),
+            max: None,
+        }],
+        ..NodeSignature::new(Compare, "Compare", "Logic")
     });
 
     // If (union in core; schema uses Vector as generic placeholder)
     nodes.push(NodeSignature {
-        type_id: If,
-        name: "If",
-        category: "Logic",
         doc: "Routes Then when Condition is true, otherwise Else; missing branches default to 0.",
         inputs: vec![
             PortSpec {
@@ -971,13 +1283,11 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_vector()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(If, "If", "Logic")
     });
 
     // Ranges
     nodes.push(NodeSignature {
-        type_id: Clamp,
-        name: "Clamp",
-        category: "Math",
         doc: "Constrains In between Min and Max; expects Min ≤ Max.",
         inputs: vec![
             PortSpec {
@@ -1006,12 +1316,10 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Clamp, "Clamp", "Math")
     });
 
     nodes.push(NodeSignature {
-        type_id: Remap,
-        name: "Remap",
-        category: "Math",
         doc: "Normalizes In from the [In Min, In Max] range into [Out Min, Out Max]; input is clamped to the source range and divide-by-zero yields NaN.",
         inputs: vec![
             PortSpec {
@@ -1054,12 +1362,10 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Remap, "Remap", "Math")
     });
 
     nodes.push(NodeSignature {
-        type_id: CenteredRemap,
-        name: "Centered Remap",
-        category: "Math",
         doc: "Linearly remaps In around an anchor without clamping: values ≤ Anchor use the [In Low, Anchor] span while values ≥ Anchor use [Anchor, In High]; spans may collapse to anchor to pin one side.",
         inputs: vec![
             PortSpec {
@@ -1116,12 +1422,10 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(CenteredRemap, "Centered Remap", "Math")
     });
 
     nodes.push(NodeSignature {
-        type_id: PiecewiseRemap,
-        name: "Piecewise Remap",
-        category: "Math",
         doc: "Remaps In across ordered breakpoint pairs; supports n segments, optional clamping, and extrapolation when Clamp is disabled.",
         inputs: vec![
             PortSpec {
@@ -1158,13 +1462,29 @@ pub fn registry() -> Registry {
             min: None,
             max: None,
         }],
+        ..NodeSignature::new(PiecewiseRemap, "Piecewise Remap", "Math")
     });
 
-    // 3D-specific utility kept
     nodes.push(NodeSignature {
-        type_id: Vec3Cross,
-        name: "Vec3 Cross",
-        category: "Vectors",
+        doc: "Maps each component of In through a piecewise-linear curve defined by Points, holding the first/last output outside their range.",
+        inputs: vec![p_vector_in()],
+        variadic_inputs: None,
+        outputs: vec![p_out_vector()],
+        variadic_outputs: None,
+        params: vec![ParamSpec {
+            id: "points",
+            ty: ParamType::Any,
+            label: "Points",
+            doc: "Breakpoints [[input, output], ...] sorted by input.",
+            default_json: None,
+            min: None,
+            max: None,
+        }],
+        ..NodeSignature::new(Curve, "Curve", "Math")
+    });
+
+    // 3D-specific utility kept
+    nodes.push(NodeSignature {
         doc: "Computes the cross product A × B; mismatched shapes yield NaN components.",
         inputs: vec![
             PortSpec {
@@ -1186,13 +1506,11 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_vec3()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Vec3Cross, "Vec3 Cross", "Vectors")
     });
 
     // Generic vector utilities
     nodes.push(NodeSignature {
-        type_id: VectorConstant,
-        name: "Vector Constant",
-        category: "Vectors",
         doc: "Outputs the configured vector value each frame.",
         inputs: vec![],
         variadic_inputs: None,
@@ -1207,6 +1525,7 @@ pub fn registry() -> Registry {
             min: None,
             max: None,
         }],
+        ..NodeSignature::new(VectorConstant, "Vector Constant", "Vectors")
     });
 
     for (nt, name, doc) in [
@@ -1227,9 +1546,6 @@ pub fn registry() -> Registry {
         ),
     ] {
         nodes.push(NodeSignature {
-            type_id: nt,
-            name,
-            category: "Vectors",
             doc,
             inputs: vec![
                 PortSpec {
@@ -1251,13 +1567,11 @@ pub fn registry() -> Registry {
             outputs: vec![p_out_vector()],
             variadic_outputs: None,
             params: vec![],
+            ..NodeSignature::new(nt, name, "Vectors")
         });
     }
 
     nodes.push(NodeSignature {
-        type_id: VectorScale,
-        name: "Vector Scale",
-        category: "Vectors",
         doc: "Multiplies Vector by Scalar; scalar broadcasts across components.",
         inputs: vec![
             PortSpec {
@@ -1279,24 +1593,20 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_vector()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(VectorScale, "Vector Scale", "Vectors")
     });
 
     nodes.push(NodeSignature {
-        type_id: VectorNormalize,
-        name: "Vector Normalize",
-        category: "Vectors",
         doc: "Normalizes the input vector to unit length; zero-length inputs yield NaN components.",
         inputs: vec![p_vector_in()],
         variadic_inputs: None,
         outputs: vec![p_out_vector()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(VectorNormalize, "Vector Normalize", "Vectors")
     });
 
     nodes.push(NodeSignature {
-        type_id: VectorDot,
-        name: "Vector Dot",
-        category: "Vectors",
         doc: "Computes the dot product of A and B; mismatched shapes yield NaN.",
         inputs: vec![
             PortSpec {
@@ -1318,24 +1628,20 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(VectorDot, "Vector Dot", "Vectors")
     });
 
     nodes.push(NodeSignature {
-        type_id: VectorLength,
-        name: "Vector Length",
-        category: "Vectors",
         doc: "Computes the Euclidean length of the input vector; non-numeric inputs yield NaN.",
         inputs: vec![p_vector_in()],
         variadic_inputs: None,
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(VectorLength, "Vector Length", "Vectors")
     });
 
     nodes.push(NodeSignature {
-        type_id: VectorIndex,
-        name: "Vector Index",
-        category: "Vectors",
         doc: "Extracts the element at floor(Index); out-of-range indices yield NaN.",
         inputs: vec![
             PortSpec {
@@ -1357,13 +1663,11 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(VectorIndex, "Vector Index", "Vectors")
     });
 
     // Join (variadic inputs -> single vector)
     nodes.push(NodeSignature {
-        type_id: Join,
-        name: "Join",
-        category: "Vectors",
         doc: "Concatenates all Operand inputs into a single numeric vector, skipping non-numeric entries.",
         inputs: vec![],
         variadic_inputs: Some(VariadicSpec {
@@ -1374,17 +1678,16 @@ pub fn registry() -> Registry {
             min: 1,
             max: None,
             keyed: false,
+            count_param: None,
         }),
         outputs: vec![p_out_vector()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(Join, "Join", "Vectors")
     });
 
     // Split (vector in, sizes param, variadic vector outputs)
     nodes.push(NodeSignature {
-        type_id: Split,
-        name: "Split",
-        category: "Vectors",
         doc: "Splits In into Parts sized by the Sizes param; mismatched totals return NaN-filled segments.",
         inputs: vec![p_vector_in()],
         variadic_inputs: None,
@@ -1393,10 +1696,12 @@ pub fn registry() -> Registry {
             id: "parts",
             ty: PortType::Vector,
             label: "Part",
-            doc: "Returned segment corresponding to each requested size.",
+            doc: "Returned segment corresponding to each requested size. The port count is \
+                  dynamic: one port per entry in Sizes, named part1..partN.",
             min: 1,
             max: None,
             keyed: false,
+            count_param: Some("sizes"),
         }),
         params: vec![
             ParamSpec {
@@ -1407,15 +1712,22 @@ pub fn registry() -> Registry {
                 default_json: Some(serde_json::json!({ "f32s": [] })),
                 min: None,
                 max: None,
+            },
+            ParamSpec {
+                id: "output_names",
+                ty: ParamType::Any,
+                label: "Output Names",
+                doc: "Optional array of port names, one per size, used instead of part1..N. A count that differs from Sizes is an error.",
+                default_json: None,
+                min: None,
+                max: None,
             }
         ],
+        ..NodeSignature::new(Split, "Split", "Vectors")
     });
 
     // ToVector (variadic Float inputs -> single Vector output)
     nodes.push(NodeSignature {
-        type_id: ToVector,
-        name: "To Vector",
-        category: "Vectors",
         doc: "Assembles variadic Float inputs into a single Vector output in port order.",
         inputs: vec![],
         variadic_inputs: Some(VariadicSpec {
@@ -1426,17 +1738,16 @@ pub fn registry() -> Registry {
             min: 1,
             max: None,
             keyed: false,
+            count_param: None,
         }),
         outputs: vec![p_out_vector()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(ToVector, "To Vector", "Vectors")
     });
 
     // FromVector (single Vector input -> variadic Float outputs)
     nodes.push(NodeSignature {
-        type_id: FromVector,
-        name: "From Vector",
-        category: "Vectors",
         doc: "Unpacks a Vector into variadic Float outputs; excess ports beyond vector length emit NaN.",
         inputs: vec![p_vector_in()],
         variadic_inputs: None,
@@ -1449,8 +1760,10 @@ pub fn registry() -> Registry {
             min: 1,
             max: None,
             keyed: false,
+            count_param: None,
         }),
         params: vec![],
+        ..NodeSignature::new(FromVector, "From Vector", "Vectors")
     });
 
     // Reducers: vector -> float
@@ -1482,15 +1795,59 @@ pub fn registry() -> Registry {
         ),
     ] {
         nodes.push(NodeSignature {
-            type_id: nt,
-            name,
-            category: "Vectors",
             doc,
             inputs: vec![p_vector_in()],
             variadic_inputs: None,
             outputs: vec![p_out_float()],
             variadic_outputs: None,
             params: vec![],
+            ..NodeSignature::new(nt, name, "Vectors")
+        });
+    }
+
+    // Color utilities: ColorRgba/Vec4 -> same kind, alpha preserved
+    for (nt, name, doc) in [
+        (
+            RgbToHsv,
+            "RGB to HSV",
+            "Converts an RGBA color to HSV with hue normalized to [0, 1); alpha passes through.",
+        ),
+        (
+            HsvToRgb,
+            "HSV to RGB",
+            "Converts an HSV color (hue normalized to [0, 1)) to RGB; alpha passes through.",
+        ),
+        (
+            SrgbToLinear,
+            "sRGB to Linear",
+            "Decodes sRGB color channels to linear light; alpha passes through.",
+        ),
+        (
+            LinearToSrgb,
+            "Linear to sRGB",
+            "Encodes linear color channels with the sRGB transfer function; alpha passes through.",
+        ),
+    ] {
+        nodes.push(NodeSignature {
+            doc,
+            inputs: vec![PortSpec {
+                id: "in",
+                ty: PortType::Any,
+                label: "Color",
+                doc: "ColorRgba or Vec4 input; other kinds are rejected.",
+                optional: false,
+            }],
+            variadic_inputs: None,
+            outputs: vec![PortSpec {
+                id: "out",
+                ty: PortType::Any,
+                label: "Color",
+                doc: "Converted color, matching the input kind.",
+                optional: false,
+            }],
+            variadic_outputs: None,
+            params: vec![],
+            ..NodeSignature::new(nt, name, "Color")
         });
     }
 
@@ -1513,9 +1870,6 @@ pub fn registry() -> Registry {
         ),
     ] {
         nodes.push(NodeSignature {
-            type_id: nt,
-            name,
-            category: "Noise",
             doc,
             inputs: vec![
                 PortSpec {
@@ -1583,14 +1937,45 @@ pub fn registry() -> Registry {
                     max: None,
                 },
             ],
+            ..NodeSignature::new(nt, name, "Noise")
         });
     }
 
     // Blend helpers
     nodes.push(NodeSignature {
-        type_id: WeightedSumVector,
-        name: "Weighted Sum Vector",
-        category: "Blend",
+        doc: "Normalized weighted average of the Value inputs using Weights; quaternions nlerp. Emits NaN when the weights sum to zero.",
+        inputs: vec![],
+        variadic_inputs: Some(VariadicSpec {
+            id: "value",
+            ty: PortType::Any,
+            label: "Value",
+            doc: "Values to blend; all must share a kind.",
+            min: 1,
+            max: None,
+            keyed: false,
+            count_param: None,
+        }),
+        outputs: vec![PortSpec {
+            id: "out",
+            ty: PortType::Any,
+            label: "Out",
+            doc: "Weighted average, shaped like the inputs.",
+            optional: false,
+        }],
+        variadic_outputs: None,
+        params: vec![ParamSpec {
+            id: "weights",
+            ty: ParamType::Vector,
+            label: "Weights",
+            doc: "Weight per Value input: value_i takes entry i - 1, whichever ports are connected; missing entries default to 1.",
+            default_json: None,
+            min: None,
+            max: None,
+        }],
+        ..NodeSignature::new(WeightedSum, "Weighted Sum", "Blend")
+    });
+
+    nodes.push(NodeSignature {
         doc: "Pre-computes aggregate blend statistics from Values, optionally applying Weights and Masks; mismatched lengths return NaNs.",
         inputs: vec![
             PortSpec {
@@ -1648,12 +2033,10 @@ pub fn registry() -> Registry {
         ],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(WeightedSumVector, "Weighted Sum Vector", "Blend")
     });
 
     nodes.push(NodeSignature {
-        type_id: DefaultBlend,
-        name: "Default Blend",
-        category: "Blend",
         doc: "Produces a weighted sum of operand inputs plus Baseline and Offset; handles weight broadcasting and falls back to neutral/null when counts mismatch.",
         inputs: vec![
             PortSpec {
@@ -1686,6 +2069,7 @@ pub fn registry() -> Registry {
             min: 0,
             max: None,
             keyed: false,
+            count_param: None,
         }),
         outputs: vec![PortSpec {
             id: "out",
@@ -1696,12 +2080,10 @@ pub fn registry() -> Registry {
         }],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(DefaultBlend, "Default Blend", "Blend")
     });
 
     nodes.push(NodeSignature {
-        type_id: BlendWeightedAverage,
-        name: "Blend - Weighted Average",
-        category: "Blend",
         doc: "Normalises Total Weighted Sum by Total Weight / Max Effective Weight; falls back when the divisor is invalid.",
         inputs: vec![
             PortSpec {
@@ -1737,12 +2119,10 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(BlendWeightedAverage, "Blend - Weighted Average", "Blend")
     });
 
     nodes.push(NodeSignature {
-        type_id: BlendAdditive,
-        name: "Blend - Additive",
-        category: "Blend",
         doc: "Outputs Total Weighted Sum when any inputs contribute; otherwise emits Fallback or NaN.",
         inputs: vec![
             PortSpec {
@@ -1771,12 +2151,10 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(BlendAdditive, "Blend - Additive", "Blend")
     });
 
     nodes.push(NodeSignature {
-        type_id: BlendMultiply,
-        name: "Blend - Multiply",
-        category: "Blend",
         doc: "Multiplies contributions using (1 - weight) + value × weight × mask for each entry; mismatched lengths yield NaN, empty input returns 1.",
         inputs: vec![
             PortSpec {
@@ -1811,12 +2189,10 @@ pub fn registry() -> Registry {
         }],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(BlendMultiply, "Blend - Multiply", "Blend")
     });
 
     nodes.push(NodeSignature {
-        type_id: BlendWeightedOverlay,
-        name: "Blend - Weighted Overlay",
-        category: "Blend",
         doc: "Interpolates between Base and Total Weighted Sum using Max Effective Weight as the blend factor; invalid weights yield NaN.",
         inputs: vec![
             PortSpec {
@@ -1845,12 +2221,10 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(BlendWeightedOverlay, "Blend - Weighted Overlay", "Blend")
     });
 
     nodes.push(NodeSignature {
-        type_id: BlendWeightedAverageOverlay,
-        name: "Blend - Weighted Average Overlay",
-        category: "Blend",
         doc: "Computes an averaged offset and adds it to Base; falls back to Base when averaging fails.",
         inputs: vec![
             PortSpec {
@@ -1886,12 +2260,10 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(BlendWeightedAverageOverlay, "Blend - Weighted Average Overlay", "Blend")
     });
 
     nodes.push(NodeSignature {
-        type_id: BlendMax,
-        name: "Blend - Max",
-        category: "Blend",
         doc: "Selects the value whose weight × mask is largest; scales it by that effective weight or falls back to Base when none contribute.",
         inputs: vec![
             PortSpec {
@@ -1927,13 +2299,12 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_float()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(BlendMax, "Blend - Max", "Blend")
     });
 
     // Case routing (select by string labels)
     nodes.push(NodeSignature {
-        type_id: Case, // reuse If's union output typing; runtime uses NodeType::If/Case mapping. Keep name as 'Case' in types.
-        name: "Case",
-        category: "Logic",
+        // reuse If's union output typing; runtime uses NodeType::If/Case mapping. Keep name as 'Case' in types.
         doc: "Selects the case value whose label matches Selector; returns Default or NaN when no match is found.",
         inputs: vec![
             PortSpec {
@@ -1959,6 +2330,7 @@ pub fn registry() -> Registry {
             min: 0,
             max: None,
             keyed: false,
+            count_param: None,
         }),
         outputs: vec![PortSpec {
             id: "out",
@@ -1977,13 +2349,59 @@ pub fn registry() -> Registry {
             min: None,
             max: None,
         }],
+        ..NodeSignature::new(Case, "Case", "Logic")
+    });
+
+    nodes.push(NodeSignature {
+        doc: "Routes the case_<label> input whose label equals the Selector text; returns Default or NaN when no label matches.",
+        inputs: vec![
+            PortSpec {
+                id: "selector",
+                ty: PortType::Any,
+                label: "Selector",
+                doc: "Text label naming the case to route (exact match against case_labels).",
+                optional: false,
+            },
+            PortSpec {
+                id: "default",
+                ty: PortType::Any,
+                label: "Default",
+                doc: "Value returned when the selector matches no case label.",
+                optional: true,
+            },
+        ],
+        variadic_inputs: Some(VariadicSpec {
+            id: "case",
+            ty: PortType::Any,
+            label: "Case Value",
+            doc: "Value routed when the selector equals its label; port case_<label>.",
+            min: 0,
+            max: None,
+            keyed: true,
+            count_param: Some("case_labels"),
+        }),
+        outputs: vec![PortSpec {
+            id: "out",
+            ty: PortType::Any,
+            label: "Out",
+            doc: "Clone of the routed case value or the default.",
+            optional: false,
+        }],
+        variadic_outputs: None,
+        params: vec![ParamSpec {
+            id: "case_labels",
+            ty: ParamType::Any,
+            label: "Case Labels",
+            doc: "Array of string labels; label L routes the input case_L.",
+            default_json: Some(serde_json::json!({ "strs": [] })),
+            min: None,
+            max: None,
+        }],
+        ..NodeSignature::new(CaseRoute, "Case Route", "Logic")
     });
 
     // Robotics
     nodes.push(NodeSignature {
-        type_id: InverseKinematics,
-        name: "Inverse Kinematics",
-        category: "Robotics",
         doc: "Analytic planar 3-bone IK solver; returns joint angles or NaNs when the target is unreachable.",
         inputs: vec![
             PortSpec {
@@ -2033,14 +2451,17 @@ pub fn registry() -> Registry {
         outputs: vec![p_out_vec3()],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(InverseKinematics, "Inverse Kinematics", "Robotics")
     });
 
-    #[cfg(feature = "urdf_ik")]
+    // Listed in every build; `available` reports whether the `urdf_ik` feature is compiled in.
     {
         nodes.push(NodeSignature {
             type_id: UrdfIkPosition,
             name: "URDF IK (Position)",
             category: "Robotics",
+            available: cfg!(feature = "urdf_ik"),
+            requires_feature: Some("urdf_ik"),
             doc: "Solves for joint angles that reach Target Position using the configured URDF chain; errors when URDF data is missing or the target is unreachable.",
             inputs: vec![
                 PortSpec {
@@ -2059,13 +2480,43 @@ pub fn registry() -> Registry {
                 },
             ],
             variadic_inputs: None,
-            outputs: vec![PortSpec {
-                id: "out",
-                ty: PortType::Any,
-                label: "Joint Angles",
-                doc: "Record mapping joint_name → angle radians.",
-                optional: false,
-            }],
+            outputs: vec![
+                PortSpec {
+                    id: "out",
+                    ty: PortType::Any,
+                    label: "Joint Angles",
+                    doc: "Record mapping joint_name → angle radians.",
+                    optional: false,
+                },
+                PortSpec {
+                    id: "at_limit",
+                    ty: PortType::Any,
+                    label: "At Limit",
+                    doc: "Record mapping joint_name → true when the solved angle sits on a joint limit.",
+                    optional: true,
+                },
+                PortSpec {
+                    id: "converged",
+                    ty: PortType::Bool,
+                    label: "Converged",
+                    doc: "True when the solver reached the target within tolerance.",
+                    optional: true,
+                },
+                PortSpec {
+                    id: "iterations",
+                    ty: PortType::Float,
+                    label: "Iterations",
+                    doc: "Solver iterations spent on this solution.",
+                    optional: true,
+                },
+                PortSpec {
+                    id: "residual",
+                    ty: PortType::Float,
+                    label: "Residual",
+                    doc: "Remaining distance between tip and target position (m).",
+                    optional: true,
+                },
+            ],
             variadic_outputs: None,
             params: vec![
                 ParamSpec {
@@ -2122,6 +2573,24 @@ pub fn registry() -> Registry {
                     min: None,
                     max: None,
                 },
+                ParamSpec {
+                    id: "solver",
+                    ty: ParamType::Text,
+                    label: "Solver",
+                    doc: "IK solver: \"jacobian\" (default) or \"ccd\" (cyclic coordinate descent; unweighted, errors when weights are set).",
+                    default_json: Some(serde_json::json!({ "str": "jacobian" })),
+                    min: None,
+                    max: None,
+                },
+                ParamSpec {
+                    id: "fail_soft",
+                    ty: ParamType::Bool,
+                    label: "Fail Soft",
+                    doc: "Return the best-effort joints with converged = false instead of failing when the solver misses the target.",
+                    default_json: Some(serde_json::json!({ "bool": false })),
+                    min: None,
+                    max: None,
+                },
             ],
         });
 
@@ -2129,6 +2598,8 @@ pub fn registry() -> Registry {
             type_id: UrdfIkPose,
             name: "URDF IK (Pose)",
             category: "Robotics",
+            available: cfg!(feature = "urdf_ik"),
+            requires_feature: Some("urdf_ik"),
             doc: "Solves for joint angles matching both Target Position and Target Rotation; errors when the pose is unreachable or input shapes are invalid.",
             inputs: vec![
                 PortSpec {
@@ -2154,13 +2625,50 @@ pub fn registry() -> Registry {
                 },
             ],
             variadic_inputs: None,
-            outputs: vec![PortSpec {
-                id: "out",
-                ty: PortType::Any,
-                label: "Joint Angles",
-                doc: "Record mapping joint_name → angle radians.",
-                optional: false,
-            }],
+            outputs: vec![
+                PortSpec {
+                    id: "out",
+                    ty: PortType::Any,
+                    label: "Joint Angles",
+                    doc: "Record mapping joint_name → angle radians.",
+                    optional: false,
+                },
+                PortSpec {
+                    id: "at_limit",
+                    ty: PortType::Any,
+                    label: "At Limit",
+                    doc: "Record mapping joint_name → true when the solved angle sits on a joint limit.",
+                    optional: true,
+                },
+                PortSpec {
+                    id: "converged",
+                    ty: PortType::Bool,
+                    label: "Converged",
+                    doc: "True when the solver reached the target within tolerance.",
+                    optional: true,
+                },
+                PortSpec {
+                    id: "iterations",
+                    ty: PortType::Float,
+                    label: "Iterations",
+                    doc: "Solver iterations spent on this solution.",
+                    optional: true,
+                },
+                PortSpec {
+                    id: "residual",
+                    ty: PortType::Float,
+                    label: "Residual",
+                    doc: "Remaining distance between tip and target position (m).",
+                    optional: true,
+                },
+                PortSpec {
+                    id: "residual_rot",
+                    ty: PortType::Float,
+                    label: "Rotation Residual",
+                    doc: "Remaining angle between tip and target rotation (rad).",
+                    optional: true,
+                },
+            ],
             variadic_outputs: None,
             params: vec![
                 ParamSpec {
@@ -2226,6 +2734,148 @@ pub fn registry() -> Registry {
                     min: None,
                     max: None,
                 },
+                ParamSpec {
+                    id: "solver",
+                    ty: ParamType::Text,
+                    label: "Solver",
+                    doc: "IK solver; pose targets support only \"jacobian\".",
+                    default_json: Some(serde_json::json!({ "str": "jacobian" })),
+                    min: None,
+                    max: None,
+                },
+                ParamSpec {
+                    id: "fail_soft",
+                    ty: ParamType::Bool,
+                    label: "Fail Soft",
+                    doc: "Return the best-effort joints with converged = false instead of failing when the solver misses the target.",
+                    default_json: Some(serde_json::json!({ "bool": false })),
+                    min: None,
+                    max: None,
+                },
+            ],
+        });
+
+        nodes.push(NodeSignature {
+            type_id: UrdfIkOrientation,
+            name: "URDF IK (Orientation)",
+            category: "Robotics",
+            available: cfg!(feature = "urdf_ik"),
+            requires_feature: Some("urdf_ik"),
+            doc: "Solves for joint angles matching Target Rotation while leaving the tip position unconstrained; errors when the orientation is unreachable.",
+            inputs: vec![
+                PortSpec {
+                    id: "target_rot",
+                    ty: PortType::Quat,
+                    label: "Target Rotation",
+                    doc: "Target quaternion (x, y, z, w).",
+                    optional: false,
+                },
+                PortSpec {
+                    id: "seed",
+                    ty: PortType::Vector,
+                    label: "Seed",
+                    doc: "Optional joint seed vector.",
+                    optional: true,
+                },
+            ],
+            variadic_inputs: None,
+            outputs: vec![
+                PortSpec {
+                    id: "out",
+                    ty: PortType::Any,
+                    label: "Joint Angles",
+                    doc: "Record mapping joint_name → angle radians.",
+                    optional: false,
+                },
+                PortSpec {
+                    id: "at_limit",
+                    ty: PortType::Any,
+                    label: "At Limit",
+                    doc: "Record mapping joint_name → true when the solved angle sits on a joint limit.",
+                    optional: true,
+                },
+                PortSpec {
+                    id: "converged",
+                    ty: PortType::Bool,
+                    label: "Converged",
+                    doc: "True when the solver reached the target within tolerance.",
+                    optional: true,
+                },
+            ],
+            variadic_outputs: None,
+            params: vec![
+                ParamSpec {
+                    id: "urdf_xml",
+                    ty: ParamType::Any,
+                    label: "URDF XML",
+                    doc: "Robot URDF definition (string).",
+                    default_json: Some(serde_json::json!({ "str": "" })),
+                    min: None,
+                    max: None,
+                },
+                ParamSpec {
+                    id: "root_link",
+                    ty: ParamType::Any,
+                    label: "Root Link",
+                    doc: "Chain root link name.",
+                    default_json: Some(serde_json::json!({ "str": "base_link" })),
+                    min: None,
+                    max: None,
+                },
+                ParamSpec {
+                    id: "tip_link",
+                    ty: ParamType::Any,
+                    label: "Tip Link",
+                    doc: "Chain tip link name.",
+                    default_json: Some(serde_json::json!({ "str": "tool0" })),
+                    min: None,
+                    max: None,
+                },
+                ParamSpec {
+                    id: "weights",
+                    ty: ParamType::Vector,
+                    label: "Joint Weights",
+                    doc: "Optional per-joint weights.",
+                    default_json: Some(serde_json::json!({ "f32s": [] })),
+                    min: None,
+                    max: None,
+                },
+                ParamSpec {
+                    id: "max_iters",
+                    ty: ParamType::Float,
+                    label: "Max Iterations",
+                    doc: "Solver iteration cap.",
+                    default_json: Some(serde_json::json!({ "f32": 100.0 })),
+                    min: None,
+                    max: None,
+                },
+                ParamSpec {
+                    id: "tol_rot",
+                    ty: ParamType::Float,
+                    label: "Rotation Tolerance",
+                    doc: "Solver rotation tolerance (rad).",
+                    default_json: Some(serde_json::json!({ "f32": 0.001 })),
+                    min: None,
+                    max: None,
+                },
+                ParamSpec {
+                    id: "solver",
+                    ty: ParamType::Text,
+                    label: "Solver",
+                    doc: "IK solver; orientation targets support only \"jacobian\".",
+                    default_json: Some(serde_json::json!({ "str": "jacobian" })),
+                    min: None,
+                    max: None,
+                },
+                ParamSpec {
+                    id: "fail_soft",
+                    ty: ParamType::Bool,
+                    label: "Fail Soft",
+                    doc: "Return the best-effort joints with converged = false instead of failing when the solver misses the target.",
+                    default_json: Some(serde_json::json!({ "bool": false })),
+                    min: None,
+                    max: None,
+                },
             ],
         });
 
@@ -2233,6 +2883,8 @@ pub fn registry() -> Registry {
             type_id: UrdfFk,
             name: "URDF FK",
             category: "Robotics",
+            available: cfg!(feature = "urdf_ik"),
+            requires_feature: Some("urdf_ik"),
             doc: "Applies forward kinematics for the configured URDF chain using provided joint values or defaults.",
             inputs: vec![PortSpec {
                 id: "joints",
@@ -2264,6 +2916,13 @@ pub fn registry() -> Registry {
                     doc: "Full pose convenience output (pos + rot + unit scale).",
                     optional: true,
                 },
+                PortSpec {
+                    id: "links",
+                    ty: PortType::Any,
+                    label: "Link Transforms",
+                    doc: "Record mapping link_name → world Transform for every link in the chain.",
+                    optional: true,
+                },
             ],
             variadic_outputs: None,
             params: vec![
@@ -2309,9 +2968,6 @@ pub fn registry() -> Registry {
 
     // IO nodes
     nodes.push(NodeSignature {
-        type_id: Input,
-        name: "Input",
-        category: "IO",
         doc: "Reads a staged value from the host path or emits the configured Default; enforces declared output shape when provided.",
         inputs: vec![],
         variadic_inputs: None,
@@ -2343,12 +2999,10 @@ pub fn registry() -> Registry {
                 max: None,
             },
         ],
+        ..NodeSignature::new(Input, "Input", "IO")
     });
 
     nodes.push(NodeSignature {
-        type_id: Output,
-        name: "Output",
-        category: "IO",
         doc: "Publishes In to the host path while passing the value through for downstream nodes. \
               Without a path, In is a keyed record batch and each record writes to the path its \
               key_field names (value from value_field) — records that name their own keys apply \
@@ -2397,14 +3051,23 @@ pub fn registry() -> Registry {
                 min: None,
                 max: None,
             },
+            ParamSpec {
+                id: "accumulate",
+                ty: ParamType::Text,
+                label: "Accumulate",
+                doc: "How this write combines with earlier writes to the same path: \"last\" \
+                      (replace), \"sum\" (add componentwise), or \"list\" (collect into an \
+                      array; a non-list earlier write becomes its first element).",
+                default_json: Some(serde_json::json!({ "str": "last" })),
+                min: None,
+                max: None,
+            },
         ],
+        ..NodeSignature::new(Output, "Output", "IO")
     });
 
     // Records
     nodes.push(NodeSignature {
-        type_id: BuildRecord,
-        name: "Build Record",
-        category: "Records",
         doc: "Assembles a Record from variadic Any-typed inputs. \
               Each slot has a user-defined string key stored in params.record_keys.",
         inputs: vec![],
@@ -2416,6 +3079,7 @@ pub fn registry() -> Registry {
             min: 1,
             max: None,
             keyed: true,
+            count_param: None,
         }),
         outputs: vec![PortSpec {
             id: "out",
@@ -2426,12 +3090,10 @@ pub fn registry() -> Registry {
         }],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(BuildRecord, "Build Record", "Records")
     });
 
     nodes.push(NodeSignature {
-        type_id: ReadRecord,
-        name: "Read Record",
-        category: "Records",
         doc: "Extracts individual fields from a Record by key. \
               Each output slot corresponds to one key stored in params.record_keys.",
         inputs: vec![PortSpec {
@@ -2451,15 +3113,14 @@ pub fn registry() -> Registry {
             min: 1,
             max: None,
             keyed: true,
+            count_param: Some("record_keys"),
         }),
         params: vec![],
+        ..NodeSignature::new(ReadRecord, "Read Record", "Records")
     });
 
     // SwitchRecord
     nodes.push(NodeSignature {
-        type_id: SwitchRecord,
-        name: "Switch Record",
-        category: "Records",
         doc: "Selects one of the variadic Record inputs by index (floored from the Switch scalar).",
         inputs: vec![PortSpec {
             id: "switch",
@@ -2476,6 +3137,7 @@ pub fn registry() -> Registry {
             min: 2,
             max: None,
             keyed: false,
+            count_param: None,
         }),
         outputs: vec![PortSpec {
             id: "out",
@@ -2486,13 +3148,11 @@ pub fn registry() -> Registry {
         }],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(SwitchRecord, "Switch Record", "Records")
     });
 
     // MergeRecord
     nodes.push(NodeSignature {
-        type_id: MergeRecord,
-        name: "Merge Record",
-        category: "Records",
         doc: "Merges variadic Record inputs into one; later inputs overwrite earlier fields.",
         inputs: vec![],
         variadic_inputs: Some(VariadicSpec {
@@ -2503,6 +3163,7 @@ pub fn registry() -> Registry {
             min: 2,
             max: None,
             keyed: false,
+            count_param: None,
         }),
         outputs: vec![PortSpec {
             id: "out",
@@ -2513,13 +3174,11 @@ pub fn registry() -> Registry {
         }],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(MergeRecord, "Merge Record", "Records")
     });
 
     // SplitRecord
     nodes.push(NodeSignature {
-        type_id: SplitRecord,
-        name: "Split Record",
-        category: "Records",
         doc: "Splits a Record into two: fields whose keys are listed in the Keys param, and the rest.",
         inputs: vec![PortSpec {
             id: "in",
@@ -2555,13 +3214,108 @@ pub fn registry() -> Registry {
             min: None,
             max: None,
         }],
+        ..NodeSignature::new(SplitRecord, "Split Record", "Records")
+    });
+
+    // Lists
+    nodes.push(NodeSignature {
+        doc: "Counts the elements of a List/Array (or the components of a numeric value).",
+        inputs: vec![PortSpec {
+            id: "list",
+            ty: PortType::Any,
+            label: "List",
+            doc: "The list to measure.",
+            optional: false,
+        }],
+        variadic_inputs: None,
+        outputs: vec![p_out_float()],
+        variadic_outputs: None,
+        params: vec![],
+        ..NodeSignature::new(ListLength, "List Length", "Lists")
+    });
+
+    nodes.push(NodeSignature {
+        doc: "Extracts the element at floor(Index); out-of-range indices yield NaN.",
+        inputs: vec![
+            PortSpec {
+                id: "list",
+                ty: PortType::Any,
+                label: "List",
+                doc: "The list to read from.",
+                optional: false,
+            },
+            PortSpec {
+                id: "index",
+                ty: PortType::Float,
+                label: "Index",
+                doc: "0-based index; overrides the Index param when connected.",
+                optional: true,
+            },
+        ],
+        variadic_inputs: None,
+        outputs: vec![PortSpec {
+            id: "out",
+            ty: PortType::Any,
+            label: "Element",
+            doc: "The element at the requested index.",
+            optional: false,
+        }],
+        variadic_outputs: None,
+        params: vec![ParamSpec {
+            id: "index",
+            ty: ParamType::Float,
+            label: "Index",
+            doc: "0-based index used when the Index input is unconnected.",
+            default_json: Some(serde_json::json!({ "f32": 0.0 })),
+            min: Some(0.0),
+            max: None,
+        }],
+        ..NodeSignature::new(ListGet, "List Get", "Lists")
+    });
+
+    // Text
+    nodes.push(NodeSignature {
+        doc: "Formats a number as Text, e.g. for labels and debug overlays.",
+        inputs: vec![p_in()],
+        variadic_inputs: None,
+        outputs: vec![PortSpec {
+            id: "out",
+            ty: PortType::Any,
+            label: "Text",
+            doc: "The formatted number.",
+            optional: false,
+        }],
+        variadic_outputs: None,
+        params: vec![ParamSpec {
+            id: "precision",
+            ty: ParamType::Float,
+            label: "Precision",
+            doc: "Decimal places (0-16). Unset prints the shortest exact form.",
+            default_json: None,
+            min: Some(0.0),
+            max: Some(16.0),
+        }],
+        ..NodeSignature::new(NumberToText, "Number To Text", "Text")
+    });
+
+    nodes.push(NodeSignature {
+        doc: "Parses Text into a Float; text that is not a number yields NaN.",
+        inputs: vec![PortSpec {
+            id: "in",
+            ty: PortType::Any,
+            label: "Text",
+            doc: "The text to parse; surrounding whitespace is ignored.",
+            optional: false,
+        }],
+        variadic_inputs: None,
+        outputs: vec![p_out_float()],
+        variadic_outputs: None,
+        params: vec![],
+        ..NodeSignature::new(TextToNumber, "Text To Number", "Text")
     });
 
     // MathMultRecord
     nodes.push(NodeSignature {
-        type_id: MathMultRecord,
-        name: "Math Mult Record",
-        category: "Records",
         doc: "Multiplies each numeric field in the Record by a scalar value.",
         inputs: vec![
             PortSpec {
@@ -2589,13 +3343,11 @@ pub fn registry() -> Registry {
         }],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(MathMultRecord, "Math Mult Record", "Records")
     });
 
     // MathAddRecord
     nodes.push(NodeSignature {
-        type_id: MathAddRecord,
-        name: "Math Add Record",
-        category: "Records",
         doc: "Adds a scalar value to each numeric field in the Record.",
         inputs: vec![
             PortSpec {
@@ -2623,13 +3375,11 @@ pub fn registry() -> Registry {
         }],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(MathAddRecord, "Math Add Record", "Records")
     });
 
     // MathDivRecord
     nodes.push(NodeSignature {
-        type_id: MathDivRecord,
-        name: "Math Div Record",
-        category: "Records",
         doc: "Divides each numeric field in the Record by a scalar value; division by zero yields NaN.",
         inputs: vec![
             PortSpec {
@@ -2657,13 +3407,11 @@ pub fn registry() -> Registry {
         }],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(MathDivRecord, "Math Div Record", "Records")
     });
 
     // MathSubRecord
     nodes.push(NodeSignature {
-        type_id: MathSubRecord,
-        name: "Math Sub Record",
-        category: "Records",
         doc: "Subtracts a scalar value from each numeric field in the Record.",
         inputs: vec![
             PortSpec {
@@ -2691,13 +3439,11 @@ pub fn registry() -> Registry {
         }],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(MathSubRecord, "Math Sub Record", "Records")
     });
 
     // External function invocation (variadic args -> single result via host function interface)
     nodes.push(NodeSignature {
-        type_id: ExternalFunction,
-        name: "External Function",
-        category: "Functions",
         doc: "Invokes an external function (by opaque id) through the host-provided function \
               interface, passing the variadic Args inputs zipped with the configured param_ids.",
         inputs: vec![],
@@ -2709,6 +3455,7 @@ pub fn registry() -> Registry {
             min: 0,
             max: None,
             keyed: false,
+            count_param: None,
         }),
         outputs: vec![PortSpec {
             id: "out",
@@ -2719,6 +3466,29 @@ pub fn registry() -> Registry {
         }],
         variadic_outputs: None,
         params: vec![],
+        ..NodeSignature::new(ExternalFunction, "External Function", "Functions")
+    });
+
+    // Composition (ports are declared by the node's subgraph mappings, not the signature)
+    nodes.push(NodeSignature {
+        doc: "Evaluates a nested graph each tick. Mapped input ports are staged into the child \
+              graph's Input nodes by path, and mapped child outputs are exposed as output ports. \
+              Stateful child nodes keep their state across frames.",
+        inputs: vec![],
+        variadic_inputs: None,
+        outputs: vec![],
+        variadic_outputs: None,
+        params: vec![ParamSpec {
+            id: "subgraph",
+            ty: ParamType::Any,
+            label: "Subgraph",
+            doc: "Nested graph plus its input (port -> path) and output (port -> node.output) \
+                  mappings.",
+            default_json: None,
+            min: None,
+            max: None,
+        }],
+        ..NodeSignature::new(Subgraph, "Subgraph", "Composition")
     });
 
     Registry {
@@ -2726,3 +3496,62 @@ pub fn registry() -> Registry {
         nodes,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signature(kind: NodeType) -> NodeSignature {
+        registry()
+            .nodes
+            .into_iter()
+            .find(|sig| sig.type_id == kind)
+            .unwrap_or_else(|| panic!("{kind:?} missing from registry"))
+    }
+
+    #[test]
+    fn urdf_nodes_report_feature_availability() {
+        for kind in [
+            NodeType::UrdfIkPosition,
+            NodeType::UrdfIkPose,
+            NodeType::UrdfIkOrientation,
+            NodeType::UrdfFk,
+        ] {
+            let sig = signature(kind);
+            assert_eq!(sig.requires_feature, Some("urdf_ik"));
+            // Listed with the feature off (unavailable) and on (available).
+            assert_eq!(sig.available, cfg!(feature = "urdf_ik"));
+        }
+        let add = signature(NodeType::Add);
+        assert!(add.available);
+        assert_eq!(add.requires_feature, None);
+    }
+
+    #[test]
+    fn multislider_reports_three_scalar_outputs() {
+        let sig = signature(NodeType::MultiSlider);
+        let ids: Vec<&str> = sig.outputs.iter().map(|port| port.id).collect();
+        assert_eq!(ids, vec!["x", "y", "z"]);
+        assert!(sig
+            .outputs
+            .iter()
+            .all(|port| matches!(port.ty, PortType::Float)));
+        assert!(sig.variadic_outputs.is_none());
+    }
+
+    #[test]
+    fn split_documents_variadic_outputs() {
+        let sig = signature(NodeType::Split);
+        assert!(sig.outputs.is_empty());
+        let parts = sig
+            .variadic_outputs
+            .expect("split exposes variadic outputs");
+        assert_eq!(parts.id, "parts");
+        assert!(matches!(parts.ty, PortType::Vector));
+        assert_eq!(parts.count_param, Some("sizes"));
+        assert!(sig.params.iter().any(|param| param.id == "sizes"));
+
+        let json = serde_json::to_value(&parts).expect("variadic spec serializes");
+        assert_eq!(json["count_param"], "sizes");
+    }
+}
@@ -0,0 +1,145 @@
+//! Programmatic [`GraphSpec`] builders for tests and benchmarks.
+//!
+//! Enabled by the `testutil` feature. The builders construct specs directly (no JSON), so
+//! benchmarks can evaluate them repeatedly with [`crate::evaluate_all`] without measuring
+//! parsing or normalization.
+
+use hashbrown::HashMap;
+use vizij_api_core::{value as vocab, Value};
+
+use crate::types::{
+    EdgeInputEndpoint, EdgeOutputEndpoint, EdgeSpec, GraphSpec, NodeParams, NodeSpec, NodeType,
+};
+
+/// Edge from `from`'s `out` port into `to`'s `input` port.
+pub fn link(from: &str, to: &str, input: &str) -> EdgeSpec {
+    EdgeSpec {
+        from: EdgeOutputEndpoint {
+            node_id: from.to_string(),
+            output: "out".into(),
+        },
+        to: EdgeInputEndpoint {
+            node_id: to.to_string(),
+            input: input.to_string(),
+        },
+        selector: None,
+    }
+}
+
+/// Node of `kind` with default params and no declared shapes or input defaults.
+pub fn node(id: impl Into<String>, kind: NodeType) -> NodeSpec {
+    NodeSpec {
+        id: id.into(),
+        kind,
+        params: NodeParams::default(),
+        output_shapes: HashMap::new(),
+        input_defaults: HashMap::new(),
+        metadata: serde_json::Value::Null,
+    }
+}
+
+/// `Constant` node emitting `value`.
+pub fn constant(id: impl Into<String>, value: Value) -> NodeSpec {
+    NodeSpec {
+        params: NodeParams {
+            value: Some(value),
+            ..Default::default()
+        },
+        ..node(id, NodeType::Constant)
+    }
+}
+
+/// A chain of `n` (at least one) `Add` nodes fed by a `1.0` constant, each adding its own `0.5`
+/// bias.
+///
+/// The last node is `add_{n-1}` and evaluates to `1.0 + 0.5 * n`.
+pub fn chain_of_adds(n: usize) -> GraphSpec {
+    let n = n.max(1);
+    let mut nodes = Vec::with_capacity(2 * n + 1);
+    let mut edges = Vec::with_capacity(2 * n);
+
+    nodes.push(constant("const_base", vocab::float(1.0)));
+    let mut prev = "const_base".to_string();
+    for i in 0..n {
+        let bias = format!("bias_{i}");
+        let add = format!("add_{i}");
+        nodes.push(constant(&bias, vocab::float(0.5)));
+        nodes.push(node(&add, NodeType::Add));
+        edges.push(link(&prev, &add, "operand_1"));
+        edges.push(link(&bias, &add, "operand_2"));
+        prev = add;
+    }
+
+    GraphSpec {
+        nodes,
+        edges,
+        ..Default::default()
+    }
+    .with_cache()
+}
+
+/// A vector pipeline over a `len`-element vector `[0, 1, ..., len - 1]` (node `vec`): scaled by
+/// `2.0` (`scale`) and added back to the input (`sum`).
+///
+/// `sum` evaluates to `[0, 3, 6, ..., 3 * (len - 1)]`.
+pub fn vector_pipeline(len: usize) -> GraphSpec {
+    let data: Vec<f32> = (0..len).map(|i| i as f32).collect();
+    let nodes = vec![
+        NodeSpec {
+            params: NodeParams {
+                value: Some(vocab::vector(data)),
+                ..Default::default()
+            },
+            ..node("vec", NodeType::VectorConstant)
+        },
+        constant("gain", vocab::float(2.0)),
+        node("scale", NodeType::VectorScale),
+        node("sum", NodeType::VectorAdd),
+    ];
+    let edges = vec![
+        link("vec", "scale", "v"),
+        link("gain", "scale", "scalar"),
+        link("scale", "sum", "a"),
+        link("vec", "sum", "b"),
+    ];
+
+    GraphSpec {
+        nodes,
+        edges,
+        ..Default::default()
+    }
+    .with_cache()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{evaluate_all, GraphRuntime};
+
+    fn output(rt: &GraphRuntime, id: &str) -> Value {
+        rt.outputs
+            .get(id)
+            .and_then(|ports| ports.get("out"))
+            .map(|port| port.value.clone())
+            .unwrap_or_else(|| panic!("missing output for {id}"))
+    }
+
+    #[test]
+    fn chain_of_adds_sums_biases() {
+        let spec = chain_of_adds(4);
+        let mut rt = GraphRuntime::default();
+        evaluate_all(&mut rt, &spec).expect("chain should evaluate");
+        assert_eq!(vocab::as_float(&output(&rt, "add_3")), Some(3.0));
+    }
+
+    #[test]
+    fn vector_pipeline_scales_and_adds() {
+        let spec = vector_pipeline(5);
+        let mut rt = GraphRuntime::default();
+        evaluate_all(&mut rt, &spec).expect("pipeline should evaluate");
+        assert_eq!(
+            vocab::as_vector(&output(&rt, "sum")),
+            Some(&[0.0, 3.0, 6.0, 9.0, 12.0][..])
+        );
+    }
+}
@@ -78,6 +78,7 @@ mod tests {
                     },
                     output_shapes: Default::default(),
                     input_defaults: Default::default(),
+                    metadata: serde_json::Value::Null,
                 },
                 NodeSpec {
                     id: "b".into(),
@@ -85,6 +86,7 @@ mod tests {
                     params: Default::default(),
                     output_shapes: Default::default(),
                     input_defaults: Default::default(),
+                    metadata: serde_json::Value::Null,
                 },
             ],
             edges: vec![EdgeSpec {
@@ -45,9 +45,17 @@ pub enum NodeType {
     Modulo,
     Sqrt,
     Sign,
+    /// Clamps each component into `[0, 1]`.
+    Saturate,
+    /// Negates each component.
+    Negate,
+    /// Componentwise `1 / x`; zero components yield NaN.
+    Reciprocal,
     Min,
     Max,
     Round,
+    /// Snaps each component to the nearest multiple of `step` (shifted by `offset`).
+    Quantize,
     Sin,
     Cos,
     Tan,
@@ -61,21 +69,39 @@ pub enum NodeType {
     Spring,
     Damp,
     Slew,
+    /// Frame-based exponential moving average: `alpha * in + (1 - alpha) * previous`.
+    Ema,
+    /// Latches `in` whenever `trigger` is truthy and holds it otherwise.
+    SampleHold,
+    /// Emits the input observed a fixed number of evaluations ago.
+    Delay,
+    /// Componentwise average of the last `window` inputs.
+    MovingAverage,
+    /// Records its `in` value for [`NodeType::FeedbackTap`] nodes and passes it through.
+    FeedbackSource,
+    /// Emits the previous frame's value of the `source` feedback node, breaking the cycle.
+    FeedbackTap,
 
     // Logic
     And,
     Or,
     Not,
     Xor,
+    /// Detects rising/falling transitions of a boolean input across frames.
+    Edge,
 
     // Conditional
     GreaterThan,
     LessThan,
     Equal,
     NotEqual,
+    /// Three-way comparison emitting -1, 0, or 1 per component.
+    Compare,
     If,
     /// Control-flow helper that routes one of several branches by label.
     Case,
+    /// Routes the `case_<label>` input named by a text selector, complementing index-based `Select`.
+    CaseRoute,
 
     // Ranges
     Clamp,
@@ -86,6 +112,8 @@ pub enum NodeType {
     /// Remap through multiple segments, optionally clamping at the ends.
     #[serde(rename = "piecewise_remap")]
     PiecewiseRemap,
+    /// Piecewise-linear curve through `(input, output)` breakpoints, clamped at the ends.
+    Curve,
 
     // 3D-specific utilities
     Vec3Cross,
@@ -110,6 +138,16 @@ pub enum NodeType {
     ToVector,
     FromVector,
 
+    // Color utilities
+    /// Converts an RGBA color to HSV (hue normalized to `[0, 1)`), preserving alpha.
+    RgbToHsv,
+    /// Converts an HSV color (hue normalized to `[0, 1)`) back to RGBA, preserving alpha.
+    HsvToRgb,
+    /// Decodes sRGB-encoded color channels to linear light, preserving alpha.
+    SrgbToLinear,
+    /// Encodes linear color channels with the sRGB transfer function, preserving alpha.
+    LinearToSrgb,
+
     // Noise generators
     SimpleNoise,
     PerlinNoise,
@@ -117,6 +155,8 @@ pub enum NodeType {
 
     // Blend helpers
     WeightedSumVector,
+    /// Normalized weighted average of variadic `value_*` inputs, weighted by the `weights` param.
+    WeightedSum,
     /// Host-default blending strategy for structured values.
     #[serde(rename = "default-blend")]
     DefaultBlend,
@@ -133,6 +173,8 @@ pub enum NodeType {
     UrdfIkPosition,
     /// URDF-backed IK solver targeting translation and rotation.
     UrdfIkPose,
+    /// URDF-backed IK solver targeting rotation only.
+    UrdfIkOrientation,
     /// URDF-backed forward-kinematics solver.
     UrdfFk,
 
@@ -147,6 +189,22 @@ pub enum NodeType {
     MathDivRecord,
     MathSubRecord,
 
+    // Lists
+    /// Counts the elements of a list/array value.
+    ListLength,
+    /// Reads one element of a list/array value by index.
+    ListGet,
+
+    // Text
+    /// Formats a scalar as text with `precision` decimals.
+    NumberToText,
+    /// Parses text into a float, yielding NaN when it is not a number.
+    TextToNumber,
+
+    // Composition
+    /// Evaluates a nested [`GraphSpec`] and exposes selected child outputs as its own ports.
+    Subgraph,
+
     // IO
     /// Reads a staged host value by typed path.
     Input,
@@ -194,9 +252,23 @@ pub struct NodeParams {
     /// Rounding mode used by [`NodeType::Round`].
     #[serde(default)]
     pub round_mode: Option<RoundMode>,
+    /// Grid spacing for [`NodeType::Quantize`]; zero or non-finite passes values through.
+    pub step: Option<f32>,
+    /// Grid origin for [`NodeType::Quantize`].
+    pub offset: Option<f32>,
+    /// Equality tolerance for [`NodeType::Compare`], [`NodeType::Equal`], and
+    /// [`NodeType::NotEqual`].
+    pub epsilon: Option<f32>,
+    /// When true, [`NodeType::Equal`]/[`NodeType::NotEqual`] scale `epsilon` by the larger
+    /// operand magnitude instead of using it as an absolute tolerance.
+    #[serde(default)]
+    pub relative: Option<bool>,
     /// Clamp behavior for [`NodeType::PiecewiseRemap`].
     #[serde(default)]
     pub clamp: Option<bool>,
+    /// `(input, output)` breakpoints for [`NodeType::Curve`], sorted by input.
+    #[serde(default)]
+    pub points: Option<Vec<(f32, f32)>>,
     /// Input low anchor for [`NodeType::CenteredRemap`].
     pub in_low: Option<f32>,
     /// Input center anchor for [`NodeType::CenteredRemap`].
@@ -223,7 +295,8 @@ pub struct NodeParams {
     pub tip_link: Option<String>,
     /// Optional seed vector passed into IK solvers.
     pub seed: Option<Vec<f32>>,
-    /// Optional per-joint weights passed into IK solvers.
+    /// Optional per-joint weights passed into IK solvers; also the per-input weights of
+    /// [`NodeType::WeightedSum`].
     pub weights: Option<Vec<f32>>,
     /// Maximum solver iterations for IK nodes.
     pub max_iters: Option<u32>,
@@ -231,6 +304,13 @@ pub struct NodeParams {
     pub tol_pos: Option<f32>,
     /// Rotational tolerance for IK convergence.
     pub tol_rot: Option<f32>,
+    /// IK solver used by URDF IK nodes: `"jacobian"` (default) or `"ccd"`.
+    #[serde(default)]
+    pub solver: Option<String>,
+    /// When true, URDF IK nodes return their best-effort solution with `converged = false`
+    /// instead of failing evaluation.
+    #[serde(default)]
+    pub fail_soft: Option<bool>,
     /// Default joint values keyed by joint name for URDF-backed nodes.
     #[serde(default)]
     pub joint_defaults: Option<Vec<(String, f32)>>,
@@ -238,6 +318,10 @@ pub struct NodeParams {
     pub index: Option<f32>,
     /// Segment sizes for [`NodeType::Split`], floored to whole-number widths by the evaluator.
     pub sizes: Option<Vec<f32>>,
+    /// Output port names for [`NodeType::Split`], one per entry of `sizes`; unset keeps the
+    /// default `part1..N` keys.
+    #[serde(default)]
+    pub output_names: Option<Vec<String>>,
 
     /// Noise seed value passed into procedural noise nodes.
     pub noise_seed: Option<f32>,
@@ -258,8 +342,24 @@ pub struct NodeParams {
     pub half_life: Option<f32>,
     /// Maximum change rate for slew nodes.
     pub max_rate: Option<f32>,
+    /// Per-evaluation smoothing factor in `[0, 1]` for [`NodeType::Ema`].
+    pub alpha: Option<f32>,
+    /// Number of evaluations [`NodeType::Delay`] lags its input by.
+    pub frames: Option<u32>,
+    /// Number of samples [`NodeType::MovingAverage`] averages over.
+    pub window: Option<u32>,
+    /// Decimal places used by [`NodeType::NumberToText`]; unset prints the shortest exact form.
+    pub precision: Option<u32>,
+    /// Id of the [`NodeType::FeedbackSource`] read by a [`NodeType::FeedbackTap`].
+    #[serde(default)]
+    pub source: Option<NodeId>,
+    /// Value emitted by [`NodeType::Delay`] until its history fills, and by
+    /// [`NodeType::FeedbackTap`] until its source has run.
+    #[serde(default)]
+    pub default: Option<Value>,
 
-    /// Branch labels for [`NodeType::Case`], matched in declaration order.
+    /// Branch labels for [`NodeType::Case`], matched in declaration order, and the labels
+    /// [`NodeType::CaseRoute`] accepts.
     #[serde(default)]
     pub case_labels: Option<Vec<String>>,
 
@@ -278,6 +378,14 @@ pub struct NodeParams {
     /// Example: `"robot1/Arm/Joint3.translation"`.
     #[serde(default)]
     pub path: Option<TypedPath>,
+    /// How an [`NodeType::Output`] combines with earlier writes to the same `path` in one
+    /// evaluation: `"last"` (default, last wins), `"sum"`, or `"list"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accumulate: Option<String>,
+
+    /// Nested graph and port mappings evaluated by [`NodeType::Subgraph`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subgraph: Option<Box<SubgraphSpec>>,
 
     /// Stable id of the node-function invoked by [`NodeType::ExternalFunction`].
     ///
@@ -315,6 +423,44 @@ pub enum RoundMode {
     Trunc,
 }
 
+/// Nested graph carried by a [`NodeType::Subgraph`] node.
+///
+/// The child graph runs on its own runtime once per parent evaluation. Parent input ports are
+/// staged into the child's [`NodeType::Input`] nodes by path, and child node outputs are exposed
+/// as parent output ports.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SubgraphSpec {
+    /// Graph evaluated inside the parent node.
+    pub graph: GraphSpec,
+    /// Parent input ports forwarded into the child graph.
+    #[serde(default)]
+    pub inputs: Vec<SubgraphInput>,
+    /// Child node outputs exposed on the parent node.
+    #[serde(default)]
+    pub outputs: Vec<SubgraphOutput>,
+}
+
+/// Maps a parent input port onto a staged input path of the child graph.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubgraphInput {
+    /// Input port name on the [`NodeType::Subgraph`] node.
+    pub port: String,
+    /// Typed path read by an [`NodeType::Input`] node inside the child graph.
+    pub path: TypedPath,
+}
+
+/// Maps a child node output onto a parent output port.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubgraphOutput {
+    /// Output port name on the [`NodeType::Subgraph`] node.
+    pub port: String,
+    /// Child node whose output is exposed.
+    pub node_id: NodeId,
+    /// Child output port name. Defaults to `"out"` when omitted in JSON.
+    #[serde(default = "default_output_key")]
+    pub output: String,
+}
+
 /// Single node declaration within a [`GraphSpec`].
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NodeSpec {
@@ -332,6 +478,10 @@ pub struct NodeSpec {
     /// Inline default values used when an input port has no inbound edge.
     #[serde(default)]
     pub input_defaults: HashMap<String, InputDefault>,
+    /// Opaque editor/UI annotations (position, notes, color, ...). Ignored by evaluation and
+    /// preserved verbatim through normalization and serialization.
+    #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+    pub metadata: serde_json::Value,
 }
 
 /// Serializable graph layout evaluated by the runtime and wasm hosts.
@@ -438,6 +588,72 @@ impl GraphSpec {
         crate::eval::fingerprint_spec(self)
     }
 
+    /// Distinct node types used by this spec, including those inside `Subgraph` child graphs,
+    /// e.g. to detect feature-gated nodes before evaluating in a build that lacks them.
+    pub fn node_type_set(&self) -> HashSet<NodeType> {
+        let mut types = HashSet::new();
+        self.collect_node_types(&mut types);
+        types
+    }
+
+    fn collect_node_types(&self, types: &mut HashSet<NodeType>) {
+        for node in &self.nodes {
+            types.insert(node.kind.clone());
+            if let Some(sub) = &node.params.subgraph {
+                sub.graph.collect_node_types(types);
+            }
+        }
+    }
+
+    /// Copy the nodes named in `ids`, and the edges between them, into a standalone spec.
+    ///
+    /// Edges entering the selection from outside are dropped. When such an edge came from a
+    /// [`NodeType::Constant`] without a selector, the constant's value is inlined as the port's
+    /// input default so the extracted nodes keep seeing the same value; other dangling inputs
+    /// fall back to the node's own default. Unknown ids are ignored, and the result carries no
+    /// cache version.
+    pub fn extract(&self, ids: &[NodeId]) -> GraphSpec {
+        let keep: HashSet<&NodeId> = ids.iter().collect();
+        let mut nodes: Vec<NodeSpec> = self
+            .nodes
+            .iter()
+            .filter(|node| keep.contains(&node.id))
+            .cloned()
+            .collect();
+        let edges: Vec<EdgeSpec> = self
+            .edges
+            .iter()
+            .filter(|edge| keep.contains(&edge.from.node_id) && keep.contains(&edge.to.node_id))
+            .cloned()
+            .collect();
+
+        for edge in &self.edges {
+            if keep.contains(&edge.from.node_id) || !keep.contains(&edge.to.node_id) {
+                continue;
+            }
+            let constant = self.nodes.iter().find(|node| {
+                node.id == edge.from.node_id
+                    && node.kind == NodeType::Constant
+                    && edge.from.output == "out"
+                    && edge.selector.is_none()
+            });
+            let Some(value) = constant.and_then(|node| node.params.value.clone()) else {
+                continue;
+            };
+            if let Some(target) = nodes.iter_mut().find(|node| node.id == edge.to.node_id) {
+                target
+                    .input_defaults
+                    .insert(edge.to.input.clone(), InputDefault { value, shape: None });
+            }
+        }
+
+        GraphSpec {
+            nodes,
+            edges,
+            ..Default::default()
+        }
+    }
+
     /// Seed or bump the spec version and refresh the fingerprint for plan-cache reuse.
     ///
     /// - If `version` is zero, it is set to 1.
@@ -4,30 +4,122 @@
 //! metadata, and delta-friendly output snapshots to JavaScript consumers.
 
 use hashbrown::HashMap;
-use js_sys::{Float32Array, Uint32Array, JSON};
+use js_sys::{Float32Array, Uint32Array, Uint8Array, JSON};
+use serde::ser::{SerializeMap, Serializer};
+use serde::{Deserialize, Serialize};
 use serde_wasm_bindgen as swb;
+use vizij_api_core::panic::catch_panic;
 use vizij_api_core::shape::ShapeId;
 use vizij_api_core::value::{
     as_array, as_bool, as_float, as_quat, as_text, as_transform, as_vec2, as_vec3, as_vec4,
     as_vector, float, kind, vector, VizijKind,
 };
-use vizij_api_core::{coercion, json, Shape, TypedPath, Value};
-use vizij_graph_core::types::RoundMode;
+use vizij_api_core::{coercion, json, Shape, TypedPath, Value, WriteBatch, WriteOp};
+use vizij_graph_core::types::{InputDefault, NodeParams, NodeSpec, RoundMode, SubgraphSpec};
 use vizij_graph_core::{
-    evaluate_all, evaluate_all_cached, GraphRuntime, GraphSpec, NodeType, PortValue,
+    evaluate_all, evaluate_all_cached, infer_shape, GraphRuntime, GraphSpec, NodeType, PortValue,
 };
 use wasm_bindgen::prelude::*;
 
+#[cfg(test)]
+thread_local! {
+    /// Panic-injection hook: when set, the next evaluation panics inside the guarded region.
+    static INJECT_EVAL_PANIC: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Normalize and deserialize a graph spec, naming the offending node and field on failure.
+fn parse_graph_spec(json_str: &str) -> Result<GraphSpec, String> {
+    let normalized = json::normalize_graph_spec_json(json_str).map_err(|e| e.to_string())?;
+    GraphSpec::deserialize(&normalized).map_err(|err| describe_spec_error(&normalized, err))
+}
+
+/// Parse the JSON text of a [`SubgraphSpec`], normalizing its child graph like a top-level spec.
+fn parse_subgraph_spec(text: &str) -> Result<SubgraphSpec, String> {
+    let mut raw: serde_json::Value = serde_json::from_str(text).map_err(|e| e.to_string())?;
+    if let Some(graph) = raw.get_mut("graph") {
+        json::normalize_graph_spec_value(graph).map_err(|e| e.to_string())?;
+    }
+    SubgraphSpec::deserialize(&raw).map_err(|e| e.to_string())
+}
+
+/// Attribute a spec deserialization error to a node (and, where possible, a single param or
+/// field) by re-checking each node of the normalized JSON in isolation.
+///
+/// Falls back to the original serde message when no node is at fault (e.g. a malformed edge).
+fn describe_spec_error(spec: &serde_json::Value, err: serde_json::Error) -> String {
+    let nodes = spec.get("nodes").and_then(|n| n.as_array());
+    for (index, node) in nodes.into_iter().flatten().enumerate() {
+        let Err(node_err) = NodeSpec::deserialize(node) else {
+            continue;
+        };
+        let label = match node.get("id").and_then(|id| id.as_str()) {
+            Some(id) => format!("node '{id}'"),
+            None => format!("node #{index}"),
+        };
+        let Some(fields) = node.as_object() else {
+            return format!("{label}: {node_err}");
+        };
+        if let Some(params) = fields.get("params").and_then(|p| p.as_object()) {
+            for (key, value) in params {
+                let mut single = serde_json::Map::new();
+                single.insert(key.clone(), value.clone());
+                if let Err(e) = NodeParams::deserialize(&serde_json::Value::Object(single)) {
+                    return format!("{label} param '{key}': {e}");
+                }
+            }
+        }
+        for (key, value) in fields {
+            let field_err = match key.as_str() {
+                "id" => String::deserialize(value).err(),
+                "type" => NodeType::deserialize(value).err(),
+                "output_shapes" => HashMap::<String, Shape>::deserialize(value).err(),
+                "input_defaults" => HashMap::<String, InputDefault>::deserialize(value).err(),
+                _ => None,
+            };
+            if let Some(e) = field_err {
+                return format!("{label} field '{key}': {e}");
+            }
+        }
+        return format!("{label}: {node_err}");
+    }
+    err.to_string()
+}
+
 /// Normalize a graph-spec JSON string into the canonical serde shape expected by the runtime.
 #[wasm_bindgen]
 pub fn normalize_graph_spec_json(json: &str) -> Result<String, JsValue> {
     json::normalize_graph_spec_json_string(json).map_err(|e| JsValue::from_str(&e.to_string()))
 }
 
+/// Extract the nodes named by `ids` (a JS string array), plus the edges between them, from a
+/// graph-spec JSON string into a standalone spec JSON string. See [`GraphSpec::extract`].
+#[wasm_bindgen]
+pub fn extract_subgraph(json: &str, ids: JsValue) -> Result<String, JsValue> {
+    let ids: Vec<String> = swb::from_value(ids)
+        .map_err(|e| JsValue::from_str(&format!("extract_subgraph ids: {}", e)))?;
+    extract_subgraph_json(json, &ids).map_err(|e| JsValue::from_str(&e))
+}
+
+fn extract_subgraph_json(json: &str, ids: &[String]) -> Result<String, String> {
+    let spec = parse_graph_spec(json)?;
+    serde_json::to_string(&spec.extract(ids)).map_err(|e| e.to_string())
+}
+
 /// ABI version for compatibility checks with npm wrappers.
 #[wasm_bindgen]
 pub fn abi_version() -> u32 {
-    2
+    vizij_api_core::abi::GRAPH_WASM
+}
+
+/// Fail with a descriptive error unless this module's ABI equals `expected`.
+#[wasm_bindgen]
+pub fn check_abi(expected: u32) -> Result<(), JsValue> {
+    vizij_api_core::abi::check(
+        "vizij-graph-wasm",
+        vizij_api_core::abi::GRAPH_WASM,
+        expected,
+    )
+    .map_err(|e| JsValue::from_str(&e))
 }
 
 #[cfg(test)]
@@ -110,6 +202,96 @@ mod tests {
         }
     }
 
+    #[test]
+    fn load_errors_name_the_offending_node_and_param() {
+        let spec = r#"{
+            "nodes": [
+                { "id": "target", "type": "constant", "params": { "value": 1.0 } },
+                { "id": "spring", "type": "spring", "params": { "stiffness": "stiff" } }
+            ],
+            "edges": []
+        }"#;
+        let err = parse_graph_spec(spec).expect_err("string stiffness must be rejected");
+        assert!(
+            err.starts_with("node 'spring' param 'stiffness':"),
+            "unexpected error: {err}"
+        );
+        assert!(err.contains("expected f32"), "unexpected error: {err}");
+
+        let bad_type = r#"{ "nodes": [ { "id": "n", "type": "nosuchnode" } ], "edges": [] }"#;
+        let err = parse_graph_spec(bad_type).expect_err("unknown node type must be rejected");
+        assert!(
+            err.starts_with("node 'n' field 'type':"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn extract_subgraph_keeps_selected_nodes_and_internal_edges() {
+        let spec = r#"{
+            "nodes": [
+                { "id": "a", "type": "constant", "params": { "value": 1.0 } },
+                { "id": "b", "type": "constant", "params": { "value": 2.0 } },
+                { "id": "sum", "type": "add", "inputs": { "lhs": "a", "rhs": "b" } }
+            ],
+            "edges": []
+        }"#;
+        let json = extract_subgraph_json(spec, &["b".to_string(), "sum".to_string()])
+            .expect("extraction succeeds");
+        let mut graph = WasmGraph::new();
+        graph.load_graph(&json).expect("extracted spec loads");
+        let ids: Vec<&str> = graph.spec.nodes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, ["b", "sum"]);
+        assert_eq!(graph.spec.edges.len(), 1);
+        assert_eq!(graph.spec.nodes[1].input_defaults.len(), 1, "a is inlined");
+    }
+
+    #[test]
+    fn used_node_types_reports_urdf_ik_nodes() {
+        let mut graph = WasmGraph::new();
+        let spec = r#"{
+            "nodes": [
+                { "id": "target", "type": "constant", "params": { "value": 1.0 }, "inputs": {}, "output_shapes": {} },
+                { "id": "ik", "type": "urdfikposition", "params": {}, "inputs": {}, "output_shapes": {} },
+                { "id": "ik2", "type": "urdfikposition", "params": {}, "inputs": {}, "output_shapes": {} }
+            ],
+            "edges": []
+        }"#;
+        graph
+            .load_graph(spec)
+            .expect("graph loads without evaluating");
+
+        let ids = graph.used_node_type_ids();
+        assert!(ids.contains(&"urdfikposition".to_string()), "{ids:?}");
+        assert_eq!(
+            ids.iter().filter(|id| *id == "urdfikposition").count(),
+            1,
+            "ids are distinct"
+        );
+        assert_eq!(ids.len(), 2);
+    }
+
+    #[test]
+    fn used_node_types_reports_nodes_inside_subgraphs() {
+        let mut graph = WasmGraph::new();
+        let spec = r#"{
+            "nodes": [{
+                "id": "outer", "type": "subgraph",
+                "params": { "subgraph": { "graph": { "nodes": [{
+                    "id": "inner", "type": "subgraph",
+                    "params": { "subgraph": { "graph": { "nodes": [
+                        { "id": "fk", "type": "urdffk", "params": {} }
+                    ] } } }
+                }] } } }
+            }]
+        }"#;
+        graph
+            .load_graph(spec)
+            .expect("graph loads without evaluating");
+
+        assert_eq!(graph.used_node_type_ids(), ["subgraph", "urdffk"]);
+    }
+
     #[test]
     fn abi_version_matches_expected() {
         assert_eq!(super::abi_version(), 2);
@@ -218,6 +400,44 @@ mod tests {
         assert!(graph.plan_ready);
     }
 
+    /// A child graph in legacy shorthand: bare values, `kind`, and `inputs` instead of edges.
+    const SHORTHAND_SUBGRAPH: &str = r#"{
+        "graph": {
+            "nodes": [
+                { "id": "k", "kind": "Constant", "params": { "value": 4 } },
+                { "id": "h", "kind": "Constant", "params": { "value": 0.5 } },
+                { "id": "half", "type": "Multiply", "inputs": { "a": "k", "b": "h" } }
+            ]
+        },
+        "outputs": [{ "port": "out", "node_id": "half" }]
+    }"#;
+
+    fn subgraph_out(graph: &mut WasmGraph) -> Option<f32> {
+        graph.eval_all().expect("eval_all");
+        as_float(&graph.runtime.outputs["sub"]["out"].value)
+    }
+
+    #[test]
+    fn shorthand_subgraph_specs_are_normalized() {
+        let spec = format!(
+            r#"{{ "nodes": [{{ "id": "sub", "type": "subgraph", "params": {{ "subgraph": {SHORTHAND_SUBGRAPH} }} }}] }}"#
+        );
+        parse_graph_spec(&spec).expect("shorthand child parses");
+        let mut graph = WasmGraph::new();
+        graph.load_graph(&spec).expect("graph loads");
+        assert_eq!(subgraph_out(&mut graph), Some(2.0));
+
+        let mut graph = WasmGraph::new();
+        graph
+            .load_graph(r#"{ "nodes": [{ "id": "sub", "type": "subgraph", "params": {} }] }"#)
+            .expect("graph loads");
+        let text = serde_json::to_string(SHORTHAND_SUBGRAPH).unwrap();
+        graph
+            .set_param("sub", "subgraph", &text)
+            .expect("shorthand subgraph set_param succeeds");
+        assert_eq!(subgraph_out(&mut graph), Some(2.0));
+    }
+
     #[test]
     fn set_param_supports_noise_params() {
         let mut graph = WasmGraph::new();
@@ -315,6 +535,164 @@ mod tests {
             "full snapshot should include nodes map"
         );
     }
+
+    #[test]
+    fn eval_nodes_writes_uses_canonical_writebatch_form() {
+        let mut graph = WasmGraph::new();
+        let spec = r#"{
+            "nodes": [
+                { "id": "c", "type": "constant", "params": { "value": 1.5 }, "inputs": {}, "output_shapes": {} },
+                { "id": "out", "type": "output", "params": { "path": "demo/out" }, "inputs": {}, "output_shapes": {} }
+            ],
+            "edges": [
+                { "from": { "node_id": "c", "output": "out" }, "to": { "node_id": "out", "input": "in" } }
+            ]
+        }"#;
+
+        graph.load_graph(spec).expect("graph loads");
+        let out = graph.eval_nodes_writes_json().expect("eval");
+        assert!(out["nodes"]
+            .as_object()
+            .is_some_and(|n| n.contains_key("c")));
+
+        // The same parser `vizij-api-wasm::validate_writebatch_json` runs.
+        let writes = json::writebatch_from_json(out["writes"].clone()).expect("valid batch");
+        let batch: vizij_api_core::WriteBatch =
+            serde_json::from_value(out["writes"].clone()).expect("canonical WriteBatch serde");
+        assert_eq!(writes.len(), 1);
+        assert_eq!(batch.len(), 1);
+        let op = batch.iter().next().unwrap();
+        assert_eq!(op.path.to_string(), "demo/out");
+        assert_eq!(as_float(&op.value), Some(1.5));
+    }
+
+    #[test]
+    fn panicking_evaluation_returns_error_and_recovers() {
+        let mut graph = WasmGraph::new();
+        let spec = r#"{
+            "nodes": [
+                { "id": "c", "type": "constant", "params": { "value": 2.0 }, "inputs": {}, "output_shapes": {} }
+            ],
+            "edges": []
+        }"#;
+        graph.load_graph(spec).expect("graph loads");
+        graph.eval_all().expect("initial eval");
+        let version = graph.output_version;
+
+        INJECT_EVAL_PANIC.with(|flag| flag.set(true));
+        let err = graph
+            .eval_guarded()
+            .expect_err("panic surfaces as an error");
+        assert!(err.contains("injected node panic"), "{err}");
+        assert_eq!(graph.output_version, version, "failed eval keeps version");
+        assert!(!graph.plan_ready);
+
+        graph
+            .eval_all()
+            .expect("graph evaluates again after a panic");
+        assert_eq!(graph.output_version, version + 1);
+    }
+
+    #[test]
+    fn eval_all_into_reuses_output_buffer() {
+        let mut graph = WasmGraph::new();
+        let spec = r#"{
+            "nodes": [
+                { "id": "c", "type": "constant", "params": { "value": 3.0 }, "inputs": {}, "output_shapes": {} },
+                { "id": "out", "type": "output", "params": { "path": "demo/out" }, "inputs": {}, "output_shapes": {} }
+            ],
+            "edges": [
+                { "from": { "node_id": "c", "output": "out" }, "to": { "node_id": "out", "input": "in" } }
+            ]
+        }"#;
+        graph.load_graph(spec).expect("graph loads");
+
+        // Frames differ only in `version`; warm up to two digits so every frame below has the
+        // same length.
+        while graph.output_version < 10 {
+            graph.eval_all().expect("eval_all");
+        }
+        let content = |bytes: &[u8]| {
+            let mut v: serde_json::Value = serde_json::from_slice(bytes).expect("valid json");
+            v.as_object_mut().unwrap().remove("version");
+            v
+        };
+        let expected = content(graph.eval_all().expect("eval_all").as_bytes());
+
+        let len = graph.eval_all_to_buffer().expect("first buffered eval");
+        assert_eq!(content(&graph.json_buf[..len]), expected);
+        let (ptr, capacity) = (graph.json_buf.as_ptr(), graph.json_buf.capacity());
+
+        for _ in 0..50 {
+            let len = graph.eval_all_to_buffer().expect("buffered eval");
+            assert_eq!(content(&graph.json_buf[..len]), expected);
+        }
+        assert_eq!(graph.json_buf.as_ptr(), ptr, "buffer was reallocated");
+        assert_eq!(graph.json_buf.capacity(), capacity);
+    }
+
+    /// Counts allocations made by the current thread, so parallel tests don't skew a measurement.
+    struct CountingAlloc;
+
+    thread_local! {
+        static ALLOCATIONS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+
+    unsafe impl std::alloc::GlobalAlloc for CountingAlloc {
+        unsafe fn alloc(&self, layout: std::alloc::Layout) -> *mut u8 {
+            let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+            std::alloc::System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: std::alloc::Layout) {
+            std::alloc::System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAlloc = CountingAlloc;
+
+    fn allocations_during(f: impl FnOnce()) -> usize {
+        let before = ALLOCATIONS.with(|n| n.get());
+        f();
+        ALLOCATIONS.with(|n| n.get()) - before
+    }
+
+    #[test]
+    fn eval_all_into_does_not_clone_outputs() {
+        const NODES: usize = 64;
+        let nodes: Vec<String> = (0..NODES)
+            .map(|i| {
+                format!(
+                    r#"{{ "id": "c{i}", "type": "constant", "params": {{ "value": {i}.0 }}, "inputs": {{}}, "output_shapes": {{}} }}"#
+                )
+            })
+            .collect();
+        let spec = format!(r#"{{ "nodes": [{}], "edges": [] }}"#, nodes.join(","));
+        let mut graph = WasmGraph::new();
+        graph.load_graph(&spec).expect("graph loads");
+        for _ in 0..4 {
+            graph.eval_all_to_buffer().expect("warm-up eval");
+        }
+
+        let eval_only = allocations_during(|| graph.advance_and_eval().expect("eval"));
+        let buffered = allocations_during(|| {
+            graph.eval_all_to_buffer().expect("buffered eval");
+        });
+        // Cloning the outputs would cost at least one allocation per node id.
+        assert!(
+            buffered < eval_only + NODES,
+            "eval_all_into allocated {buffered} times against {eval_only} for evaluation alone"
+        );
+
+        let snapshot = graph.snapshot_outputs();
+        assert_eq!(snapshot.len(), NODES);
+        assert_eq!(
+            as_float(&snapshot["c7"]["out"].value),
+            Some(7.0),
+            "parked outputs stay readable"
+        );
+    }
 }
 
 /// WASM-facing graph runtime with staging and output delta caches.
@@ -333,9 +711,78 @@ pub struct WasmGraph {
     output_version: u64,
     last_outputs: HashMap<String, HashMap<String, PortValue>>,
     last_outputs_version: u64,
+    /// Set when `eval_all_into` swapped the current outputs into `last_outputs`; the runtime's
+    /// table then holds the previous frame until the next evaluation clears and refills it.
+    outputs_parked: bool,
     input_last_values: HashMap<usize, Value>,
     input_last_shapes: HashMap<usize, Option<Shape>>,
     input_touched: HashMap<usize, u64>,
+    /// UTF-8 JSON of the last `eval_all_into` frame; cleared but never shrunk between calls.
+    json_buf: Vec<u8>,
+}
+
+/// Borrowed [`WasmGraph::eval_all`] frame (`{ version, nodes, writes }`) that serializes straight
+/// from the output snapshot and write batch, without an intermediate `serde_json::Value` tree.
+struct FrameView<'a> {
+    version: u64,
+    nodes: &'a HashMap<String, HashMap<String, PortValue>>,
+    writes: &'a WriteBatch,
+}
+
+struct PortView<'a>(&'a PortValue);
+
+struct WriteView<'a>(&'a WriteOp);
+
+impl Serialize for FrameView<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        struct Nodes<'a>(&'a HashMap<String, HashMap<String, PortValue>>);
+        struct Ports<'a>(&'a HashMap<String, PortValue>);
+        struct Writes<'a>(&'a WriteBatch);
+        impl Serialize for Nodes<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_map(self.0.iter().map(|(id, ports)| (id, Ports(ports))))
+            }
+        }
+        impl Serialize for Ports<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_map(self.0.iter().map(|(key, port)| (key, PortView(port))))
+            }
+        }
+        impl Serialize for Writes<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_seq(self.0.iter().map(WriteView))
+            }
+        }
+
+        let mut map = serializer.serialize_map(Some(3))?;
+        map.serialize_entry("version", &self.version)?;
+        map.serialize_entry("nodes", &Nodes(self.nodes))?;
+        map.serialize_entry("writes", &Writes(self.writes))?;
+        map.end()
+    }
+}
+
+impl Serialize for PortView<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("value", &self.0.value)?;
+        map.serialize_entry("shape", &self.0.shape)?;
+        map.end()
+    }
+}
+
+impl Serialize for WriteView<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let op = self.0;
+        let mut map = serializer.serialize_map(Some(3))?;
+        map.serialize_entry("path", &op.path.to_string())?;
+        map.serialize_entry("value", &op.value)?;
+        match &op.shape {
+            Some(shape) => map.serialize_entry("shape", shape)?,
+            None => map.serialize_entry("shape", &infer_shape(&op.value))?,
+        }
+        map.end()
+    }
 }
 
 #[derive(Clone)]
@@ -407,20 +854,19 @@ impl WasmGraph {
             output_version: 0,
             last_outputs: HashMap::new(),
             last_outputs_version: 0,
+            outputs_parked: false,
             input_last_values: HashMap::new(),
             input_last_shapes: HashMap::new(),
             input_touched: HashMap::new(),
+            json_buf: Vec::new(),
         }
     }
 
     /// Load a new graph spec from JSON and reset all staged inputs, output snapshots, and caches.
     #[wasm_bindgen]
     pub fn load_graph(&mut self, json_str: &str) -> Result<(), JsValue> {
-        let normalized = json::normalize_graph_spec_json(json_str)
-            .map_err(|e| JsValue::from_str(&e.to_string()))?;
-        // Now deserialize into the typed GraphSpec
-        self.spec = serde_json::from_value::<GraphSpec>(normalized)
-            .map_err(|e| JsValue::from_str(&e.to_string()))?
+        self.spec = parse_graph_spec(json_str)
+            .map_err(|e| JsValue::from_str(&e))?
             .with_cache();
 
         self.runtime = GraphRuntime::default();
@@ -432,12 +878,38 @@ impl WasmGraph {
         self.output_version = 0;
         self.last_outputs.clear();
         self.last_outputs_version = 0;
+        self.outputs_parked = false;
         self.input_last_values.clear();
         self.input_last_shapes.clear();
         self.input_touched.clear();
         Ok(())
     }
 
+    /// Sorted serde type ids (e.g. `"urdfikpose"`) of the node types in the loaded spec.
+    fn used_node_type_ids(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self
+            .spec
+            .node_type_set()
+            .iter()
+            .filter_map(|ty| {
+                serde_json::to_value(ty)
+                    .ok()
+                    .and_then(|v| v.as_str().map(str::to_string))
+            })
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    /// Return the distinct node type ids used by the loaded graph as a sorted string array.
+    ///
+    /// Ids match `type_id` in [`get_node_schemas_json`], so hosts can warn about feature-gated
+    /// nodes (e.g. the `urdf_ik` family) before evaluating.
+    #[wasm_bindgen(js_name = "used_node_types")]
+    pub fn used_node_types(&self) -> Result<JsValue, JsValue> {
+        swb::to_value(&self.used_node_type_ids()).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
     /// Stage one input by typed path using a JSON string payload.
     ///
     /// This path-based staging route bypasses the slot/index cache helpers.
@@ -504,6 +976,9 @@ impl WasmGraph {
     fn invalidate_plan_cache(&mut self) {
         self.plan_ready = false;
         self.runtime.plan = Default::default();
+        if std::mem::take(&mut self.outputs_parked) {
+            std::mem::swap(&mut self.last_outputs, &mut self.runtime.outputs);
+        }
         self.last_outputs.clear();
         self.last_outputs_version = 0;
         // Bump the plan-validity key (structural generation) and refresh the fingerprint.
@@ -513,20 +988,41 @@ impl WasmGraph {
     }
 
     fn eval_internal(&mut self) -> Result<(), JsValue> {
-        let res = if self.plan_ready {
-            evaluate_all_cached(&mut self.runtime, &self.spec)
-        } else {
-            evaluate_all(&mut self.runtime, &self.spec)
-        };
+        self.eval_guarded().map_err(|e| JsValue::from_str(&e))
+    }
+
+    /// Evaluate once, turning both evaluation errors and node panics into `Err`.
+    ///
+    /// A panic leaves the runtime mid-frame, so the cached plan is dropped and rebuilt on the
+    /// next evaluation.
+    fn eval_guarded(&mut self) -> Result<(), String> {
+        let plan_ready = self.plan_ready;
+        let (runtime, spec) = (&mut self.runtime, &self.spec);
+        let res = catch_panic("graph evaluation", || {
+            #[cfg(test)]
+            if INJECT_EVAL_PANIC.with(|flag| flag.replace(false)) {
+                panic!("injected node panic");
+            }
+            if plan_ready {
+                evaluate_all_cached(runtime, spec)
+            } else {
+                evaluate_all(runtime, spec)
+            }
+        });
         match res {
-            Ok(_) => {
+            Ok(Ok(_)) => {
                 self.plan_ready = true;
+                self.outputs_parked = false;
                 self.output_version = self.output_version.saturating_add(1);
                 Ok(())
             }
-            Err(e) => {
+            Ok(Err(e)) => {
                 self.plan_ready = false;
-                Err(JsValue::from_str(&e))
+                Err(e)
+            }
+            Err(panic) => {
+                self.invalidate_plan_cache();
+                Err(panic)
             }
         }
     }
@@ -580,9 +1076,17 @@ impl WasmGraph {
         Ok(())
     }
 
+    /// Outputs of the latest evaluation, wherever `eval_all_into` left them.
+    fn current_outputs(&self) -> &HashMap<String, HashMap<String, PortValue>> {
+        if self.outputs_parked {
+            &self.last_outputs
+        } else {
+            &self.runtime.outputs
+        }
+    }
+
     fn snapshot_outputs(&self) -> HashMap<String, HashMap<String, PortValue>> {
-        self.runtime
-            .outputs
+        self.current_outputs()
             .iter()
             .map(|(id, ports)| {
                 let cloned = ports
@@ -646,8 +1150,7 @@ impl WasmGraph {
             let shape_json = if let Some(shape) = &op.shape {
                 serde_json::to_value(shape).unwrap()
             } else {
-                let inferred_shape = PortValue::new(op.value.clone()).shape;
-                serde_json::to_value(&inferred_shape).unwrap()
+                serde_json::to_value(infer_shape(&op.value)).unwrap()
             };
             writes.push(serde_json::json!({
                 "path": op.path.to_string(),
@@ -695,7 +1198,7 @@ impl WasmGraph {
 
         let mut delta_nodes: HashMap<String, serde_json::Value> = HashMap::new();
 
-        for (node_id, outputs) in self.runtime.outputs.iter() {
+        for (node_id, outputs) in self.current_outputs().iter() {
             let mut changed_ports: HashMap<String, serde_json::Value> = HashMap::new();
             let prev_node = self.last_outputs.get(node_id);
             for (key, port) in outputs {
@@ -738,8 +1241,7 @@ impl WasmGraph {
             let shape_json = if let Some(shape) = &op.shape {
                 serde_json::to_value(shape).unwrap()
             } else {
-                let inferred_shape = PortValue::new(op.value.clone()).shape;
-                serde_json::to_value(&inferred_shape).unwrap()
+                serde_json::to_value(infer_shape(&op.value)).unwrap()
             };
             writes.push(serde_json::json!({
                 "path": op.path.to_string(),
@@ -958,7 +1460,7 @@ impl WasmGraph {
     /// Fetch a float/vec/array output directly as Float32Array (if numeric).
     #[wasm_bindgen(js_name = "get_output_f32")]
     pub fn get_output_f32(&self, node_id: &str, output_key: &str) -> Option<Float32Array> {
-        let port = self.runtime.outputs.get(node_id)?.get(output_key)?;
+        let port = self.current_outputs().get(node_id)?.get(output_key)?;
         let value = &port.value;
         match kind(value) {
             VizijKind::Float => as_float(value).map(|f| Float32Array::from(&[f][..])),
@@ -1024,7 +1526,8 @@ impl WasmGraph {
         last.ok_or_else(|| JsValue::from_str("eval_steps: no steps executed"))
     }
 
-    fn eval_all_json(&mut self) -> Result<serde_json::Value, JsValue> {
+    /// Advance runtime time to `t`, restage cached inputs, and evaluate once.
+    fn advance_and_eval(&mut self) -> Result<(), JsValue> {
         let new_time = self.t as f32;
         let mut dt = new_time - self.runtime.t;
         if !dt.is_finite() || dt < 0.0 {
@@ -1033,7 +1536,11 @@ impl WasmGraph {
         self.runtime.dt = dt;
         self.runtime.t = new_time;
         self.restage_cached_inputs()?;
-        self.eval_internal()?;
+        self.eval_internal()
+    }
+
+    fn eval_all_json(&mut self) -> Result<serde_json::Value, JsValue> {
+        self.advance_and_eval()?;
         Ok(self.serialize_full())
     }
 
@@ -1062,6 +1569,76 @@ impl WasmGraph {
         serde_json::to_string(&out_obj).map_err(|e| JsValue::from_str(&e.to_string()))
     }
 
+    /// Evaluate and serialize the `eval_all` JSON into the reused `json_buf`, returning its length.
+    ///
+    /// The frame is written straight from the runtime outputs rather than through a
+    /// `serde_json::Value` tree. Those outputs then become the delta snapshot by swapping tables
+    /// with `last_outputs`, so no output is cloned.
+    fn eval_all_to_buffer(&mut self) -> Result<usize, JsValue> {
+        self.advance_and_eval()?;
+        let frame = FrameView {
+            version: self.output_version,
+            nodes: &self.runtime.outputs,
+            writes: &self.runtime.writes,
+        };
+        self.json_buf.clear();
+        serde_json::to_writer(&mut self.json_buf, &frame)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        std::mem::swap(&mut self.last_outputs, &mut self.runtime.outputs);
+        self.outputs_parked = true;
+        self.last_outputs_version = self.output_version;
+        Ok(self.json_buf.len())
+    }
+
+    /// Evaluate the graph and write the [`Self::eval_all`] JSON as UTF-8 into `buffer`.
+    ///
+    /// Serialization reuses a byte buffer owned by the graph, and the caller keeps one
+    /// `Uint8Array` across frames, so steady-state calls allocate no output string on either
+    /// side. Decode with `new TextDecoder().decode(buffer.subarray(0, len))`.
+    ///
+    /// Returns the byte length. When it exceeds `buffer.length` nothing is written; grow the
+    /// buffer and call [`Self::read_last_eval_into`] to fetch the frame without re-evaluating.
+    #[wasm_bindgen(js_name = "eval_all_into")]
+    pub fn eval_all_into(&mut self, buffer: &Uint8Array) -> Result<u32, JsValue> {
+        self.eval_all_to_buffer()?;
+        Ok(self.read_last_eval_into(buffer))
+    }
+
+    /// Copy the JSON from the last [`Self::eval_all_into`] call into `buffer`.
+    ///
+    /// Returns the byte length; nothing is written when `buffer` is too small.
+    #[wasm_bindgen(js_name = "read_last_eval_into")]
+    pub fn read_last_eval_into(&self, buffer: &Uint8Array) -> u32 {
+        let len = self.json_buf.len() as u32;
+        if len <= buffer.length() {
+            buffer.subarray(0, len).copy_from(&self.json_buf);
+        }
+        len
+    }
+
+    fn eval_nodes_writes_json(&mut self) -> Result<serde_json::Value, JsValue> {
+        let mut full = self.eval_all_json()?;
+        let writes = serde_json::to_value(&self.runtime.writes)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(serde_json::json!({
+            "nodes": full["nodes"].take(),
+            "writes": writes,
+        }))
+    }
+
+    /// Evaluate the graph and return `{ nodes, writes }` matching the animation wrapper's
+    /// `update_nodes_writes` contract.
+    ///
+    /// `nodes` is the same per-node map as [`Self::eval_all`]; `writes` is the canonical
+    /// `WriteBatch` serde form (`[{ path, value, shape? }]`, shape only when declared), so it
+    /// round-trips into Rust hosts that deserialize `WriteBatch` directly.
+    #[wasm_bindgen(js_name = "eval_nodes_writes")]
+    pub fn eval_nodes_writes(&mut self) -> Result<JsValue, JsValue> {
+        let out_obj = self.eval_nodes_writes_json()?;
+        let s = serde_json::to_string(&out_obj).map_err(|e| JsValue::from_str(&e.to_string()))?;
+        JSON::parse(&s)
+    }
+
     /// Evaluate without serializing to JSON and return the current output version token.
     #[wasm_bindgen(js_name = "eval_all_slots")]
     pub fn eval_all_slots(&mut self) -> Result<u64, JsValue> {
@@ -1190,11 +1767,19 @@ impl WasmGraph {
                 )))
             }
         }
-        fn parse_pairs(node_id: &str, key: &str, v: &Value) -> Result<Vec<(String, f32)>, JsValue> {
+        /// Parse `[[first, float], ...]`, reading each pair's first element with `first`
+        /// (`first_kind` names it in errors).
+        fn parse_pairs<T>(
+            node_id: &str,
+            key: &str,
+            v: &Value,
+            first_kind: &str,
+            first: impl Fn(&Value) -> Option<T>,
+        ) -> Result<Vec<(T, f32)>, JsValue> {
             let items = as_array(v).ok_or_else(|| {
                 JsValue::from_str(&format!(
-                    "set_param: node '{}' key '{}' expects a sequence of [text, float] pairs",
-                    node_id, key
+                    "set_param: node '{}' key '{}' expects a sequence of [{}, float] pairs",
+                    node_id, key, first_kind
                 ))
             })?;
             let mut out = Vec::with_capacity(items.len());
@@ -1203,14 +1788,14 @@ impl WasmGraph {
                     .filter(|elems| elems.len() >= 2)
                     .ok_or_else(|| {
                         JsValue::from_str(&format!(
-                        "set_param: node '{}' key '{}' expects a sequence of [text, float] pairs",
-                        node_id, key
-                    ))
+                            "set_param: node '{}' key '{}' expects a sequence of [{}, float] pairs",
+                            node_id, key, first_kind
+                        ))
                     })?;
-                let name = as_text(&elems[0]).ok_or_else(|| {
+                let name = first(&elems[0]).ok_or_else(|| {
                     JsValue::from_str(&format!(
-                        "set_param: node '{}' key '{}' pair[0] expects text",
-                        node_id, key
+                        "set_param: node '{}' key '{}' pair[0] expects {}",
+                        node_id, key, first_kind
                     ))
                 })?;
                 let val = as_float(&elems[1]).ok_or_else(|| {
@@ -1219,7 +1804,7 @@ impl WasmGraph {
                         node_id, key
                     ))
                 })?;
-                out.push((name.to_string(), val));
+                out.push((name, val));
             }
             Ok(out)
         }
@@ -1264,10 +1849,15 @@ impl WasmGraph {
             // Structural params are those that can change the cached plan's port layouts or
             // bindings. Known structural mutations via `set_param`:
             // - `Split.sizes`: changes the number of variadic outputs and slot indices.
+            // - `Split.output_names`: renames the part output slots.
             // - `ReadRecord.record_keys`: changes the number of variadic outputs and slot indices.
+            // - `Subgraph.subgraph`: output mappings name the node's output slots.
             let structural_change = matches!(
                 (&node.kind, key),
-                (NodeType::Split, "sizes") | (NodeType::ReadRecord, "record_keys")
+                (NodeType::Split, "sizes")
+                    | (NodeType::Split, "output_names")
+                    | (NodeType::ReadRecord, "record_keys")
+                    | (NodeType::Subgraph, "subgraph")
             );
 
             match key {
@@ -1289,6 +1879,7 @@ impl WasmGraph {
                 "out_min" => node.params.out_min = Some(expect_float(node_id, key, &val)?),
                 "out_max" => node.params.out_max = Some(expect_float(node_id, key, &val)?),
                 "clamp" => node.params.clamp = Some(expect_bool(node_id, key, &val)?),
+                "relative" => node.params.relative = Some(expect_bool(node_id, key, &val)?),
                 "x" => node.params.x = Some(expect_float(node_id, key, &val)?),
                 "y" => node.params.y = Some(expect_float(node_id, key, &val)?),
                 "z" => node.params.z = Some(expect_float(node_id, key, &val)?),
@@ -1301,6 +1892,14 @@ impl WasmGraph {
                 "mass" => node.params.mass = Some(expect_float(node_id, key, &val)?),
                 "half_life" => node.params.half_life = Some(expect_float(node_id, key, &val)?),
                 "max_rate" => node.params.max_rate = Some(expect_float(node_id, key, &val)?),
+                "alpha" => node.params.alpha = Some(expect_float(node_id, key, &val)?),
+                "frames" => node.params.frames = Some(parse_u32(node_id, key, &val)?),
+                "window" => node.params.window = Some(parse_u32(node_id, key, &val)?),
+                "precision" => node.params.precision = Some(parse_u32(node_id, key, &val)?),
+                "default" => node.params.default = Some(val),
+                "step" => node.params.step = Some(expect_float(node_id, key, &val)?),
+                "offset" => node.params.offset = Some(expect_float(node_id, key, &val)?),
+                "epsilon" => node.params.epsilon = Some(expect_float(node_id, key, &val)?),
                 "round_mode" => {
                     node.params.round_mode = Some(parse_round_mode(node_id, key, &val)?);
                 }
@@ -1309,6 +1908,9 @@ impl WasmGraph {
                 "sizes" => {
                     node.params.sizes = Some(coercion::to_vector(&val));
                 }
+                "output_names" => {
+                    node.params.output_names = Some(parse_string_list(node_id, key, &val)?);
+                }
 
                 // Paths
                 "path" => {
@@ -1348,8 +1950,18 @@ impl WasmGraph {
                 "tol_rot" => {
                     node.params.tol_rot = Some(expect_float(node_id, key, &val)?);
                 }
+                "solver" => {
+                    node.params.solver = Some(expect_text(node_id, key, &val)?.to_string());
+                }
+                "fail_soft" => node.params.fail_soft = Some(expect_bool(node_id, key, &val)?),
                 "joint_defaults" => {
-                    node.params.joint_defaults = Some(parse_pairs(node_id, key, &val)?);
+                    node.params.joint_defaults =
+                        Some(parse_pairs(node_id, key, &val, "text", |v| {
+                            as_text(v).map(str::to_string)
+                        })?);
+                }
+                "points" => {
+                    node.params.points = Some(parse_pairs(node_id, key, &val, "float", as_float)?);
                 }
                 "case_labels" => {
                     node.params.case_labels = Some(parse_string_list(node_id, key, &val)?);
@@ -1368,6 +1980,24 @@ impl WasmGraph {
                         }
                     };
                 }
+                "accumulate" => {
+                    node.params.accumulate = Some(expect_text(node_id, key, &val)?.to_string());
+                }
+                "source" => {
+                    node.params.source = Some(expect_text(node_id, key, &val)?.to_string());
+                }
+
+                // Nested graphs (JSON text of a SubgraphSpec)
+                "subgraph" => {
+                    let text = expect_text(node_id, key, &val)?;
+                    let sub = parse_subgraph_spec(text).map_err(|e| {
+                        JsValue::from_str(&format!(
+                            "set_param: node '{}' key 'subgraph' is not a valid subgraph: {}",
+                            node_id, e
+                        ))
+                    })?;
+                    node.params.subgraph = Some(Box::new(sub));
+                }
 
                 _ => {
                     return Err(JsValue::from_str(&format!(
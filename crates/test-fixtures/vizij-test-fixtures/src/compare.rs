@@ -0,0 +1,271 @@
+//! Tolerance-aware comparison of fixture expectations against produced values.
+//!
+//! Fixture `expect` blocks map a path (or node id and port) to a value in any JSON form accepted
+//! by [`vizij_api_core::json::parse_value`]. These helpers parse each expectation, compare it
+//! against the produced [`Value`] with a float tolerance, and report every mismatch at once.
+
+use std::collections::HashMap;
+
+use serde_json::Value as JsonValue;
+use vizij_api_core::coercion::to_vector;
+use vizij_api_core::value::{as_array, as_record, as_text, as_transform};
+use vizij_api_core::{json, TypedPath, Value, VizijKind, WriteBatch};
+
+/// Compare an `expect` object (`{ "path": value, ... }`) against the writes in `actual`.
+///
+/// Only the expected paths are checked; extra writes are ignored. When a path is written more
+/// than once the last write wins. On mismatch the error lists one line per failing path.
+pub fn assert_outputs_match(
+    expected: &JsonValue,
+    actual: &WriteBatch,
+    eps: f32,
+) -> Result<(), String> {
+    let expected = expected
+        .as_object()
+        .ok_or_else(|| "expected outputs must be a JSON object keyed by path".to_string())?;
+    let produced: HashMap<String, &Value> = actual
+        .iter()
+        .map(|op| (op.path.to_string(), &op.value))
+        .collect();
+
+    let mut diffs = Vec::new();
+    for (key, expected_json) in expected {
+        let path = match TypedPath::parse(key) {
+            Ok(tp) => tp.to_string(),
+            Err(err) => {
+                diffs.push(format!("{key}: invalid expected path ({err})"));
+                continue;
+            }
+        };
+        match produced.get(&path) {
+            Some(value) => check(key, expected_json, value, eps, &mut diffs),
+            None => diffs.push(format!("{key}: missing from actual outputs")),
+        }
+    }
+    finish(diffs)
+}
+
+/// Compare a `{ "node_id": { "port": value, ... }, ... }` expectation against graph outputs.
+///
+/// `actual` is the runtime's node → port map as-is (`GraphRuntime::outputs`); ports only need to
+/// expose their [`Value`] through `AsRef`. Only the expected node ports are checked.
+pub fn assert_graph_outputs_match<P: AsRef<Value>>(
+    expected: &JsonValue,
+    actual: &hashbrown::HashMap<String, hashbrown::HashMap<String, P>>,
+    eps: f32,
+) -> Result<(), String> {
+    let expected = expected.as_object().ok_or_else(|| {
+        "expected graph outputs must be a JSON object keyed by node id".to_string()
+    })?;
+
+    let mut diffs = Vec::new();
+    for (node_id, ports) in expected {
+        let Some(ports) = ports.as_object() else {
+            diffs.push(format!("{node_id}: expected a JSON object keyed by port"));
+            continue;
+        };
+        let Some(produced) = actual.get(node_id) else {
+            diffs.push(format!("{node_id}: missing from actual outputs"));
+            continue;
+        };
+        for (port, expected_json) in ports {
+            let label = format!("{node_id}.{port}");
+            match produced.get(port) {
+                Some(port) => check(&label, expected_json, port.as_ref(), eps, &mut diffs),
+                None => diffs.push(format!("{label}: missing from actual outputs")),
+            }
+        }
+    }
+    finish(diffs)
+}
+
+fn check(label: &str, expected: &JsonValue, actual: &Value, eps: f32, diffs: &mut Vec<String>) {
+    match json::parse_value(expected.clone()) {
+        Ok(expected) => compare(label, &expected, actual, eps, diffs),
+        Err(err) => diffs.push(format!("{label}: invalid expected value ({err})")),
+    }
+}
+
+fn compare(label: &str, expected: &Value, actual: &Value, eps: f32, diffs: &mut Vec<String>) {
+    use VizijKind::*;
+
+    match (vizij_api_core::kind(expected), vizij_api_core::kind(actual)) {
+        (Text, Text) => {
+            if as_text(expected) != as_text(actual) {
+                diffs.push(format!(
+                    "{label}: expected {:?}, got {:?}",
+                    as_text(expected).unwrap_or_default(),
+                    as_text(actual).unwrap_or_default()
+                ));
+            }
+        }
+        (Transform, Transform) => {
+            let flatten = |v: &Value| {
+                as_transform(v)
+                    .map(|t| [&t.translation[..], &t.rotation[..], &t.scale[..]].concat())
+                    .unwrap_or_default()
+            };
+            compare_numbers(label, &flatten(expected), &flatten(actual), eps, diffs);
+        }
+        (Record, Record) => {
+            let produced: HashMap<&str, &Value> =
+                as_record(actual).unwrap_or_default().into_iter().collect();
+            for (key, value) in as_record(expected).unwrap_or_default() {
+                let field = format!("{label}.{key}");
+                match produced.get(key) {
+                    Some(actual) => compare(&field, value, actual, eps, diffs),
+                    None => diffs.push(format!("{field}: missing from actual record")),
+                }
+            }
+        }
+        (Array, Array) => {
+            let (expected, actual) = (
+                as_array(expected).unwrap_or_default(),
+                as_array(actual).unwrap_or_default(),
+            );
+            if expected.len() != actual.len() {
+                diffs.push(format!(
+                    "{label}: expected {} items, got {}",
+                    expected.len(),
+                    actual.len()
+                ));
+                return;
+            }
+            for (index, (expected, actual)) in expected.iter().zip(actual).enumerate() {
+                compare(&format!("{label}[{index}]"), expected, actual, eps, diffs);
+            }
+        }
+        (ek, ak) if is_numeric(ek) && is_numeric(ak) => {
+            compare_numbers(label, &to_vector(expected), &to_vector(actual), eps, diffs);
+        }
+        (ek, ak) => {
+            if expected != actual {
+                diffs.push(format!(
+                    "{label}: expected {ek:?} {expected:?}, got {ak:?} {actual:?}"
+                ));
+            }
+        }
+    }
+}
+
+fn is_numeric(kind: VizijKind) -> bool {
+    use VizijKind::*;
+    matches!(
+        kind,
+        Float | Bool | Vector | Vec2 | Vec3 | Vec4 | Quat | ColorRgba
+    )
+}
+
+fn compare_numbers(
+    label: &str,
+    expected: &[f32],
+    actual: &[f32],
+    eps: f32,
+    diffs: &mut Vec<String>,
+) {
+    let close = expected.len() == actual.len()
+        && expected
+            .iter()
+            .zip(actual)
+            .all(|(e, a)| (e - a).abs() <= eps || (e.is_nan() && a.is_nan()));
+    if !close {
+        let show = |xs: &[f32]| match xs {
+            [x] => format!("{x}"),
+            xs => format!("{xs:?}"),
+        };
+        diffs.push(format!(
+            "{label}: expected {}, got {} (eps {eps})",
+            show(expected),
+            show(actual)
+        ));
+    }
+}
+
+fn finish(diffs: Vec<String>) -> Result<(), String> {
+    if diffs.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} mismatched output(s):\n  {}",
+            diffs.len(),
+            diffs.join("\n  ")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use vizij_api_core::value::{float, vec3};
+    use vizij_api_core::WriteOp;
+
+    fn batch(ops: &[(&str, Value)]) -> WriteBatch {
+        let mut batch = WriteBatch::new();
+        for (path, value) in ops {
+            batch.push(WriteOp::new(TypedPath::parse(path).unwrap(), value.clone()));
+        }
+        batch
+    }
+
+    #[test]
+    fn writebatch_matches_within_tolerance() {
+        let expected = json!({
+            "samples/json.pose": { "vec3": [0.005, 0.115, 0.275] },
+            "chain/sign.value": -1,
+        });
+        let actual = batch(&[
+            ("samples/json.pose", vec3([0.00501, 0.115, 0.27499])),
+            ("chain/sign.value", float(-1.0)),
+            ("chain/extra.value", float(3.0)),
+        ]);
+        assert_outputs_match(&expected, &actual, 1e-4).expect("outputs should match");
+    }
+
+    #[test]
+    fn writebatch_mismatch_lists_every_failure() {
+        let expected = json!({
+            "samples/json.pose": { "vec3": [0.005, 0.115, 0.275] },
+            "chain/sign.value": -1,
+            "chain/slewed.value": 0,
+        });
+        let actual = batch(&[
+            ("samples/json.pose", vec3([0.005, 0.5, 0.275])),
+            ("chain/sign.value", float(-1.0)),
+        ]);
+        let err = assert_outputs_match(&expected, &actual, 1e-4).unwrap_err();
+        assert!(err.starts_with("2 mismatched output(s)"), "{err}");
+        assert!(
+            err.contains(
+                "samples/json.pose: expected [0.005, 0.115, 0.275], got [0.005, 0.5, 0.275]"
+            ),
+            "{err}"
+        );
+        assert!(
+            err.contains("chain/slewed.value: missing from actual outputs"),
+            "{err}"
+        );
+    }
+
+    /// Stand-in for the graph runtime's `PortValue`.
+    struct Port(Value);
+
+    impl AsRef<Value> for Port {
+        fn as_ref(&self) -> &Value {
+            &self.0
+        }
+    }
+
+    #[test]
+    fn graph_outputs_compare_per_port() {
+        let actual = hashbrown::HashMap::from([(
+            "gain".to_string(),
+            hashbrown::HashMap::from([("out".to_string(), Port(float(2.0)))]),
+        )]);
+        assert_graph_outputs_match(&json!({ "gain": { "out": 2.0 } }), &actual, 1e-6)
+            .expect("graph outputs should match");
+        let err = assert_graph_outputs_match(&json!({ "gain": { "out": 2.5 } }), &actual, 1e-6)
+            .unwrap_err();
+        assert!(err.contains("gain.out: expected 2.5, got 2"), "{err}");
+    }
+}
@@ -1,7 +1,9 @@
 //! Fixture manifest helpers shared across Rust tests and examples.
 //!
 //! This crate loads the workspace fixture manifest and exposes small helper modules for
-//! resolving animation, node-graph, and orchestration assets by logical key.
+//! resolving animation, node-graph, and orchestration assets by logical key, plus [`compare`]
+//! helpers for checking produced values against fixture expectations and [`record`] helpers for
+//! snapshotting a run into new expectations.
 
 use std::collections::HashMap;
 use std::fs;
@@ -12,6 +14,12 @@ use once_cell::sync::Lazy;
 use serde::de::DeserializeOwned;
 use serde::Deserialize;
 
+pub mod compare;
+pub mod record;
+
+pub use compare::{assert_graph_outputs_match, assert_outputs_match};
+pub use record::record_steps;
+
 static MANIFEST: Lazy<Manifest> = Lazy::new(|| {
     let raw = include_str!("../../../../fixtures/manifest.json");
     serde_json::from_str(raw).expect("fixtures manifest should parse")
@@ -67,6 +75,50 @@ fn load_json<T: DeserializeOwned>(rel: &str) -> Result<T> {
     serde_json::from_str(&text).with_context(|| format!("failed to parse JSON fixture {rel}"))
 }
 
+/// Check that every file the fixture manifest references exists and parses as JSON.
+///
+/// Unlike the per-fixture loaders, this walks the whole manifest up front and reports every
+/// problem at once, one message per broken animation, graph spec, stage, or orchestration entry.
+pub fn validate_manifest() -> std::result::Result<(), Vec<String>> {
+    validate(&MANIFEST, &fixtures_root())
+}
+
+fn validate(manifest: &Manifest, root: &Path) -> std::result::Result<(), Vec<String>> {
+    let mut refs: Vec<(String, &str)> = Vec::new();
+    for (name, rel) in &manifest.animations {
+        refs.push((format!("animation '{name}'"), rel));
+    }
+    for (name, entry) in &manifest.node_graphs {
+        refs.push((format!("node graph '{name}' spec"), &entry.spec));
+        if let Some(stage) = &entry.stage {
+            refs.push((format!("node graph '{name}' stage"), stage));
+        }
+    }
+    for (name, entry) in &manifest.orchestrations {
+        refs.push((format!("orchestration '{name}'"), entry.as_path()));
+    }
+    refs.sort();
+
+    let problems: Vec<String> = refs
+        .into_iter()
+        .filter_map(|(label, rel)| {
+            let path = root.join(rel);
+            let text = match fs::read_to_string(&path) {
+                Ok(text) => text,
+                Err(err) => return Some(format!("{label}: cannot read {}: {err}", path.display())),
+            };
+            serde_json::from_str::<serde_json::Value>(&text)
+                .err()
+                .map(|err| format!("{label}: invalid JSON in {}: {err}", path.display()))
+        })
+        .collect();
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(problems)
+    }
+}
+
 fn lookup<'a, T>(map: &'a HashMap<String, T>, kind: &str, name: &str) -> Result<&'a T> {
     map.get(name)
         .ok_or_else(|| anyhow!("unknown {kind} fixture '{name}'"))
@@ -166,6 +218,35 @@ pub mod orchestrations {
 mod tests {
     use super::*;
 
+    #[test]
+    fn bundled_manifest_validates() {
+        if let Err(problems) = validate_manifest() {
+            panic!("fixture manifest problems:\n{}", problems.join("\n"));
+        }
+    }
+
+    #[test]
+    fn broken_manifest_reports_every_problem() {
+        let root = std::env::temp_dir().join(format!("vizij-fixtures-{}", std::process::id()));
+        fs::create_dir_all(&root).expect("create temp fixture root");
+        fs::write(root.join("good.json"), "{}").expect("write good fixture");
+        fs::write(root.join("bad.json"), "{ not json").expect("write bad fixture");
+
+        let manifest: Manifest = serde_json::from_value(serde_json::json!({
+            "animations": { "ok": "good.json", "gone": "missing.json" },
+            "node-graphs": { "graph": { "spec": "good.json", "stage": "bad.json" } },
+            "orchestrations": { "pipeline": { "path": "missing-too.json" } },
+        }))
+        .expect("synthetic manifest parses");
+
+        let problems = validate(&manifest, &root).expect_err("broken manifest should fail");
+        fs::remove_dir_all(&root).ok();
+        assert_eq!(problems.len(), 3, "{problems:#?}");
+        assert!(problems[0].starts_with("animation 'gone': cannot read"));
+        assert!(problems[1].starts_with("node graph 'graph' stage: invalid JSON"));
+        assert!(problems[2].starts_with("orchestration 'pipeline': cannot read"));
+    }
+
     #[test]
     fn animation_pose_quat_transform_loads() {
         let value: serde_json::Value =
@@ -0,0 +1,72 @@
+//! Snapshot produced frames into fixture `steps[].expect` blocks.
+//!
+//! Drive a pipeline by hand, collect each frame's `(delta, writes)`, and [`record_steps`] turns
+//! them into the `steps` array used by orchestration fixtures so the run can be checked in and
+//! replayed with [`crate::compare::assert_outputs_match`].
+
+use serde_json::{json, Map, Value as JsonValue};
+use vizij_api_core::WriteBatch;
+
+/// Fill `descriptor["steps"]` with one `{ "delta", "expect" }` entry per frame.
+///
+/// Each `expect` maps every written path to the value's canonical JSON wire form; when a frame
+/// writes a path more than once the last write is kept. Any existing `steps` are replaced and the
+/// rest of `descriptor` is returned untouched. Errors when `descriptor` is not a JSON object or a
+/// value fails to serialize.
+pub fn record_steps(
+    mut descriptor: JsonValue,
+    frames: impl IntoIterator<Item = (f32, WriteBatch)>,
+) -> Result<JsonValue, String> {
+    let mut steps = Vec::new();
+    for (delta, writes) in frames {
+        let mut expect = Map::new();
+        for op in writes.iter() {
+            let value = serde_json::to_value(&op.value)
+                .map_err(|err| format!("failed to serialize value at {}: {err}", op.path))?;
+            expect.insert(op.path.to_string(), value);
+        }
+        steps.push(json!({ "delta": delta, "expect": expect }));
+    }
+
+    descriptor
+        .as_object_mut()
+        .ok_or_else(|| "fixture descriptor must be a JSON object".to_string())?
+        .insert("steps".to_string(), JsonValue::Array(steps));
+    Ok(descriptor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vizij_api_core::value::float;
+    use vizij_api_core::{TypedPath, WriteOp};
+
+    fn write(path: &str, value: f32) -> WriteOp {
+        WriteOp::new(TypedPath::parse(path).unwrap(), float(value))
+    }
+
+    #[test]
+    fn record_steps_fills_steps_and_keeps_the_descriptor() {
+        let mut repeated = WriteBatch::new();
+        repeated.push(write("demo/output.value", 1.0));
+        repeated.push(write("demo/output.value", 2.0));
+        let frames = [(0.0, WriteBatch::new()), (0.5, repeated)];
+        let descriptor = json!({ "description": "recorded demo run", "steps": [{ "delta": 9 }] });
+
+        let recorded = record_steps(descriptor, frames).expect("record steps");
+        assert_eq!(recorded["description"], "recorded demo run");
+        assert_eq!(
+            recorded["steps"],
+            json!([
+                { "delta": 0.0, "expect": {} },
+                { "delta": 0.5, "expect": { "demo/output.value": float_json(2.0) } },
+            ])
+        );
+
+        assert!(record_steps(json!([]), Vec::new()).is_err());
+    }
+
+    fn float_json(value: f32) -> JsonValue {
+        serde_json::to_value(float(value)).unwrap()
+    }
+}
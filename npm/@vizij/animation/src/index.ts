@@ -31,6 +31,8 @@ import type {
   BakedAnimationData,
   BakedDerivativeAnimationData,
   BakedAnimationBundle,
+  TimedKeypoint,
+  BindingReportRow,
 } from "./types";
 
 export type {
@@ -55,6 +57,8 @@ export type {
   BakedAnimationData,
   BakedDerivativeAnimationData,
   BakedAnimationBundle,
+  TimedKeypoint,
+  BindingReportRow,
 };
 
 export {
@@ -316,6 +320,9 @@ export class Engine {
    *
    * Optional `inputs` are applied before the engine advances time, so player commands and instance
    * updates affect the same returned frame.
+   *
+   * @throws Error when the step panics, but only in a `panic=unwind` wasm build; the default
+   * `panic=abort` build traps and the engine must be recreated.
    */
   updateValues(dt: number, inputs?: Inputs): Outputs {
     const inner: any = this.inner;
@@ -354,15 +361,25 @@ export class Engine {
    * mirrors `vizij-animation-core`'s `BakedAnimationData` schema.
    *
    * Baking does not require a player or instance; it operates directly on the loaded clip id.
+   * `onProgress` receives the completion fraction after each frame; returning `false` cancels
+   * the bake and returns the frames sampled so far.
    */
-  bakeAnimation(anim: AnimId, cfg?: BakingConfig): BakedAnimationData {
+  bakeAnimation(
+    anim: AnimId,
+    cfg?: BakingConfig,
+    onProgress?: (fraction: number) => boolean | void
+  ): BakedAnimationData {
     const inner: any = this.inner;
     if (typeof inner.bake_animation !== "function") {
       throw new Error(
         "Current WASM build does not expose bake_animation; rebuild vizij-animation-wasm with updated bindings."
       );
     }
-    return inner.bake_animation(anim as number, (cfg ?? undefined) as any) as BakedAnimationData;
+    return inner.bake_animation(
+      anim as number,
+      (cfg ?? undefined) as any,
+      onProgress
+    ) as BakedAnimationData;
   }
 
   /**
@@ -383,6 +400,55 @@ export class Engine {
     ) as BakedAnimationBundle;
   }
 
+  /**
+   * Set the global time scale applied to every player's `dt`.
+   *
+   * `0.5` runs all players at half rate; per-player speed and per-instance time scale still
+   * apply on top.
+   */
+  setTimeScale(scale: number): void {
+    const inner: any = this.inner;
+    if (typeof inner.set_time_scale !== "function") {
+      throw new Error("set_time_scale not available; rebuild vizij-animation-wasm");
+    }
+    inner.set_time_scale(scale);
+  }
+
+  /**
+   * Freeze every player (for example when the app loses focus).
+   *
+   * Updates still return outputs for the frozen time; per-player playback state is untouched.
+   */
+  pauseAll(): void {
+    const inner: any = this.inner;
+    if (typeof inner.pause_all !== "function") {
+      throw new Error("pause_all not available; rebuild vizij-animation-wasm");
+    }
+    inner.pause_all();
+  }
+
+  /**
+   * Make output `key` a strict-priority key: within a player, only the instances with the
+   * highest `priority` writing it contribute (e.g. an override pose over a base layer). Pass
+   * `enabled = false` to return to weighted blending.
+   */
+  setKeyOverride(key: string, enabled = true): void {
+    const inner: any = this.inner;
+    if (typeof inner.set_key_override !== "function") {
+      throw new Error("set_key_override not available; rebuild vizij-animation-wasm");
+    }
+    inner.set_key_override(key, enabled);
+  }
+
+  /** Resume every player from where {@link pauseAll} froze it. */
+  resumeAll(): void {
+    const inner: any = this.inner;
+    if (typeof inner.resume_all !== "function") {
+      throw new Error("resume_all not available; rebuild vizij-animation-wasm");
+    }
+    inner.resume_all();
+  }
+
   /**
    * Remove a player and all instances attached to it.
    *
@@ -422,6 +488,86 @@ export class Engine {
     return !!inner.unload_animation(anim as number);
   }
 
+  /**
+   * Merge two loaded clips track-wise into a new clip and return its id.
+   *
+   * Duplicate target paths throw unless `overwrite` is true (the second clip's track wins).
+   * The merged clip lasts as long as the longer input.
+   */
+  mergeAnimations(a: AnimId, b: AnimId, overwrite = false): AnimId {
+    const inner: any = this.inner;
+    if (typeof inner.merge_animations !== "function") {
+      throw new Error("merge_animations not available; rebuild vizij-animation-wasm");
+    }
+    return inner.merge_animations(a as number, b as number, overwrite) as AnimId;
+  }
+
+  /**
+   * Extract `[startS, endS]` (clip seconds) of a loaded clip as a new clip starting at 0 and
+   * return its id. Endpoint values are sampled from the original.
+   */
+  subclip(anim: AnimId, startS: number, endS: number): AnimId {
+    const inner: any = this.inner;
+    if (typeof inner.subclip !== "function") {
+      throw new Error("subclip not available; rebuild vizij-animation-wasm");
+    }
+    return inner.subclip(anim as number, startS, endS) as AnimId;
+  }
+
+  /**
+   * Reduce a loaded clip's keypoints while keeping every track within `tolerance` of the
+   * original, returning the id of the simplified clip.
+   */
+  simplify(anim: AnimId, tolerance: number): AnimId {
+    const inner: any = this.inner;
+    if (typeof inner.simplify !== "function") {
+      throw new Error("simplify not available; rebuild vizij-animation-wasm");
+    }
+    return inner.simplify(anim as number, tolerance) as AnimId;
+  }
+
+  /**
+   * Resample every track of a loaded clip onto a uniform `fps` keypoint grid (linear segments,
+   * same duration), e.g. for hardware with a fixed update rate, returning the new clip's id.
+   */
+  resample(anim: AnimId, fps: number): AnimId {
+    const inner: any = this.inner;
+    if (typeof inner.resample !== "function") {
+      throw new Error("resample not available; rebuild vizij-animation-wasm");
+    }
+    return inner.resample(anim as number, fps) as AnimId;
+  }
+
+  /**
+   * Stream keypoints into the track of a loaded clip targeting `trackPath`, e.g. keyframes
+   * received over a socket. `time` is in clip seconds and the clip grows to fit; keypoints
+   * earlier than the track's last one throw unless `sort` is true.
+   */
+  appendKeypoints(
+    anim: AnimId,
+    trackPath: string,
+    keypoints: TimedKeypoint[],
+    sort = false
+  ): void {
+    const inner: any = this.inner;
+    if (typeof inner.append_keypoints !== "function") {
+      throw new Error("append_keypoints not available; rebuild vizij-animation-wasm");
+    }
+    inner.append_keypoints(anim as number, trackPath, keypoints, sort);
+  }
+
+  /**
+   * Effective duration of a player in seconds for timeline bounds: the latest instance end
+   * for `once` players, `Infinity` for looping ones, and 0 for unknown ids.
+   */
+  playerDuration(player: PlayerId): number {
+    const inner: any = this.inner;
+    if (typeof inner.player_duration !== "function") {
+      throw new Error("player_duration not available; rebuild vizij-animation-wasm");
+    }
+    return inner.player_duration(player as number) as number;
+  }
+
   /**
    * Return the currently loaded animations and their runtime metadata.
    */
@@ -468,6 +614,19 @@ export class Engine {
     }
     return (inner.list_player_keys(player as number) as unknown) as string[];
   }
+
+  /**
+   * List every loaded channel (animation id and track index) with the target handle `prebind()`
+   * resolved for it; `target` is `null` where the resolver returned nothing, which is where to
+   * look for paths a resolver missed.
+   */
+  bindingReport(): BindingReportRow[] {
+    const inner: any = this.inner;
+    if (typeof inner.binding_report !== "function") {
+      throw new Error("binding_report not available; rebuild vizij-animation-wasm");
+    }
+    return inner.binding_report() as BindingReportRow[];
+  }
 }
 
 /* -----------------------------------------------------------
@@ -64,11 +64,29 @@ export interface BakingConfig {
   end_time?: number | null;
   /** Optional finite-difference epsilon override for derivative estimation */
   derivative_epsilon?: number;
+  /** Emit samples in reverse time order with derivatives negated */
+  reverse?: boolean;
+  /** Bit depth (1-16) for an extra fixed-point encoding of numeric tracks */
+  quantize_bits?: number;
+}
+
+/** Fixed-point track encoding: `value = offset[c] + scale[c] * code` for component `c`. */
+export interface QuantizedTrack {
+  bits: number;
+  /** Numeric components per frame; `data` is frame-major. */
+  components: number;
+  /** Decode step per component. */
+  scale: number[];
+  /** Decoded value of code `0` per component. */
+  offset: number[];
+  data: number[];
 }
 
 export interface BakedTrack {
   target_path: string;
   values: Value[];
+  /** Present when `quantize_bits` was requested and the track is numeric and finite. */
+  quantized?: QuantizedTrack;
 }
 
 export interface BakedAnimationData {
@@ -169,11 +187,18 @@ export interface Outputs {
   changes: Change[];
   /** Semantic playback/events emitted during this tick. */
   events: CoreEvent[];
+  /**
+   * Diagnostics such as instances writing incompatible value kinds (or vectors of
+   * different lengths) to one key; omitted when empty.
+   */
+  warnings?: string[];
 }
 
 export interface OutputsWithDerivatives {
   changes: ChangeWithDerivative[];
   events: CoreEvent[];
+  /** Same diagnostics as {@link Outputs.warnings}; omitted when empty. */
+  warnings?: string[];
 }
 
 /* -----------------------------------------------------------
@@ -210,6 +235,24 @@ export interface Keypoint {
     in?: BezierCP;
     out?: BezierCP;
   };
+  /**
+   * Explicit cubic-bezier timing (normalized segment time) for the segment leaving this
+   * keypoint; overrides both ends' `transitions`. Control point `x` must be in [0, 1].
+   */
+  interpolation?: { type: "bezier"; p1: BezierCP; p2: BezierCP };
+}
+
+/** Keypoint streamed into a loaded clip via `Animation.appendKeypoints`. */
+export interface TimedKeypoint {
+  /** Derived from the track id when omitted. */
+  id?: string;
+  /** Clip time in seconds (not normalized); the clip grows to fit. */
+  time: number;
+  value: Value;
+  transitions?: {
+    in?: BezierCP;
+    out?: BezierCP;
+  };
 }
 
 export interface Track {
@@ -220,6 +263,8 @@ export interface Track {
   /** Keypoints ordered in normalized clip space. */
   points: Keypoint[];
   settings?: { color?: string };
+  /** Units of numeric keypoint values; degree tracks are converted to radians on load. */
+  units?: "degrees" | "radians";
 }
 
 export interface StoredAnimation {
@@ -300,6 +345,16 @@ export interface InstanceCfg {
   /** Start offset in seconds on the player timeline. */
   start_offset: number;
   enabled: boolean;
+  /** Layer priority for keys marked with `setKeyOverride`; defaults to 0. */
+  priority?: number;
+}
+
+/** One channel of `Animation.bindingReport`. */
+export interface BindingReportRow {
+  anim: number;
+  track_idx: number;
+  /** Handle the resolver returned for the track's path; null when unresolved. */
+  target: string | null;
 }
 
 /** Snapshot of one registered animation instance. */
@@ -21,6 +21,8 @@ import type {
   EvalResult,
   ShapeJSON,
   WriteOpJSON,
+  CanonicalWriteOpJSON,
+  NodesWritesResult,
   ParamSpec,
   PortSpec,
   VariadicSpec,
@@ -38,6 +40,8 @@ export type {
   PortSnapshot,
   EvalResult,
   WriteOpJSON,
+  CanonicalWriteOpJSON,
+  NodesWritesResult,
   ShapeJSON,
   ParamSpec,
   Registry,
@@ -65,6 +69,7 @@ interface WasmBindings {
   default: (input?: unknown) => Promise<unknown>;
   WasmGraph: WasmGraphCtor;
   normalize_graph_spec_json: (json: string) => string;
+  extract_subgraph: (json: string, ids: string[]) => string;
   get_node_schemas_json: () => string;
   abi_version: () => number;
 }
@@ -384,6 +389,9 @@ export class Graph {
    * The wrapper prefers delta-aware wasm entrypoints when available, but always returns the same
    * merged `EvalResult` shape to consumers. Calling this method also refreshes the wrapper's
    * internal delta baseline for later `getOutputsDelta()` calls.
+   *
+   * @throws Error when evaluation fails. A node panic is only thrown in a `panic=unwind` wasm
+   * build; the default `panic=abort` build traps and the graph must be recreated.
    */
   evalAll(): EvalResult {
     const target = this.inner as any;
@@ -448,6 +456,69 @@ export class Graph {
     return parsed;
   }
 
+  /**
+   * Evaluate and return `{ nodes, writes }` with `writes` in the canonical `WriteBatch` serde
+   * form, so a Rust host can deserialize it directly (same contract as the animation wrapper's
+   * `update_nodes_writes`).
+   *
+   * This evaluation bypasses the delta cache, so the next `evalAll()` resynchronizes from a
+   * full snapshot.
+   */
+  evalNodesWrites(): NodesWritesResult {
+    const target = this.inner as any;
+    if (typeof target.eval_nodes_writes !== "function") {
+      throw new Error("eval_nodes_writes not available; rebuild vizij-graph-wasm");
+    }
+    const result = target.eval_nodes_writes() as NodesWritesResult;
+    this._baselineCaptured = false;
+    this._lastOutputVersion = 0n;
+    this._outputsDirty = true;
+    return result;
+  }
+
+  /**
+   * Distinct node type ids used by the loaded graph, sorted.
+   *
+   * Ids match the registry's `type_id`, so hosts can warn when a feature-gated node (such as the
+   * `urdf_ik` family) appears in a build without that feature.
+   */
+  usedNodeTypes(): string[] {
+    const target = this.inner as any;
+    if (typeof target.used_node_types !== "function") {
+      throw new Error("used_node_types not available; rebuild vizij-graph-wasm");
+    }
+    return target.used_node_types() as string[];
+  }
+
+  /**
+   * Evaluate and write the `evalAll` JSON as UTF-8 into a caller-owned buffer.
+   *
+   * Reuse one `Uint8Array` across frames to avoid allocating an output string per tick; decode
+   * with `new TextDecoder().decode(buffer.subarray(0, len))`. Returns the byte length. When it
+   * exceeds `buffer.length` nothing is written; grow the buffer and call `readLastEvalInto`.
+   * Like `evalNodesWrites`, this bypasses the delta cache.
+   */
+  evalAllInto(buffer: Uint8Array): number {
+    const target = this.inner as any;
+    if (typeof target.eval_all_into !== "function") {
+      throw new Error("eval_all_into not available; rebuild vizij-graph-wasm");
+    }
+    const len = target.eval_all_into(buffer) as number;
+    this._baselineCaptured = false;
+    this._lastOutputVersion = 0n;
+    this._outputsDirty = true;
+    return len;
+  }
+
+  /** Copy the JSON from the last `evalAllInto` call into `buffer` without re-evaluating. */
+  readLastEvalInto(buffer: Uint8Array): number {
+    const target = this.inner as any;
+    if (typeof target.read_last_eval_into !== "function") {
+      throw new Error("read_last_eval_into not available; rebuild vizij-graph-wasm");
+    }
+    return target.read_last_eval_into(buffer) as number;
+  }
+
   /**
    * Force a full output snapshot and reset the wrapper's delta baseline.
    *
@@ -1011,6 +1082,21 @@ export async function normalizeGraphSpec(
   return JSON.parse(normalizedJson) as GraphSpec;
 }
 
+/**
+ * Extract the named nodes, and the edges among them, into a standalone graph
+ * spec (e.g. for copy/paste). Inputs wired from outside the selection are
+ * dropped, with constant sources inlined as input defaults.
+ */
+export async function extractSubgraph(
+  spec: GraphSpec | string,
+  ids: string[]
+): Promise<GraphSpec> {
+  await init();
+  const json = typeof spec === "string" ? spec : JSON.stringify(spec);
+  const mod = await loadBindings();
+  return JSON.parse(mod.extract_subgraph(json, ids)) as GraphSpec;
+}
+
 /**
  * Fetch the node schema registry from the wasm module as a parsed object.
  * Ensures the wasm module is initialized before calling.
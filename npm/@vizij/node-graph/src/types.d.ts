@@ -37,9 +37,13 @@ export type NodeType =
   | "modulo"
   | "sqrt"
   | "sign"
+  | "saturate"
+  | "negate"
+  | "reciprocal"
   | "min"
   | "max"
   | "round"
+  | "quantize"
   | "sin"
   | "cos"
   | "tan"
@@ -48,20 +52,30 @@ export type NodeType =
   | "spring"
   | "damp"
   | "slew"
+  | "ema"
+  | "samplehold"
+  | "delay"
+  | "movingaverage"
+  | "feedbacksource"
+  | "feedbacktap"
   | "and"
   | "or"
   | "not"
   | "xor"
+  | "edge"
   | "greaterthan"
   | "lessthan"
   | "equal"
   | "notequal"
+  | "compare"
   | "if"
   | "case"
+  | "caseroute"
   | "clamp"
   | "remap"
   | "centered_remap"
   | "piecewise_remap"
+  | "curve"
   | "vec3cross"
   | "vectorconstant"
   | "vectoradd"
@@ -81,10 +95,15 @@ export type NodeType =
   | "vectormode"
   | "tovector"
   | "fromvector"
+  | "rgbtohsv"
+  | "hsvtorgb"
+  | "srgbtolinear"
+  | "lineartosrgb"
   | "simplenoise"
   | "perlinnoise"
   | "simplexnoise"
   | "weightedsumvector"
+  | "weightedsum"
   | "default-blend"
   | "blendweightedaverage"
   | "blendadditive"
@@ -95,6 +114,7 @@ export type NodeType =
   | "inversekinematics"
   | "urdfikposition"
   | "urdfikpose"
+  | "urdfikorientation"
   | "urdffk"
   | "buildrecord"
   | "readrecord"
@@ -105,6 +125,11 @@ export type NodeType =
   | "mathaddrecord"
   | "mathdivrecord"
   | "mathsubrecord"
+  | "listlength"
+  | "listget"
+  | "numbertotext"
+  | "texttonumber"
+  | "subgraph"
   | "input"
   | "output"
   | "externalfunction";
@@ -136,12 +161,24 @@ export interface NodeParams {
   value?: ValueJSON | number | boolean | [number, number, number] | number[];
   /** Segment sizes for `split`; fractional values are floored by the Rust runtime. */
   sizes?: number[];
+  /** Output port names for `split`, one per size; replaces the default `part1..N` keys. */
+  output_names?: string[];
   frequency?: number;
   noise_seed?: number;
   octaves?: number;
   lacunarity?: number;
   persistence?: number;
   phase?: number;
+  /** Zero-based element index for `listget` when its `index` input is unconnected. */
+  index?: number;
+  /** Grid spacing for `quantize`; zero passes values through. */
+  step?: number;
+  /** Grid origin for `quantize`. */
+  offset?: number;
+  /** Equality tolerance for `compare`, `equal`, and `notequal`. */
+  epsilon?: number;
+  /** Scale `epsilon` by the larger operand magnitude for `equal`/`notequal`. */
+  relative?: boolean;
   min?: number;
   max?: number;
   x?: number;
@@ -153,26 +190,57 @@ export interface NodeParams {
   out_max?: number;
   /** Optional typed-path target for sinks (validated in Rust). */
   path?: string;
+  /** How `output` nodes sharing a path combine their writes; defaults to `"last"`. */
+  accumulate?: "last" | "sum" | "list";
   stiffness?: number;
   damping?: number;
   mass?: number;
   half_life?: number;
   max_rate?: number;
+  /** Per-evaluation smoothing factor for `ema`, in [0, 1]. */
+  alpha?: number;
+  /** Number of evaluations `delay` lags its input by. */
+  frames?: number;
+  /** Number of samples `movingaverage` averages over. */
+  window?: number;
+  /** Decimal places used by `numbertotext`; unset prints the shortest exact form. */
+  precision?: number;
+  /** Id of the `feedbacksource` node read by a `feedbacktap`. */
+  source?: NodeId;
+  /** Value `delay` emits until its history fills, and `feedbacktap` until its source has run. */
+  default?: ValueJSON | number | boolean | [number, number, number] | number[];
   urdf_xml?: string;
   root_link?: string;
   tip_link?: string;
   /** Optional seed vector passed into IK solvers. */
   seed?: number[];
-  /** Optional per-joint weights passed into IK solvers. */
+  /** Optional per-joint weights passed into IK solvers; per-input weights for `weightedsum` (`value_i` takes `weights[i - 1]`). */
   weights?: number[];
   max_iters?: number;
   tol_pos?: number;
   tol_rot?: number;
+  /** IK solver for URDF IK nodes: `"jacobian"` (default) or `"ccd"` (position targets only, no weights). */
+  solver?: "jacobian" | "ccd";
+  /** Return the best-effort solution with `converged: false` instead of failing when IK misses the target. */
+  fail_soft?: boolean;
   joint_defaults?: [string, number][];
+  /** `[input, output]` breakpoints for `curve`, sorted by input. */
+  points?: [number, number][];
   /** Branch labels matched by `case` routing nodes. */
   case_labels?: string[];
   record_keys?: string[]; // for BuildRecord/ReadRecord
   keys?: string; // for SplitRecord (comma-separated field names)
+  /** Nested graph and port mappings evaluated by `subgraph`. */
+  subgraph?: SubgraphSpec;
+}
+
+/** Nested graph carried by a `subgraph` node. */
+export interface SubgraphSpec {
+  graph: GraphSpec;
+  /** Parent input ports staged into the child graph's `input` nodes by typed path. */
+  inputs?: { port: string; path: string }[];
+  /** Child node outputs exposed as parent output ports; `output` defaults to `"out"`. */
+  outputs?: { port: string; node_id: NodeId; output?: string }[];
 }
 
 /** One step in a selector path applied to a structured output value. */
@@ -189,6 +257,8 @@ export interface NodeSpec {
   type: NodeType;
   params?: NodeParams;
   output_shapes?: Record<string, ShapeJSON>;
+  /** Opaque editor annotations (position, notes, color); preserved by load/save round-trips. */
+  metadata?: Record<string, unknown>;
   /**
    * Optional map of input names to inline default values. Each entry mirrors the effect of wiring a
    * Constant node but can be overridden by an explicit edge targeting the same input. Bare values
@@ -257,10 +327,23 @@ export interface WriteOpJSON {
   shape: ShapeJSON;
 }
 
+/** Write in the canonical `WriteBatch` serde form; `shape` is present only when declared. */
+export interface CanonicalWriteOpJSON {
+  path: string;
+  value: ValueJSON;
+  shape?: ShapeJSON;
+}
+
+/** `{ nodes, writes }` result of `evalNodesWrites()`, matching the animation wrapper contract. */
+export interface NodesWritesResult {
+  nodes: Record<NodeId, Record<string, PortSnapshot>>;
+  writes: CanonicalWriteOpJSON[];
+}
+
 /** Full evaluation result returned by wrapper helpers such as `evalAll()`. */
 export interface EvalResult {
   nodes: Record<NodeId, Record<string, PortSnapshot>>;
-  /** Ordered writes emitted during this evaluation. They are not conflict-resolved or deduplicated. */
+  /** Writes emitted during this evaluation, one per path in first-write order; a later write to a path replaces the earlier one. */
   writes: WriteOpJSON[];
 }
 
@@ -314,6 +397,8 @@ export interface VariadicSpec {
   min: number;
   /** Maximum number of accepted ports, when finite. */
   max?: number;
+  /** Param whose entry count sets the number of ports (for example `"sizes"` for `split`). */
+  count_param?: string;
 }
 
 /** Schema for one configurable node parameter. */
@@ -336,6 +421,10 @@ export interface NodeSignature {
   type_id: NodeType;
   name: string;
   category: string;
+  /** False when the node needs a cargo feature this wasm build lacks. */
+  available: boolean;
+  /** Cargo feature gating the node (e.g. `"urdf_ik"`), when any. */
+  requires_feature?: string;
   doc?: string;
   inputs: PortSpec[];
   variadic_inputs?: VariadicSpec;